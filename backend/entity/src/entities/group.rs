@@ -14,6 +14,8 @@ pub struct Model {
   pub group_permissions: HasMany<super::group_permission::Entity>,
   #[sea_orm(has_many, via = "group_user")]
   pub users: HasMany<super::user::Entity>,
+  #[sea_orm(has_many)]
+  pub location_groups: HasMany<super::location_group::Entity>,
 }
 
 impl ActiveModelBehavior for ActiveModel {}
@@ -2,13 +2,32 @@
 
 pub mod prelude;
 
+pub mod alert;
+pub mod alert_channel;
+pub mod alert_rule;
+pub mod alert_rule_channel;
+pub mod api_key;
+pub mod api_key_permission;
+pub mod api_key_usage;
 pub mod group;
 pub mod group_permission;
 pub mod group_user;
 pub mod invalid_jwt;
 pub mod key;
+pub mod lease;
+pub mod location;
+pub mod location_group;
 pub mod node;
+pub mod node_enrollment;
+pub mod node_nonce;
+pub mod node_sample;
+pub mod node_sync;
+pub mod node_tag;
 pub mod settings;
 pub mod setup;
+pub mod ssh_key;
 pub mod user;
 pub mod user_avatar;
+pub mod webhook;
+pub mod webhook_delivery;
+pub mod webhook_event;
@@ -19,6 +19,30 @@ pub struct Model {
   pub memory_limit_mb: Option<f64>,
   pub cpu_limit: Option<i32>,
   pub token: String,
+  pub maintenance: bool,
+  pub maintenance_message: Option<String>,
+  pub location_id: Option<Uuid>,
+  pub reverse_connect: bool,
+  #[sea_orm(column_type = "Text", nullable)]
+  pub tls_ca: Option<String>,
+  pub tls_pin: Option<String>,
+  pub lifecycle: String,
+  pub first_connected_at: Option<DateTime>,
+  pub last_seen_at: Option<DateTime>,
+  #[sea_orm(has_many)]
+  pub alert_rules: HasMany<super::alert_rule::Entity>,
+  #[sea_orm(has_many)]
+  pub alerts: HasMany<super::alert::Entity>,
+  #[sea_orm(has_one)]
+  pub node_enrollment: HasOne<super::node_enrollment::Entity>,
+  #[sea_orm(has_many)]
+  pub node_nonces: HasMany<super::node_nonce::Entity>,
+  #[sea_orm(has_many)]
+  pub node_samples: HasMany<super::node_sample::Entity>,
+  #[sea_orm(has_one)]
+  pub node_sync: HasOne<super::node_sync::Entity>,
+  #[sea_orm(has_many)]
+  pub node_tags: HasMany<super::node_tag::Entity>,
 }
 
 impl ActiveModelBehavior for ActiveModel {}
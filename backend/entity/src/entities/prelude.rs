@@ -1,12 +1,31 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 2.0
 
+pub use super::alert::Entity as Alert;
+pub use super::alert_channel::Entity as AlertChannel;
+pub use super::alert_rule::Entity as AlertRule;
+pub use super::alert_rule_channel::Entity as AlertRuleChannel;
+pub use super::api_key::Entity as ApiKey;
+pub use super::api_key_permission::Entity as ApiKeyPermission;
+pub use super::api_key_usage::Entity as ApiKeyUsage;
 pub use super::group::Entity as Group;
 pub use super::group_permission::Entity as GroupPermission;
 pub use super::group_user::Entity as GroupUser;
 pub use super::invalid_jwt::Entity as InvalidJwt;
 pub use super::key::Entity as Key;
+pub use super::lease::Entity as Lease;
+pub use super::location::Entity as Location;
+pub use super::location_group::Entity as LocationGroup;
 pub use super::node::Entity as Node;
+pub use super::node_enrollment::Entity as NodeEnrollment;
+pub use super::node_nonce::Entity as NodeNonce;
+pub use super::node_sample::Entity as NodeSample;
+pub use super::node_sync::Entity as NodeSync;
+pub use super::node_tag::Entity as NodeTag;
 pub use super::settings::Entity as Settings;
 pub use super::setup::Entity as Setup;
+pub use super::ssh_key::Entity as SshKey;
 pub use super::user::Entity as User;
 pub use super::user_avatar::Entity as UserAvatar;
+pub use super::webhook::Entity as Webhook;
+pub use super::webhook_delivery::Entity as WebhookDelivery;
+pub use super::webhook_event::Entity as WebhookEvent;
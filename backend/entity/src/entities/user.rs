@@ -21,6 +21,10 @@ pub struct Model {
   pub user_avatar: HasOne<super::user_avatar::Entity>,
   #[sea_orm(has_many, via = "group_user")]
   pub groups: HasMany<super::group::Entity>,
+  #[sea_orm(has_many)]
+  pub api_keys: HasMany<super::api_key::Entity>,
+  #[sea_orm(has_many)]
+  pub ssh_keys: HasMany<super::ssh_key::Entity>,
 }
 
 impl ActiveModelBehavior for ActiveModel {}
@@ -3,6 +3,20 @@ pub use sea_orm_migration::prelude::*;
 pub struct Migrator;
 
 mod m20260123_145152_node;
+mod m20260201_101500_api_key;
+mod m20260203_090000_node_maintenance;
+mod m20260206_143000_location;
+mod m20260209_111500_ssh_key;
+mod m20260212_160000_node_sample;
+mod m20260216_100000_alert;
+mod m20260218_090000_webhook;
+mod m20260220_093000_node_enrollment;
+mod m20260223_101500_node_reverse_connect;
+mod m20260225_090000_node_tls;
+mod m20260227_140000_node_sync;
+mod m20260302_090000_node_lifecycle;
+mod m20260305_100000_lease;
+mod m20260308_090000_node_nonce;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
@@ -16,6 +30,20 @@ impl MigratorTrait for Migrator {
       Box::new(centaurus::db::migrations::m5_setup::Migration),
       Box::new(centaurus::db::migrations::m6_user_oidc_subject::Migration),
       Box::new(m20260123_145152_node::Migration),
+      Box::new(m20260201_101500_api_key::Migration),
+      Box::new(m20260203_090000_node_maintenance::Migration),
+      Box::new(m20260206_143000_location::Migration),
+      Box::new(m20260209_111500_ssh_key::Migration),
+      Box::new(m20260212_160000_node_sample::Migration),
+      Box::new(m20260216_100000_alert::Migration),
+      Box::new(m20260218_090000_webhook::Migration),
+      Box::new(m20260220_093000_node_enrollment::Migration),
+      Box::new(m20260223_101500_node_reverse_connect::Migration),
+      Box::new(m20260225_090000_node_tls::Migration),
+      Box::new(m20260227_140000_node_sync::Migration),
+      Box::new(m20260302_090000_node_lifecycle::Migration),
+      Box::new(m20260305_100000_lease::Migration),
+      Box::new(m20260308_090000_node_nonce::Migration),
     ]
   }
 }
@@ -0,0 +1,160 @@
+use centaurus::db::migrations::m3_user::User;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const API_KEY_USER_ID_INDEX_NAME: &str = "api_key.user_id";
+const API_KEY_USAGE_API_KEY_ID_INDEX_NAME: &str = "api_key_usage.api_key_id";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+  async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .create_table(
+        Table::create()
+          .table(ApiKey::Table)
+          .if_not_exists()
+          .col(pk_uuid(ApiKey::Id))
+          .col(uuid(ApiKey::UserId))
+          .col(string(ApiKey::Name))
+          .col(string(ApiKey::Prefix))
+          .col(string_uniq(ApiKey::Hash))
+          .col(date_time(ApiKey::CreatedAt))
+          .col(date_time_null(ApiKey::ExpiresAt))
+          .col(date_time_null(ApiKey::LastUsedAt))
+          .foreign_key(
+            ForeignKey::create()
+              .from(ApiKey::Table, ApiKey::UserId)
+              .to(User::Table, User::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_table(
+        Table::create()
+          .table(ApiKeyPermission::Table)
+          .if_not_exists()
+          .primary_key(
+            Index::create()
+              .table(ApiKeyPermission::Table)
+              .col(ApiKeyPermission::ApiKeyId)
+              .col(ApiKeyPermission::Permission),
+          )
+          .col(uuid(ApiKeyPermission::ApiKeyId))
+          .col(string(ApiKeyPermission::Permission))
+          .foreign_key(
+            ForeignKey::create()
+              .from(ApiKeyPermission::Table, ApiKeyPermission::ApiKeyId)
+              .to(ApiKey::Table, ApiKey::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_table(
+        Table::create()
+          .table(ApiKeyUsage::Table)
+          .if_not_exists()
+          .col(pk_uuid(ApiKeyUsage::Id))
+          .col(uuid(ApiKeyUsage::ApiKeyId))
+          .col(string(ApiKeyUsage::Method))
+          .col(string(ApiKeyUsage::Path))
+          .col(date_time(ApiKeyUsage::CreatedAt))
+          .foreign_key(
+            ForeignKey::create()
+              .from(ApiKeyUsage::Table, ApiKeyUsage::ApiKeyId)
+              .to(ApiKey::Table, ApiKey::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_index(
+        Index::create()
+          .if_not_exists()
+          .name(API_KEY_USER_ID_INDEX_NAME)
+          .table(ApiKey::Table)
+          .col(ApiKey::UserId)
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_index(
+        Index::create()
+          .if_not_exists()
+          .name(API_KEY_USAGE_API_KEY_ID_INDEX_NAME)
+          .table(ApiKeyUsage::Table)
+          .col(ApiKeyUsage::ApiKeyId)
+          .to_owned(),
+      )
+      .await
+  }
+
+  async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .drop_index(
+        Index::drop()
+          .name(API_KEY_USAGE_API_KEY_ID_INDEX_NAME)
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .drop_index(Index::drop().name(API_KEY_USER_ID_INDEX_NAME).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(ApiKeyUsage::Table).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(ApiKeyPermission::Table).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(ApiKey::Table).to_owned())
+      .await
+  }
+}
+
+#[derive(DeriveIden)]
+enum ApiKey {
+  Table,
+  Id,
+  UserId,
+  Name,
+  Prefix,
+  Hash,
+  CreatedAt,
+  ExpiresAt,
+  LastUsedAt,
+}
+
+#[derive(DeriveIden)]
+enum ApiKeyPermission {
+  Table,
+  ApiKeyId,
+  Permission,
+}
+
+#[derive(DeriveIden)]
+enum ApiKeyUsage {
+  Table,
+  Id,
+  ApiKeyId,
+  Method,
+  Path,
+  CreatedAt,
+}
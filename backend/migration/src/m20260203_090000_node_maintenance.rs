@@ -0,0 +1,55 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+  async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    // sqlite only supports a single column per alter statement
+    manager
+      .alter_table(
+        Table::alter()
+          .table(Node::Table)
+          .add_column(boolean(Node::Maintenance).default(false))
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .alter_table(
+        Table::alter()
+          .table(Node::Table)
+          .add_column(string_null(Node::MaintenanceMessage))
+          .to_owned(),
+      )
+      .await
+  }
+
+  async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .alter_table(
+        Table::alter()
+          .table(Node::Table)
+          .drop_column(Node::MaintenanceMessage)
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .alter_table(
+        Table::alter()
+          .table(Node::Table)
+          .drop_column(Node::Maintenance)
+          .to_owned(),
+      )
+      .await
+  }
+}
+
+#[derive(DeriveIden)]
+enum Node {
+  Table,
+  Maintenance,
+  MaintenanceMessage,
+}
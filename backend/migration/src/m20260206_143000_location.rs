@@ -0,0 +1,157 @@
+use centaurus::db::migrations::m4_groups::Group;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const NODE_TAG_TAG_INDEX_NAME: &str = "node_tag.tag";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+  async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .create_table(
+        Table::create()
+          .table(Location::Table)
+          .if_not_exists()
+          .col(pk_uuid(Location::Id))
+          .col(string_uniq(Location::Name))
+          .col(string_null(Location::Description))
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_table(
+        Table::create()
+          .table(LocationGroup::Table)
+          .if_not_exists()
+          .primary_key(
+            Index::create()
+              .table(LocationGroup::Table)
+              .col(LocationGroup::LocationId)
+              .col(LocationGroup::GroupId),
+          )
+          .col(uuid(LocationGroup::LocationId))
+          .col(uuid(LocationGroup::GroupId))
+          .foreign_key(
+            ForeignKey::create()
+              .from(LocationGroup::Table, LocationGroup::LocationId)
+              .to(Location::Table, Location::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .foreign_key(
+            ForeignKey::create()
+              .from(LocationGroup::Table, LocationGroup::GroupId)
+              .to(Group::Table, Group::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await?;
+
+    // sqlite can not add foreign keys to existing tables, so deleting a
+    // location clears this column in the application instead
+    manager
+      .alter_table(
+        Table::alter()
+          .table(Node::Table)
+          .add_column(uuid_null(Node::LocationId))
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_table(
+        Table::create()
+          .table(NodeTag::Table)
+          .if_not_exists()
+          .primary_key(
+            Index::create()
+              .table(NodeTag::Table)
+              .col(NodeTag::NodeId)
+              .col(NodeTag::Tag),
+          )
+          .col(uuid(NodeTag::NodeId))
+          .col(string(NodeTag::Tag))
+          .foreign_key(
+            ForeignKey::create()
+              .from(NodeTag::Table, NodeTag::NodeId)
+              .to(Node::Table, Node::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_index(
+        Index::create()
+          .if_not_exists()
+          .name(NODE_TAG_TAG_INDEX_NAME)
+          .table(NodeTag::Table)
+          .col(NodeTag::Tag)
+          .to_owned(),
+      )
+      .await
+  }
+
+  async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .drop_index(Index::drop().name(NODE_TAG_TAG_INDEX_NAME).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(NodeTag::Table).to_owned())
+      .await?;
+
+    manager
+      .alter_table(
+        Table::alter()
+          .table(Node::Table)
+          .drop_column(Node::LocationId)
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(LocationGroup::Table).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(Location::Table).to_owned())
+      .await
+  }
+}
+
+#[derive(DeriveIden)]
+enum Location {
+  Table,
+  Id,
+  Name,
+  Description,
+}
+
+#[derive(DeriveIden)]
+enum LocationGroup {
+  Table,
+  LocationId,
+  GroupId,
+}
+
+#[derive(DeriveIden)]
+enum Node {
+  Table,
+  Id,
+  LocationId,
+}
+
+#[derive(DeriveIden)]
+enum NodeTag {
+  Table,
+  NodeId,
+  Tag,
+}
@@ -0,0 +1,68 @@
+use centaurus::db::migrations::m3_user::User;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const SSH_KEY_USER_ID_INDEX_NAME: &str = "ssh_key.user_id";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+  async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .create_table(
+        Table::create()
+          .table(SshKey::Table)
+          .if_not_exists()
+          .col(pk_uuid(SshKey::Id))
+          .col(uuid(SshKey::UserId))
+          .col(string(SshKey::Name))
+          .col(string(SshKey::KeyType))
+          .col(text(SshKey::PublicKey))
+          .col(string_uniq(SshKey::Fingerprint))
+          .col(date_time(SshKey::CreatedAt))
+          .foreign_key(
+            ForeignKey::create()
+              .from(SshKey::Table, SshKey::UserId)
+              .to(User::Table, User::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_index(
+        Index::create()
+          .if_not_exists()
+          .name(SSH_KEY_USER_ID_INDEX_NAME)
+          .table(SshKey::Table)
+          .col(SshKey::UserId)
+          .to_owned(),
+      )
+      .await
+  }
+
+  async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .drop_index(Index::drop().name(SSH_KEY_USER_ID_INDEX_NAME).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(SshKey::Table).to_owned())
+      .await
+  }
+}
+
+#[derive(DeriveIden)]
+enum SshKey {
+  Table,
+  Id,
+  UserId,
+  Name,
+  KeyType,
+  PublicKey,
+  Fingerprint,
+  CreatedAt,
+}
@@ -0,0 +1,79 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const NODE_SAMPLE_LOOKUP_INDEX_NAME: &str = "node_sample.node_id_resolution_timestamp";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+  async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .create_table(
+        Table::create()
+          .table(NodeSample::Table)
+          .if_not_exists()
+          .col(pk_uuid(NodeSample::Id))
+          .col(uuid(NodeSample::NodeId))
+          .col(integer(NodeSample::Resolution))
+          .col(date_time(NodeSample::Timestamp))
+          .col(double(NodeSample::CpuPercent))
+          .col(double(NodeSample::MemoryUsedMb))
+          .col(double(NodeSample::MemoryTotalMb))
+          .col(double(NodeSample::DiskUsedMb))
+          .col(double(NodeSample::DiskTotalMb))
+          .foreign_key(
+            ForeignKey::create()
+              .from(NodeSample::Table, NodeSample::NodeId)
+              .to(Node::Table, Node::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_index(
+        Index::create()
+          .if_not_exists()
+          .name(NODE_SAMPLE_LOOKUP_INDEX_NAME)
+          .table(NodeSample::Table)
+          .col(NodeSample::NodeId)
+          .col(NodeSample::Resolution)
+          .col(NodeSample::Timestamp)
+          .to_owned(),
+      )
+      .await
+  }
+
+  async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .drop_index(Index::drop().name(NODE_SAMPLE_LOOKUP_INDEX_NAME).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(NodeSample::Table).to_owned())
+      .await
+  }
+}
+
+#[derive(DeriveIden)]
+enum Node {
+  Table,
+  Id,
+}
+
+#[derive(DeriveIden)]
+enum NodeSample {
+  Table,
+  Id,
+  NodeId,
+  Resolution,
+  Timestamp,
+  CpuPercent,
+  MemoryUsedMb,
+  MemoryTotalMb,
+  DiskUsedMb,
+  DiskTotalMb,
+}
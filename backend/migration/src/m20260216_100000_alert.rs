@@ -0,0 +1,188 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const ALERT_RULE_NODE_INDEX_NAME: &str = "alert.rule_id_node_id";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+  async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .create_table(
+        Table::create()
+          .table(AlertChannel::Table)
+          .if_not_exists()
+          .col(pk_uuid(AlertChannel::Id))
+          .col(string_uniq(AlertChannel::Name))
+          .col(string(AlertChannel::Kind))
+          .col(string(AlertChannel::Target))
+          .col(date_time(AlertChannel::CreatedAt))
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_table(
+        Table::create()
+          .table(AlertRule::Table)
+          .if_not_exists()
+          .col(pk_uuid(AlertRule::Id))
+          .col(string_uniq(AlertRule::Name))
+          .col(string(AlertRule::Kind))
+          .col(double(AlertRule::Threshold))
+          .col(uuid_null(AlertRule::NodeId))
+          .col(boolean(AlertRule::Enabled))
+          .col(date_time(AlertRule::CreatedAt))
+          .foreign_key(
+            ForeignKey::create()
+              .from(AlertRule::Table, AlertRule::NodeId)
+              .to(Node::Table, Node::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_table(
+        Table::create()
+          .table(AlertRuleChannel::Table)
+          .if_not_exists()
+          .primary_key(
+            Index::create()
+              .table(AlertRuleChannel::Table)
+              .col(AlertRuleChannel::RuleId)
+              .col(AlertRuleChannel::ChannelId),
+          )
+          .col(uuid(AlertRuleChannel::RuleId))
+          .col(uuid(AlertRuleChannel::ChannelId))
+          .foreign_key(
+            ForeignKey::create()
+              .from(AlertRuleChannel::Table, AlertRuleChannel::RuleId)
+              .to(AlertRule::Table, AlertRule::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .foreign_key(
+            ForeignKey::create()
+              .from(AlertRuleChannel::Table, AlertRuleChannel::ChannelId)
+              .to(AlertChannel::Table, AlertChannel::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_table(
+        Table::create()
+          .table(Alert::Table)
+          .if_not_exists()
+          .col(pk_uuid(Alert::Id))
+          .col(uuid(Alert::RuleId))
+          .col(uuid(Alert::NodeId))
+          .col(text(Alert::Message))
+          .col(date_time(Alert::FiredAt))
+          .col(date_time_null(Alert::ResolvedAt))
+          .foreign_key(
+            ForeignKey::create()
+              .from(Alert::Table, Alert::RuleId)
+              .to(AlertRule::Table, AlertRule::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .foreign_key(
+            ForeignKey::create()
+              .from(Alert::Table, Alert::NodeId)
+              .to(Node::Table, Node::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_index(
+        Index::create()
+          .if_not_exists()
+          .name(ALERT_RULE_NODE_INDEX_NAME)
+          .table(Alert::Table)
+          .col(Alert::RuleId)
+          .col(Alert::NodeId)
+          .to_owned(),
+      )
+      .await
+  }
+
+  async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .drop_index(Index::drop().name(ALERT_RULE_NODE_INDEX_NAME).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(Alert::Table).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(AlertRuleChannel::Table).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(AlertRule::Table).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(AlertChannel::Table).to_owned())
+      .await
+  }
+}
+
+#[derive(DeriveIden)]
+enum Node {
+  Table,
+  Id,
+}
+
+#[derive(DeriveIden)]
+enum AlertChannel {
+  Table,
+  Id,
+  Name,
+  Kind,
+  Target,
+  CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum AlertRule {
+  Table,
+  Id,
+  Name,
+  Kind,
+  Threshold,
+  NodeId,
+  Enabled,
+  CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum AlertRuleChannel {
+  Table,
+  RuleId,
+  ChannelId,
+}
+
+#[derive(DeriveIden)]
+enum Alert {
+  Table,
+  Id,
+  RuleId,
+  NodeId,
+  Message,
+  FiredAt,
+  ResolvedAt,
+}
@@ -0,0 +1,143 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const WEBHOOK_DELIVERY_WEBHOOK_INDEX_NAME: &str = "webhook_delivery.webhook_id_created_at";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+  async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .create_table(
+        Table::create()
+          .table(Webhook::Table)
+          .if_not_exists()
+          .col(pk_uuid(Webhook::Id))
+          .col(string_uniq(Webhook::Name))
+          .col(string(Webhook::Url))
+          .col(string(Webhook::Secret))
+          .col(boolean(Webhook::Enabled))
+          .col(date_time(Webhook::CreatedAt))
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_table(
+        Table::create()
+          .table(WebhookEvent::Table)
+          .if_not_exists()
+          .primary_key(
+            Index::create()
+              .table(WebhookEvent::Table)
+              .col(WebhookEvent::WebhookId)
+              .col(WebhookEvent::Event),
+          )
+          .col(uuid(WebhookEvent::WebhookId))
+          .col(string(WebhookEvent::Event))
+          .foreign_key(
+            ForeignKey::create()
+              .from(WebhookEvent::Table, WebhookEvent::WebhookId)
+              .to(Webhook::Table, Webhook::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_table(
+        Table::create()
+          .table(WebhookDelivery::Table)
+          .if_not_exists()
+          .col(pk_uuid(WebhookDelivery::Id))
+          .col(uuid(WebhookDelivery::WebhookId))
+          .col(string(WebhookDelivery::Event))
+          .col(text(WebhookDelivery::Payload))
+          .col(integer(WebhookDelivery::Attempts))
+          .col(integer_null(WebhookDelivery::StatusCode))
+          .col(text_null(WebhookDelivery::Error))
+          .col(boolean(WebhookDelivery::Delivered))
+          .col(date_time(WebhookDelivery::CreatedAt))
+          .col(date_time(WebhookDelivery::UpdatedAt))
+          .foreign_key(
+            ForeignKey::create()
+              .from(WebhookDelivery::Table, WebhookDelivery::WebhookId)
+              .to(Webhook::Table, Webhook::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .create_index(
+        Index::create()
+          .if_not_exists()
+          .name(WEBHOOK_DELIVERY_WEBHOOK_INDEX_NAME)
+          .table(WebhookDelivery::Table)
+          .col(WebhookDelivery::WebhookId)
+          .col(WebhookDelivery::CreatedAt)
+          .to_owned(),
+      )
+      .await
+  }
+
+  async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .drop_index(
+        Index::drop()
+          .name(WEBHOOK_DELIVERY_WEBHOOK_INDEX_NAME)
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(WebhookDelivery::Table).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(WebhookEvent::Table).to_owned())
+      .await?;
+
+    manager
+      .drop_table(Table::drop().table(Webhook::Table).to_owned())
+      .await
+  }
+}
+
+#[derive(DeriveIden)]
+enum Webhook {
+  Table,
+  Id,
+  Name,
+  Url,
+  Secret,
+  Enabled,
+  CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum WebhookEvent {
+  Table,
+  WebhookId,
+  Event,
+}
+
+#[derive(DeriveIden)]
+enum WebhookDelivery {
+  Table,
+  Id,
+  WebhookId,
+  Event,
+  Payload,
+  Attempts,
+  StatusCode,
+  Error,
+  Delivered,
+  CreatedAt,
+  UpdatedAt,
+}
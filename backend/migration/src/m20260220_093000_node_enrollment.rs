@@ -0,0 +1,48 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+  async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .create_table(
+        Table::create()
+          .table(NodeEnrollment::Table)
+          .if_not_exists()
+          .col(pk_uuid(NodeEnrollment::NodeId))
+          .col(string_uniq(NodeEnrollment::CodeHash))
+          .col(date_time(NodeEnrollment::ExpiresAt))
+          .foreign_key(
+            ForeignKey::create()
+              .from(NodeEnrollment::Table, NodeEnrollment::NodeId)
+              .to(Node::Table, Node::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await
+  }
+
+  async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .drop_table(Table::drop().table(NodeEnrollment::Table).to_owned())
+      .await
+  }
+}
+
+#[derive(DeriveIden)]
+enum NodeEnrollment {
+  Table,
+  NodeId,
+  CodeHash,
+  ExpiresAt,
+}
+
+#[derive(DeriveIden)]
+enum Node {
+  Table,
+  Id,
+}
@@ -0,0 +1,50 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+  async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .create_table(
+        Table::create()
+          .table(NodeSync::Table)
+          .if_not_exists()
+          .col(pk_uuid(NodeSync::NodeId))
+          .col(date_time(NodeSync::RequestedAt))
+          .col(date_time_null(NodeSync::SyncedAt))
+          .col(text_null(NodeSync::Drift))
+          .foreign_key(
+            ForeignKey::create()
+              .from(NodeSync::Table, NodeSync::NodeId)
+              .to(Node::Table, Node::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await
+  }
+
+  async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .drop_table(Table::drop().table(NodeSync::Table).to_owned())
+      .await
+  }
+}
+
+#[derive(DeriveIden)]
+enum NodeSync {
+  Table,
+  NodeId,
+  RequestedAt,
+  SyncedAt,
+  Drift,
+}
+
+#[derive(DeriveIden)]
+enum Node {
+  Table,
+  Id,
+}
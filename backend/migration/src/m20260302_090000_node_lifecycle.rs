@@ -0,0 +1,75 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+  async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    // sqlite only supports a single column per alter statement
+    manager
+      .alter_table(
+        Table::alter()
+          .table(Node::Table)
+          // existing nodes were set up before the lifecycle was tracked
+          .add_column(string(Node::Lifecycle).default("enrolled"))
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .alter_table(
+        Table::alter()
+          .table(Node::Table)
+          .add_column(date_time_null(Node::FirstConnectedAt))
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .alter_table(
+        Table::alter()
+          .table(Node::Table)
+          .add_column(date_time_null(Node::LastSeenAt))
+          .to_owned(),
+      )
+      .await
+  }
+
+  async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .alter_table(
+        Table::alter()
+          .table(Node::Table)
+          .drop_column(Node::LastSeenAt)
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .alter_table(
+        Table::alter()
+          .table(Node::Table)
+          .drop_column(Node::FirstConnectedAt)
+          .to_owned(),
+      )
+      .await?;
+
+    manager
+      .alter_table(
+        Table::alter()
+          .table(Node::Table)
+          .drop_column(Node::Lifecycle)
+          .to_owned(),
+      )
+      .await
+  }
+}
+
+#[derive(DeriveIden)]
+enum Node {
+  Table,
+  Lifecycle,
+  FirstConnectedAt,
+  LastSeenAt,
+}
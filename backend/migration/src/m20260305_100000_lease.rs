@@ -0,0 +1,35 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+  async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .create_table(
+        Table::create()
+          .table(Lease::Table)
+          .if_not_exists()
+          .col(string(Lease::Name).primary_key())
+          .col(uuid(Lease::Holder))
+          .col(date_time(Lease::ExpiresAt))
+          .to_owned(),
+      )
+      .await
+  }
+
+  async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .drop_table(Table::drop().table(Lease::Table).to_owned())
+      .await
+  }
+}
+
+#[derive(DeriveIden)]
+enum Lease {
+  Table,
+  Name,
+  Holder,
+  ExpiresAt,
+}
@@ -0,0 +1,54 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+  async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .create_table(
+        Table::create()
+          .table(NodeNonce::Table)
+          .if_not_exists()
+          .primary_key(
+            Index::create()
+              .table(NodeNonce::Table)
+              .col(NodeNonce::NodeId)
+              .col(NodeNonce::Nonce),
+          )
+          .col(uuid(NodeNonce::NodeId))
+          .col(string(NodeNonce::Nonce))
+          .col(date_time(NodeNonce::UsedAt))
+          .foreign_key(
+            ForeignKey::create()
+              .from(NodeNonce::Table, NodeNonce::NodeId)
+              .to(Node::Table, Node::Id)
+              .on_delete(ForeignKeyAction::Cascade)
+              .on_update(ForeignKeyAction::Cascade),
+          )
+          .to_owned(),
+      )
+      .await
+  }
+
+  async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+    manager
+      .drop_table(Table::drop().table(NodeNonce::Table).to_owned())
+      .await
+  }
+}
+
+#[derive(DeriveIden)]
+enum NodeNonce {
+  Table,
+  NodeId,
+  Nonce,
+  UsedAt,
+}
+
+#[derive(DeriveIden)]
+enum Node {
+  Table,
+  Id,
+}
@@ -0,0 +1,177 @@
+use aide::axum::{
+  ApiRouter,
+  routing::{delete_with, get_with, post_with},
+};
+use axum::{Json, extract::Path};
+use centaurus::{bail, db::init::Connection, error::Result};
+use chrono::Utc;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use url::Url;
+use uuid::Uuid;
+
+use crate::{
+  alerts::notify::{Event, Notification, Notifier},
+  auth::jwt_auth::JwtAuth,
+  db::{
+    DBTrait,
+    alert::{AlertChannel, ChannelKind},
+  },
+  utils::{AlertEditPerm, AlertViewPerm, UpdateMessage, Updater},
+};
+
+pub fn router() -> ApiRouter {
+  ApiRouter::new()
+    .api_route(
+      "/channels",
+      post_with(create_channel, |op| op.id("createAlertChannel")),
+    )
+    .api_route(
+      "/channels",
+      get_with(list_channels, |op| op.id("listAlertChannels")),
+    )
+    .api_route(
+      "/channels",
+      delete_with(delete_channel, |op| op.id("deleteAlertChannel")),
+    )
+    .api_route(
+      "/channels/{uuid}/test",
+      post_with(test_channel, |op| op.id("testAlertChannel")),
+    )
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct CreateChannel {
+  name: String,
+  kind: ChannelKind,
+  /// Email address or webhook URL depending on `kind`.
+  target: String,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct CreateChannelRes {
+  uuid: Uuid,
+}
+
+async fn create_channel(
+  _auth: JwtAuth<AlertEditPerm>,
+  db: Connection,
+  updater: Updater,
+  Json(data): Json<CreateChannel>,
+) -> Result<Json<CreateChannelRes>> {
+  let name = data.name.trim().to_string();
+  if name.is_empty() {
+    bail!(BAD_REQUEST, "Alert channel name must not be empty");
+  }
+  if db.alert().find_channel_by_name(&name).await?.is_some() {
+    bail!(CONFLICT, "Alert channel with this name already exists");
+  }
+
+  let target = data.target.trim().to_string();
+  match data.kind {
+    ChannelKind::Email => {
+      if !target
+        .split_once('@')
+        .is_some_and(|(user, domain)| !user.is_empty() && !domain.is_empty())
+      {
+        bail!(BAD_REQUEST, "Invalid email address");
+      }
+    }
+    ChannelKind::Webhook => {
+      if !Url::parse(&target).is_ok_and(|url| matches!(url.scheme(), "http" | "https")) {
+        bail!(BAD_REQUEST, "Webhook URL must be a valid http(s) URL");
+      }
+    }
+  }
+
+  let id = Uuid::now_v7();
+  db.alert()
+    .create_channel(AlertChannel {
+      id,
+      name,
+      kind: data.kind,
+      target,
+      created_at: Utc::now().naive_utc(),
+    })
+    .await?;
+  info!("Created alert channel with ID {}", id);
+
+  updater.broadcast(UpdateMessage::Alerts).await;
+
+  Ok(Json(CreateChannelRes { uuid: id }))
+}
+
+#[derive(Serialize, JsonSchema)]
+struct ChannelInfo {
+  uuid: Uuid,
+  name: String,
+  kind: ChannelKind,
+  target: String,
+}
+
+async fn list_channels(
+  _auth: JwtAuth<AlertViewPerm>,
+  db: Connection,
+) -> Result<Json<Vec<ChannelInfo>>> {
+  let channels = db.alert().list_channels().await?;
+
+  Ok(Json(
+    channels
+      .into_iter()
+      .map(|channel| ChannelInfo {
+        uuid: channel.id,
+        name: channel.name,
+        kind: channel.kind,
+        target: channel.target,
+      })
+      .collect(),
+  ))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct DeleteChannel {
+  uuid: Uuid,
+}
+
+async fn delete_channel(
+  _auth: JwtAuth<AlertEditPerm>,
+  db: Connection,
+  updater: Updater,
+  Json(data): Json<DeleteChannel>,
+) -> Result<()> {
+  db.alert().find_channel(data.uuid).await?;
+  db.alert().delete_channel(data.uuid).await?;
+  info!("Deleted alert channel with ID {}", data.uuid);
+
+  updater.broadcast(UpdateMessage::Alerts).await;
+
+  Ok(())
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct TestChannelRequest {
+  uuid: Uuid,
+}
+
+async fn test_channel(
+  _auth: JwtAuth<AlertEditPerm>,
+  db: Connection,
+  notifier: Notifier,
+  Path(req): Path<TestChannelRequest>,
+) -> Result<()> {
+  let channel = db.alert().find_channel(req.uuid).await?;
+
+  let notification = Notification {
+    event: Event::Test,
+    alert: None,
+    rule: None,
+    rule_name: "Test notification".to_string(),
+    node: None,
+    node_name: None,
+    message: format!("Alert channel {} is set up correctly", channel.name),
+    fired_at: Utc::now(),
+    resolved_at: None,
+  };
+  notifier.send(&channel, &notification).await
+}
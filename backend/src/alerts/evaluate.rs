@@ -0,0 +1,304 @@
+use std::{collections::HashMap, time::Duration};
+
+use centaurus::{db::init::Connection, error::Result};
+use chrono::{DateTime, TimeDelta, Utc};
+use serde_json::json;
+use shared::msg::NodeStats;
+use tokio::{spawn, time::interval};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::{
+  alerts::notify::{Event, Notification, Notifier},
+  cluster::Cluster,
+  db::{
+    DBTrait,
+    alert::{Alert, AlertChannel, AlertCondition, AlertRule},
+    node::Node,
+    node_sample::Resolution,
+    webhook::WebhookEvent,
+  },
+  nodes::Wings,
+  utils::{UpdateMessage, Updater},
+  webhooks::Webhooks,
+};
+
+const EVALUATE_INTERVAL: Duration = Duration::from_secs(30);
+/// Older stats are treated as unknown instead of as the current usage.
+const STATS_MAX_AGE: TimeDelta = TimeDelta::minutes(5);
+
+/// Only the leader evaluates, so every alert fires once for all instances.
+pub fn spawn_evaluator(
+  db: Connection,
+  cluster: Cluster,
+  wings: Wings,
+  notifier: Notifier,
+  updater: Updater,
+  webhooks: Webhooks,
+) {
+  spawn(async move {
+    let mut interval = interval(EVALUATE_INTERVAL);
+    loop {
+      interval.tick().await;
+      if !cluster.is_leader() {
+        continue;
+      }
+      if let Err(err) = evaluate(&db, &wings, &notifier, &updater, &webhooks, Utc::now()).await {
+        warn!("Failed to evaluate alert rules: {:?}", err);
+      }
+    }
+  });
+}
+
+struct NodeStatus {
+  maintenance: bool,
+  disconnected_for: Option<TimeDelta>,
+  stats: Option<NodeStats>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Check {
+  Firing(String),
+  Ok,
+  /// Not enough data to decide, the alert keeps its current state.
+  Unknown,
+}
+
+fn check(condition: AlertCondition, status: &NodeStatus) -> Check {
+  match condition {
+    AlertCondition::NodeDisconnected { minutes } => {
+      if status.maintenance {
+        return Check::Ok;
+      }
+      match status.disconnected_for {
+        Some(duration) if duration >= TimeDelta::minutes(minutes as i64) => Check::Firing(format!(
+          "Node has been disconnected for {} minutes",
+          duration.num_minutes()
+        )),
+        _ => Check::Ok,
+      }
+    }
+    AlertCondition::DiskUsage { percent } => {
+      let Some(stats) = status.stats else {
+        return Check::Unknown;
+      };
+      if stats.disk_total_mb <= 0.0 {
+        return Check::Unknown;
+      }
+
+      let usage = stats.disk_used_mb / stats.disk_total_mb * 100.0;
+      if usage >= percent {
+        Check::Firing(format!(
+          "Disk usage is at {:.1}% ({:.0} of {:.0} MB)",
+          usage, stats.disk_used_mb, stats.disk_total_mb
+        ))
+      } else {
+        Check::Ok
+      }
+    }
+  }
+}
+
+async fn evaluate(
+  db: &Connection,
+  wings: &Wings,
+  notifier: &Notifier,
+  updater: &Updater,
+  webhooks: &Webhooks,
+  now: DateTime<Utc>,
+) -> Result<()> {
+  let rules = db.alert().list_rules().await?;
+  let mut rule_channels = db.alert().rule_channels().await?;
+  let channels: HashMap<Uuid, AlertChannel> = db
+    .alert()
+    .list_channels()
+    .await?
+    .into_iter()
+    .map(|channel| (channel.id, channel))
+    .collect();
+  let mut open: HashMap<(Uuid, Uuid), Alert> = db
+    .alert()
+    .open_alerts()
+    .await?
+    .into_iter()
+    .map(|alert| ((alert.rule_id, alert.node_id), alert))
+    .collect();
+
+  let nodes = db.node().list_nodes().await?;
+  let mut statuses = HashMap::new();
+  for node in &nodes {
+    let stats = db
+      .node_sample()
+      .latest_sample(node.id, Resolution::Raw)
+      .await?
+      .filter(|sample| sample.timestamp >= (now - STATS_MAX_AGE).naive_utc())
+      .map(|sample| sample.stats);
+
+    statuses.insert(
+      node.id,
+      NodeStatus {
+        maintenance: node.maintenance,
+        disconnected_for: wings
+          .disconnected_since(node.id)
+          .await
+          .map(|since| now - since),
+        stats,
+      },
+    );
+  }
+
+  let mut changed = false;
+  for rule in rules {
+    let targets = rule_channels
+      .remove(&rule.id)
+      .unwrap_or_default()
+      .into_iter()
+      .filter_map(|id| channels.get(&id))
+      .collect::<Vec<_>>();
+
+    for node in nodes
+      .iter()
+      .filter(|node| rule.node_id.is_none_or(|id| id == node.id))
+    {
+      let key = (rule.id, node.id);
+      let result = if rule.enabled {
+        check(rule.condition, &statuses[&node.id])
+      } else {
+        Check::Ok
+      };
+
+      match result {
+        Check::Firing(message) => {
+          if open.remove(&key).is_some() {
+            continue;
+          }
+          let alert = Alert {
+            id: Uuid::now_v7(),
+            rule_id: rule.id,
+            node_id: node.id,
+            message,
+            fired_at: now.naive_utc(),
+            resolved_at: None,
+          };
+          db.alert().create_alert(alert.clone()).await?;
+          info!("Alert {} fired for node {}", rule.name, node.name);
+
+          let notification = notification(Event::Firing, &alert, &rule, node);
+          webhooks.emit(WebhookEvent::AlertFired, json!(notification));
+          notify(notifier, &targets, notification).await;
+          changed = true;
+        }
+        Check::Ok => {
+          let Some(mut alert) = open.remove(&key) else {
+            continue;
+          };
+          db.alert().resolve_alert(alert.id, now.naive_utc()).await?;
+          alert.resolved_at = Some(now.naive_utc());
+          info!("Alert {} resolved for node {}", rule.name, node.name);
+
+          // disabling a rule resolves its alerts without notifying anyone
+          if rule.enabled {
+            let notification = notification(Event::Resolved, &alert, &rule, node);
+            webhooks.emit(WebhookEvent::AlertResolved, json!(notification));
+            notify(notifier, &targets, notification).await;
+          }
+          changed = true;
+        }
+        Check::Unknown => {
+          open.remove(&key);
+        }
+      }
+    }
+  }
+
+  // left over alerts belong to nodes the rule no longer applies to
+  for alert in open.into_values() {
+    db.alert().resolve_alert(alert.id, now.naive_utc()).await?;
+    changed = true;
+  }
+
+  if changed {
+    updater.broadcast(UpdateMessage::Alerts).await;
+  }
+
+  Ok(())
+}
+
+fn notification(event: Event, alert: &Alert, rule: &AlertRule, node: &Node) -> Notification {
+  Notification {
+    event,
+    alert: Some(alert.id),
+    rule: Some(rule.id),
+    rule_name: rule.name.clone(),
+    node: Some(node.id),
+    node_name: Some(node.name.clone()),
+    message: alert.message.clone(),
+    fired_at: alert.fired_at.and_utc(),
+    resolved_at: alert.resolved_at.map(|at| at.and_utc()),
+  }
+}
+
+/// Failed deliveries are only logged, the alert state is kept regardless so
+/// a flaky channel does not cause repeated notifications.
+async fn notify(notifier: &Notifier, channels: &[&AlertChannel], notification: Notification) {
+  for channel in channels {
+    if let Err(err) = notifier.send(channel, &notification).await {
+      warn!(
+        "Failed to send alert notification to channel {}: {:?}",
+        channel.name, err
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn status(disconnected_minutes: Option<i64>, disk: Option<(f64, f64)>) -> NodeStatus {
+    NodeStatus {
+      maintenance: false,
+      disconnected_for: disconnected_minutes.map(TimeDelta::minutes),
+      stats: disk.map(|(used, total)| NodeStats {
+        disk_used_mb: used,
+        disk_total_mb: total,
+        ..Default::default()
+      }),
+    }
+  }
+
+  #[test]
+  fn disconnected_fires_after_threshold() {
+    let condition = AlertCondition::NodeDisconnected { minutes: 5 };
+
+    assert_eq!(check(condition, &status(None, None)), Check::Ok);
+    assert_eq!(check(condition, &status(Some(4), None)), Check::Ok);
+    assert!(matches!(
+      check(condition, &status(Some(5), None)),
+      Check::Firing(_)
+    ));
+
+    let mut maintenance = status(Some(60), None);
+    maintenance.maintenance = true;
+    assert_eq!(check(condition, &maintenance), Check::Ok);
+  }
+
+  #[test]
+  fn disk_usage_needs_recent_stats() {
+    let condition = AlertCondition::DiskUsage { percent: 90.0 };
+
+    assert_eq!(check(condition, &status(None, None)), Check::Unknown);
+    assert_eq!(
+      check(condition, &status(None, Some((10.0, 0.0)))),
+      Check::Unknown
+    );
+    assert_eq!(
+      check(condition, &status(None, Some((800.0, 1000.0)))),
+      Check::Ok
+    );
+    assert_eq!(
+      check(condition, &status(None, Some((950.0, 1000.0)))),
+      Check::Firing("Disk usage is at 95.0% (950 of 1000 MB)".to_string())
+    );
+  }
+}
@@ -0,0 +1,39 @@
+use aide::axum::ApiRouter;
+use axum::Extension;
+use centaurus::db::init::Connection;
+
+use crate::{
+  alerts::notify::Notifier, cluster::Cluster, config::Config, nodes::Wings, utils::Updater,
+  webhooks::Webhooks,
+};
+
+mod channels;
+mod evaluate;
+mod notify;
+mod rules;
+
+pub fn router() -> ApiRouter {
+  rules::router().merge(channels::router())
+}
+
+pub fn state(
+  router: ApiRouter,
+  config: &Config,
+  db: &Connection,
+  cluster: &Cluster,
+  wings: Wings,
+  updater: Updater,
+  webhooks: Webhooks,
+) -> ApiRouter {
+  let notifier = Notifier::new(db, config);
+  evaluate::spawn_evaluator(
+    db.clone(),
+    cluster.clone(),
+    wings,
+    notifier.clone(),
+    updater,
+    webhooks,
+  );
+
+  router.layer(Extension(notifier))
+}
@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use aide::OperationIo;
+use axum::{Extension, extract::FromRequestParts};
+use centaurus::{
+  bail,
+  db::{init::Connection, tables::ConnectionExt},
+  error::{ErrorReportStatusExt, Result},
+  mail::{MailSettings, Mailer},
+  overwrite_with_env_config,
+};
+use chrono::{DateTime, Utc};
+use http::StatusCode;
+use reqwest::Client;
+use schemars::JsonSchema;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+  config::Config,
+  db::alert::{AlertChannel, ChannelKind},
+};
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Event {
+  Firing,
+  Resolved,
+  Test,
+}
+
+/// Body of webhook deliveries, also rendered into emails.
+#[derive(Serialize, Clone, Debug, JsonSchema)]
+pub struct Notification {
+  pub event: Event,
+  pub alert: Option<Uuid>,
+  pub rule: Option<Uuid>,
+  pub rule_name: String,
+  pub node: Option<Uuid>,
+  pub node_name: Option<String>,
+  pub message: String,
+  pub fired_at: DateTime<Utc>,
+  pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl Notification {
+  fn subject(&self) -> String {
+    let state = match self.event {
+      Event::Firing => "Firing",
+      Event::Resolved => "Resolved",
+      Event::Test => "Test",
+    };
+    match &self.node_name {
+      Some(node) => format!("[{}] {} on {}", state, self.rule_name, node),
+      None => format!("[{}] {}", state, self.rule_name),
+    }
+  }
+
+  fn html(&self) -> String {
+    let mut body = format!("<p>{}</p>", escape(&self.message));
+    body.push_str(&format!(
+      "<p>Fired at {}</p>",
+      self.fired_at.format("%Y-%m-%d %H:%M:%S UTC")
+    ));
+    if let Some(resolved_at) = self.resolved_at {
+      body.push_str(&format!(
+        "<p>Resolved at {}</p>",
+        resolved_at.format("%Y-%m-%d %H:%M:%S UTC")
+      ));
+    }
+    body
+  }
+}
+
+/// Delivers notifications to alert channels.
+#[derive(Clone, FromRequestParts, OperationIo)]
+#[from_request(via(Extension))]
+pub struct Notifier {
+  db: Connection,
+  /// Mail settings from the environment, they take precedence over the stored ones.
+  mail: MailSettings,
+  client: Client,
+}
+
+impl Notifier {
+  pub fn new(db: &Connection, config: &Config) -> Self {
+    let client = Client::builder()
+      .timeout(WEBHOOK_TIMEOUT)
+      .build()
+      .expect("Failed to build webhook client");
+
+    Self {
+      db: db.clone(),
+      mail: config.mail.clone(),
+      client,
+    }
+  }
+
+  pub async fn send(&self, channel: &AlertChannel, notification: &Notification) -> Result<()> {
+    match channel.kind {
+      ChannelKind::Email => self.send_mail(channel, notification).await,
+      ChannelKind::Webhook => {
+        self
+          .client
+          .post(&channel.target)
+          .json(notification)
+          .send()
+          .await
+          .and_then(|res| res.error_for_status())
+          .status_context(StatusCode::BAD_GATEWAY, "Failed to deliver webhook")?;
+        Ok(())
+      }
+    }
+  }
+
+  /// The mailer is created per delivery so changes to the mail settings are
+  /// picked up without a restart.
+  async fn send_mail(&self, channel: &AlertChannel, notification: &Notification) -> Result<()> {
+    let mut settings: MailSettings = self.db.settings().get_settings().await.unwrap_or_default();
+    let mail = Some(&self.mail);
+
+    overwrite_with_env_config!(
+      settings,
+      mail,
+      smtp_server,
+      smtp_port,
+      smtp_username,
+      smtp_password,
+      smtp_from_address,
+      smtp_from_name,
+      smtp_use_tls,,
+      smtp_enabled
+    );
+
+    let mailer = Mailer::new(settings).await;
+    if !mailer.is_active().await {
+      bail!(BAD_REQUEST, "Mail is not configured");
+    }
+
+    mailer
+      .send_mail(
+        channel.name.clone(),
+        channel.target.clone(),
+        notification.subject(),
+        notification.html(),
+      )
+      .await
+  }
+}
+
+fn escape(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
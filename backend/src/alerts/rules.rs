@@ -0,0 +1,260 @@
+use aide::axum::{
+  ApiRouter,
+  routing::{delete_with, get_with, post_with},
+};
+use axum::{
+  Json,
+  extract::{Path, Query},
+};
+use centaurus::{bail, db::init::Connection, error::Result};
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+  auth::jwt_auth::JwtAuth,
+  db::{
+    DBTrait,
+    alert::{AlertCondition, AlertRule},
+  },
+  utils::{AlertEditPerm, AlertViewPerm, UpdateMessage, Updater},
+};
+
+const DEFAULT_ALERT_LIMIT: u64 = 100;
+
+pub fn router() -> ApiRouter {
+  ApiRouter::new()
+    .api_route("/", get_with(list_alerts, |op| op.id("listAlerts")))
+    .api_route(
+      "/rules",
+      post_with(create_rule, |op| op.id("createAlertRule")),
+    )
+    .api_route("/rules", get_with(list_rules, |op| op.id("listAlertRules")))
+    .api_route(
+      "/rules",
+      delete_with(delete_rule, |op| op.id("deleteAlertRule")),
+    )
+    .api_route(
+      "/rules/{uuid}",
+      post_with(update_rule, |op| op.id("updateAlertRule")),
+    )
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct RuleData {
+  name: String,
+  condition: AlertCondition,
+  /// Restricts the rule to a single node, otherwise all nodes are checked.
+  #[serde(default)]
+  node: Option<Uuid>,
+  #[serde(default = "enabled_default")]
+  enabled: bool,
+  #[serde(default)]
+  channels: Vec<Uuid>,
+}
+
+fn enabled_default() -> bool {
+  true
+}
+
+/// Checks everything but the name and returns the normalized channels.
+async fn validate(db: &Connection, data: &RuleData) -> Result<Vec<Uuid>> {
+  match data.condition {
+    AlertCondition::NodeDisconnected { minutes } => {
+      if minutes == 0 {
+        bail!(BAD_REQUEST, "Disconnect duration must be at least a minute");
+      }
+    }
+    AlertCondition::DiskUsage { percent } => {
+      if !(percent > 0.0 && percent <= 100.0) {
+        bail!(
+          BAD_REQUEST,
+          "Disk usage threshold must be between 0 and 100"
+        );
+      }
+    }
+  }
+
+  if let Some(node) = data.node {
+    db.node().find_by_id(node).await?;
+  }
+
+  let mut channels = data.channels.clone();
+  channels.sort();
+  channels.dedup();
+  for channel in &channels {
+    db.alert().find_channel(*channel).await?;
+  }
+
+  Ok(channels)
+}
+
+#[derive(Serialize, JsonSchema)]
+struct CreateRuleRes {
+  uuid: Uuid,
+}
+
+async fn create_rule(
+  _auth: JwtAuth<AlertEditPerm>,
+  db: Connection,
+  updater: Updater,
+  Json(data): Json<RuleData>,
+) -> Result<Json<CreateRuleRes>> {
+  let name = data.name.trim().to_string();
+  if name.is_empty() {
+    bail!(BAD_REQUEST, "Alert rule name must not be empty");
+  }
+  if db.alert().find_rule_by_name(&name).await?.is_some() {
+    bail!(CONFLICT, "Alert rule with this name already exists");
+  }
+  let channels = validate(&db, &data).await?;
+
+  let id = Uuid::now_v7();
+  db.alert()
+    .create_rule(AlertRule {
+      id,
+      name,
+      condition: data.condition,
+      node_id: data.node,
+      enabled: data.enabled,
+      created_at: Utc::now().naive_utc(),
+    })
+    .await?;
+  db.alert().set_rule_channels(id, channels).await?;
+  info!("Created alert rule with ID {}", id);
+
+  updater.broadcast(UpdateMessage::Alerts).await;
+
+  Ok(Json(CreateRuleRes { uuid: id }))
+}
+
+#[derive(Serialize, JsonSchema)]
+struct RuleInfo {
+  uuid: Uuid,
+  name: String,
+  condition: AlertCondition,
+  node: Option<Uuid>,
+  enabled: bool,
+  channels: Vec<Uuid>,
+}
+
+async fn list_rules(_auth: JwtAuth<AlertViewPerm>, db: Connection) -> Result<Json<Vec<RuleInfo>>> {
+  let rules = db.alert().list_rules().await?;
+  let mut channels = db.alert().rule_channels().await?;
+
+  Ok(Json(
+    rules
+      .into_iter()
+      .map(|rule| RuleInfo {
+        uuid: rule.id,
+        channels: channels.remove(&rule.id).unwrap_or_default(),
+        name: rule.name,
+        condition: rule.condition,
+        node: rule.node_id,
+        enabled: rule.enabled,
+      })
+      .collect(),
+  ))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct RuleRequest {
+  uuid: Uuid,
+}
+
+async fn update_rule(
+  _auth: JwtAuth<AlertEditPerm>,
+  db: Connection,
+  updater: Updater,
+  Path(req): Path<RuleRequest>,
+  Json(data): Json<RuleData>,
+) -> Result<()> {
+  let rule = db.alert().find_rule(req.uuid).await?;
+
+  let name = data.name.trim().to_string();
+  if name.is_empty() {
+    bail!(BAD_REQUEST, "Alert rule name must not be empty");
+  }
+  if let Some(other) = db.alert().find_rule_by_name(&name).await?
+    && other.id != rule.id
+  {
+    bail!(CONFLICT, "Alert rule with this name already exists");
+  }
+  let channels = validate(&db, &data).await?;
+
+  db.alert()
+    .update_rule(AlertRule {
+      name,
+      condition: data.condition,
+      node_id: data.node,
+      enabled: data.enabled,
+      ..rule
+    })
+    .await?;
+  db.alert().set_rule_channels(req.uuid, channels).await?;
+  info!("Updated alert rule with ID {}", req.uuid);
+
+  updater.broadcast(UpdateMessage::Alerts).await;
+
+  Ok(())
+}
+
+async fn delete_rule(
+  _auth: JwtAuth<AlertEditPerm>,
+  db: Connection,
+  updater: Updater,
+  Json(data): Json<RuleRequest>,
+) -> Result<()> {
+  db.alert().find_rule(data.uuid).await?;
+  db.alert().delete_rule(data.uuid).await?;
+  info!("Deleted alert rule with ID {}", data.uuid);
+
+  updater.broadcast(UpdateMessage::Alerts).await;
+
+  Ok(())
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ListAlerts {
+  /// Only alerts that are still firing.
+  #[serde(default)]
+  open: bool,
+  limit: Option<u64>,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct AlertInfo {
+  uuid: Uuid,
+  rule: Uuid,
+  node: Uuid,
+  message: String,
+  fired_at: DateTime<Utc>,
+  resolved_at: Option<DateTime<Utc>>,
+}
+
+async fn list_alerts(
+  _auth: JwtAuth<AlertViewPerm>,
+  db: Connection,
+  Query(query): Query<ListAlerts>,
+) -> Result<Json<Vec<AlertInfo>>> {
+  let alerts = db
+    .alert()
+    .list_alerts(query.open, query.limit.unwrap_or(DEFAULT_ALERT_LIMIT))
+    .await?;
+
+  Ok(Json(
+    alerts
+      .into_iter()
+      .map(|alert| AlertInfo {
+        uuid: alert.id,
+        rule: alert.rule_id,
+        node: alert.node_id,
+        message: alert.message,
+        fired_at: alert.fired_at.and_utc(),
+        resolved_at: alert.resolved_at.map(|at| at.and_utc()),
+      })
+      .collect(),
+  ))
+}
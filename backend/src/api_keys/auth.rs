@@ -0,0 +1,150 @@
+use std::time::Duration;
+
+use axum::{
+  extract::{OriginalUri, Request},
+  middleware::Next,
+  response::Response,
+};
+use centaurus::{
+  backend::auth::jwt_state::JwtState,
+  bail,
+  db::init::Connection,
+  error::{ErrorReportStatusExt, Result},
+};
+use chrono::{TimeDelta, Utc};
+use http::{HeaderValue, StatusCode, header::AUTHORIZATION, request::Parts};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use tokio::{spawn, time::interval};
+use tracing::{debug, warn};
+
+use crate::{cluster::Cluster, db::DBTrait};
+
+pub const API_KEY_PREFIX: &str = "smaug_";
+/// Number of characters of the raw key (including [`API_KEY_PREFIX`]) that are
+/// stored in plain text so users can tell their keys apart.
+const DISPLAY_PREFIX_LEN: usize = API_KEY_PREFIX.len() + 8;
+/// Every request made with a key is recorded, so the log is only kept for a
+/// while.
+const USAGE_RETENTION: TimeDelta = TimeDelta::days(30);
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Attached to requests that were authenticated with an API key instead of a
+/// session. Session requests carry no scope and are only limited by the
+/// permissions of the user's groups.
+#[derive(Clone, Debug)]
+pub struct ApiKeyScope {
+  pub permissions: Vec<String>,
+}
+
+impl ApiKeyScope {
+  pub fn allows(parts: &Parts, permission: &str) -> bool {
+    parts
+      .extensions
+      .get::<Self>()
+      .is_none_or(|scope| scope.permissions.iter().any(|p| p == permission))
+  }
+}
+
+pub struct GeneratedKey {
+  pub key: String,
+  pub prefix: String,
+  pub hash: String,
+}
+
+pub fn generate_key() -> GeneratedKey {
+  let mut raw_key = [0u8; 32];
+  rand::rng().fill_bytes(&mut raw_key);
+  let key = format!("{}{}", API_KEY_PREFIX, hex::encode(raw_key));
+
+  GeneratedKey {
+    prefix: key[..DISPLAY_PREFIX_LEN].to_string(),
+    hash: hash_key(&key),
+    key,
+  }
+}
+
+pub fn hash_key(key: &str) -> String {
+  hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+/// Resolves `Authorization: Bearer smaug_...` headers to the owning user.
+///
+/// The key is swapped for a freshly signed session token of its owner so the
+/// regular `JwtAuth` extractors keep working unchanged, while the attached
+/// [`ApiKeyScope`] limits the permissions that can be used with it.
+pub async fn api_key_auth(mut req: Request, next: Next) -> Result<Response> {
+  let Some(key) = req
+    .headers()
+    .get(AUTHORIZATION)
+    .and_then(|value| value.to_str().ok())
+    .and_then(|value| value.strip_prefix("Bearer "))
+    .filter(|value| value.starts_with(API_KEY_PREFIX))
+  else {
+    return Ok(next.run(req).await);
+  };
+
+  let db = req
+    .extensions()
+    .get::<Connection>()
+    .cloned()
+    .status_context(StatusCode::INTERNAL_SERVER_ERROR, "Missing database state")?;
+  let jwt = req
+    .extensions()
+    .get::<JwtState>()
+    .cloned()
+    .status_context(StatusCode::INTERNAL_SERVER_ERROR, "Missing jwt state")?;
+
+  let Some(api_key) = db.api_key().find_by_hash(&hash_key(key)).await? else {
+    bail!(UNAUTHORIZED, "invalid api key");
+  };
+
+  if let Some(expires_at) = api_key.expires_at
+    && expires_at <= Utc::now().naive_utc()
+  {
+    bail!(UNAUTHORIZED, "api key expired");
+  }
+
+  let token = jwt.create_raw_token(api_key.user_id)?;
+  let header = HeaderValue::from_str(&format!("Bearer {}", token))
+    .status_context(StatusCode::INTERNAL_SERVER_ERROR, "Invalid session token")?;
+  req.headers_mut().insert(AUTHORIZATION, header);
+
+  let method = req.method().to_string();
+  let path = req
+    .extensions()
+    .get::<OriginalUri>()
+    .map(|uri| uri.path())
+    .unwrap_or(req.uri().path())
+    .to_string();
+  debug!(
+    "Request {} {} authenticated with api key {}",
+    method, path, api_key.id
+  );
+  db.api_key().record_usage(api_key.id, method, path).await?;
+
+  req.extensions_mut().insert(ApiKeyScope {
+    permissions: api_key.permissions,
+  });
+
+  Ok(next.run(req).await)
+}
+
+/// Only the leader prunes, the usage log is shared by all instances.
+pub fn spawn_prune(db: Connection, cluster: Cluster) {
+  spawn(async move {
+    let mut interval = interval(PRUNE_INTERVAL);
+    loop {
+      interval.tick().await;
+      if !cluster.is_leader() {
+        continue;
+      }
+      let before = (Utc::now() - USAGE_RETENTION).naive_utc();
+      match db.api_key().prune_usage(before).await {
+        Ok(pruned) if pruned > 0 => debug!("Pruned {} api key usage records", pruned),
+        Ok(_) => (),
+        Err(err) => warn!("Failed to prune api key usage: {:?}", err),
+      }
+    }
+  });
+}
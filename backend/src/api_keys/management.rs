@@ -0,0 +1,187 @@
+use aide::axum::{
+  ApiRouter,
+  routing::{delete_with, get_with, post_with},
+};
+use axum::{Json, extract::Path};
+use centaurus::{
+  bail,
+  db::{init::Connection, tables::ConnectionExt},
+  error::Result,
+};
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+  api_keys::auth::generate_key,
+  auth::jwt_auth::JwtAuth,
+  db::{DBTrait, api_key::ApiKey},
+  utils,
+};
+
+const USAGE_LIMIT: u64 = 100;
+
+pub fn router() -> ApiRouter {
+  ApiRouter::new()
+    .api_route("/", post_with(create_api_key, |op| op.id("createApiKey")))
+    .api_route("/", get_with(list_api_keys, |op| op.id("listApiKeys")))
+    .api_route("/", delete_with(revoke_api_key, |op| op.id("revokeApiKey")))
+    .api_route(
+      "/{uuid}/usage",
+      get_with(api_key_usage, |op| op.id("apiKeyUsage")),
+    )
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct CreateApiKey {
+  name: String,
+  permissions: Vec<String>,
+  expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct CreateApiKeyRes {
+  uuid: Uuid,
+  /// The full key, only returned once on creation.
+  key: String,
+}
+
+async fn create_api_key(
+  auth: JwtAuth,
+  db: Connection,
+  Json(data): Json<CreateApiKey>,
+) -> Result<Json<CreateApiKeyRes>> {
+  if data.name.trim().is_empty() {
+    bail!(BAD_REQUEST, "API key name must not be empty");
+  }
+
+  if let Some(expires_at) = data.expires_at
+    && expires_at <= Utc::now()
+  {
+    bail!(BAD_REQUEST, "API key expiry must be in the future");
+  }
+
+  let known = utils::permissions();
+  let owned = db.group().get_user_permissions(auth.user_id).await?;
+  let mut permissions = Vec::new();
+  for permission in data.permissions {
+    if !known.contains(&permission.as_str()) {
+      bail!(BAD_REQUEST, "Unknown permission {}", permission);
+    }
+    if !owned.contains(&permission) {
+      bail!(
+        FORBIDDEN,
+        "Cannot grant permission {} you do not have",
+        permission
+      );
+    }
+    if !permissions.contains(&permission) {
+      permissions.push(permission);
+    }
+  }
+
+  let generated = generate_key();
+  let id = Uuid::now_v7();
+
+  db.api_key()
+    .create_key(ApiKey {
+      id,
+      user_id: auth.user_id,
+      name: data.name,
+      prefix: generated.prefix,
+      hash: generated.hash,
+      created_at: Utc::now().naive_utc(),
+      expires_at: data.expires_at.map(|exp| exp.naive_utc()),
+      last_used_at: None,
+      permissions,
+    })
+    .await?;
+  info!("Created api key {} for user {}", id, auth.user_id);
+
+  Ok(Json(CreateApiKeyRes {
+    uuid: id,
+    key: generated.key,
+  }))
+}
+
+#[derive(Serialize, JsonSchema)]
+struct ApiKeyInfo {
+  id: Uuid,
+  name: String,
+  prefix: String,
+  permissions: Vec<String>,
+  created_at: DateTime<Utc>,
+  expires_at: Option<DateTime<Utc>>,
+  last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<ApiKey> for ApiKeyInfo {
+  fn from(key: ApiKey) -> Self {
+    Self {
+      id: key.id,
+      name: key.name,
+      prefix: key.prefix,
+      permissions: key.permissions,
+      created_at: key.created_at.and_utc(),
+      expires_at: key.expires_at.map(|exp| exp.and_utc()),
+      last_used_at: key.last_used_at.map(|used| used.and_utc()),
+    }
+  }
+}
+
+async fn list_api_keys(auth: JwtAuth, db: Connection) -> Result<Json<Vec<ApiKeyInfo>>> {
+  let keys = db.api_key().list_for_user(auth.user_id).await?;
+  Ok(Json(keys.into_iter().map(ApiKeyInfo::from).collect()))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct RevokeApiKey {
+  uuid: Uuid,
+}
+
+async fn revoke_api_key(
+  auth: JwtAuth,
+  db: Connection,
+  Json(data): Json<RevokeApiKey>,
+) -> Result<()> {
+  let key = db.api_key().find_for_user(data.uuid, auth.user_id).await?;
+
+  db.api_key().delete_key(key.id).await?;
+  info!("Revoked api key {} of user {}", key.id, auth.user_id);
+
+  Ok(())
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ApiKeyRequest {
+  uuid: Uuid,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct ApiKeyUsage {
+  method: String,
+  path: String,
+  created_at: DateTime<Utc>,
+}
+
+async fn api_key_usage(
+  auth: JwtAuth,
+  db: Connection,
+  Path(req): Path<ApiKeyRequest>,
+) -> Result<Json<Vec<ApiKeyUsage>>> {
+  let key = db.api_key().find_for_user(req.uuid, auth.user_id).await?;
+  let usage = db.api_key().list_usage(key.id, USAGE_LIMIT).await?;
+
+  Ok(Json(
+    usage
+      .into_iter()
+      .map(|usage| ApiKeyUsage {
+        method: usage.method,
+        path: usage.path,
+        created_at: usage.created_at.and_utc(),
+      })
+      .collect(),
+  ))
+}
@@ -0,0 +1,25 @@
+use aide::axum::ApiRouter;
+use axum::middleware::from_fn;
+use centaurus::db::init::Connection;
+
+use crate::cluster::Cluster;
+
+pub use auth::ApiKeyScope;
+
+mod auth;
+mod management;
+
+pub fn router() -> ApiRouter {
+  management::router()
+}
+
+pub fn state(db: &Connection, cluster: &Cluster) {
+  auth::spawn_prune(db.clone(), cluster.clone());
+}
+
+/// Allows the routes of `router` to be called with an API key in addition to a
+/// session. Key management itself is intentionally not wrapped so a leaked key
+/// can not be used to mint further keys.
+pub fn accept(router: ApiRouter) -> ApiRouter {
+  router.layer(from_fn(auth::api_key_auth))
+}
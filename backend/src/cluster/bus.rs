@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use sea_orm::sqlx::{
+  self, PgPool,
+  postgres::{PgListener, PgNotification},
+};
+use serde::{Deserialize, Serialize};
+use tokio::{spawn, sync::broadcast, time::sleep};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::utils::UpdateMessage;
+
+const CHANNEL: &str = "smaug_cluster";
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+  /// An update for the websocket sessions connected to the other instances.
+  Update { message: UpdateMessage },
+  /// A call for the instance that owns the connection of the node.
+  Wings { node: Uuid, call: WingsCall },
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WingsCall {
+  /// The node changed, the owner reloads it from the database.
+  Reconnect,
+  Disconnect,
+  Sync,
+  Maintenance {
+    enabled: bool,
+  },
+}
+
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+  origin: Uuid,
+  event: Event,
+}
+
+/// Postgres notifications between the instances. Notifications are not
+/// persisted, instances that are not listening miss them.
+#[derive(Clone)]
+pub struct Bus {
+  pool: PgPool,
+  instance: Uuid,
+  events: broadcast::Sender<Event>,
+}
+
+impl Bus {
+  pub async fn connect(pool: &PgPool, instance: Uuid) -> Result<Self, sqlx::Error> {
+    let mut listener = PgListener::connect_with(pool).await?;
+    listener.listen(CHANNEL).await?;
+
+    let (events, _) = broadcast::channel(256);
+    spawn(listen(listener, instance, events.clone()));
+
+    Ok(Self {
+      pool: pool.clone(),
+      instance,
+      events,
+    })
+  }
+
+  pub async fn publish(&self, event: Event) {
+    let payload = match serde_json::to_string(&Envelope {
+      origin: self.instance,
+      event,
+    }) {
+      Ok(payload) => payload,
+      Err(err) => {
+        warn!("Failed to serialize cluster event: {:?}", err);
+        return;
+      }
+    };
+
+    if let Err(err) = sqlx::query("SELECT pg_notify($1, $2)")
+      .bind(CHANNEL)
+      .bind(payload)
+      .execute(&self.pool)
+      .await
+    {
+      warn!("Failed to publish cluster event: {:?}", err);
+    }
+  }
+
+  /// Events published by the other instances.
+  pub fn subscribe(&self) -> broadcast::Receiver<Event> {
+    self.events.subscribe()
+  }
+}
+
+async fn listen(mut listener: PgListener, instance: Uuid, events: broadcast::Sender<Event>) {
+  loop {
+    match listener.recv().await {
+      Ok(notification) => handle(&notification, instance, &events),
+      Err(err) => {
+        // the listener reconnects on the next call, notifications sent in
+        // between are lost
+        warn!("Lost connection to the cluster channel: {:?}", err);
+        sleep(RETRY_DELAY).await;
+      }
+    }
+  }
+}
+
+fn handle(notification: &PgNotification, instance: Uuid, events: &broadcast::Sender<Event>) {
+  match serde_json::from_str::<Envelope>(notification.payload()) {
+    Ok(envelope) if envelope.origin != instance => {
+      debug!("Received cluster event: {:?}", envelope.event);
+      // nobody subscribed yet
+      events.send(envelope.event).ok();
+    }
+    Ok(_) => (),
+    Err(err) => warn!("Received invalid cluster event: {:?}", err),
+  }
+}
@@ -0,0 +1,137 @@
+use std::{
+  sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+  },
+  time::Duration,
+};
+
+use centaurus::db::init::Connection;
+use chrono::{TimeDelta, Utc};
+use sea_orm::{DatabaseBackend, DbErr};
+use tokio::{spawn, time::interval};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::{config::Config, db::DBTrait};
+
+pub use bus::{Bus, Event, WingsCall};
+
+mod bus;
+
+const LEADER_LEASE: &str = "leader";
+
+/// Instances of the panel that share a database. Every instance holds leases
+/// in the database for the nodes it is connected to, one of them additionally
+/// holds the leadership and runs the jobs that must only run once.
+#[derive(Clone)]
+pub struct Cluster {
+  instance: Uuid,
+  lease_ttl: TimeDelta,
+  leader: Arc<AtomicBool>,
+  /// Only exists on Postgres, other databases are not shared by instances.
+  bus: Option<Bus>,
+}
+
+impl Cluster {
+  pub async fn new(config: &Config, db: &Connection) -> Self {
+    let instance = Uuid::now_v7();
+    info!("Starting instance {}", instance);
+
+    let bus = if db.get_database_backend() == DatabaseBackend::Postgres {
+      match Bus::connect(db.get_postgres_connection_pool(), instance).await {
+        Ok(bus) => Some(bus),
+        Err(err) => {
+          warn!(
+            "Failed to listen for other instances, updates stay local: {:?}",
+            err
+          );
+          None
+        }
+      }
+    } else {
+      None
+    };
+
+    let cluster = Self {
+      instance,
+      lease_ttl: TimeDelta::seconds(config.lease_ttl_secs as i64),
+      leader: Arc::new(AtomicBool::new(false)),
+      bus,
+    };
+    cluster.elect(db).await;
+    cluster.spawn_election(db.clone());
+    cluster
+  }
+
+  pub fn bus(&self) -> Option<&Bus> {
+    self.bus.as_ref()
+  }
+
+  /// Leases are renewed three times within their lifetime, so a slow renewal
+  /// does not hand them over.
+  pub fn renew_interval(&self) -> Duration {
+    (self.lease_ttl / 3)
+      .to_std()
+      .unwrap_or_default()
+      .max(Duration::from_secs(1))
+  }
+
+  pub fn is_leader(&self) -> bool {
+    self.leader.load(Ordering::Relaxed)
+  }
+
+  /// Takes or renews a lease for this instance.
+  pub async fn acquire(&self, db: &Connection, name: &str) -> Result<bool, DbErr> {
+    db.lease()
+      .acquire(name, self.instance, Utc::now().naive_utc(), self.lease_ttl)
+      .await
+  }
+
+  /// Takes a lease from whoever holds it.
+  pub async fn take(&self, db: &Connection, name: &str) -> Result<(), DbErr> {
+    db.lease()
+      .take(name, self.instance, Utc::now().naive_utc(), self.lease_ttl)
+      .await
+  }
+
+  pub async fn release(&self, db: &Connection, name: &str) -> Result<(), DbErr> {
+    db.lease().release(name, self.instance).await
+  }
+
+  async fn elect(&self, db: &Connection) {
+    let leader = match self.acquire(db, LEADER_LEASE).await {
+      Ok(leader) => leader,
+      Err(err) => {
+        // without the database the lease can not be renewed either
+        warn!("Failed to renew the leader lease: {:?}", err);
+        false
+      }
+    };
+
+    if self.leader.swap(leader, Ordering::Relaxed) != leader {
+      if leader {
+        info!("Instance {} is now the leader", self.instance);
+      } else {
+        info!("Instance {} is no longer the leader", self.instance);
+      }
+    }
+  }
+
+  fn spawn_election(&self, db: Connection) {
+    let cluster = self.clone();
+    spawn(async move {
+      let mut interval = interval(cluster.renew_interval());
+      // the first round already ran
+      interval.tick().await;
+      loop {
+        interval.tick().await;
+        cluster.elect(&db).await;
+      }
+    });
+  }
+}
+
+pub fn node_lease(uuid: Uuid) -> String {
+  format!("node:{}", uuid)
+}
@@ -42,6 +42,9 @@ pub struct Config {
 
   pub db_url: String,
   pub admin_group: String,
+  /// How long an instance holds the leadership and its nodes without renewing
+  /// them, in seconds. Another instance takes over once it ran out.
+  pub lease_ttl_secs: u64,
 }
 
 impl Default for Config {
@@ -54,6 +57,7 @@ impl Default for Config {
       oidc: UserSettings::default(),
       db_url: "".to_string(),
       admin_group: "Admin".to_string(),
+      lease_ttl_secs: 30,
       metrics: MetricsConfig {
         metrics_name: "smaug".to_string(),
         ..Default::default()
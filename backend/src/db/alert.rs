@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+
+use centaurus::error::ErrorReportStatusExt;
+use chrono::NaiveDateTime;
+use entity::{alert, alert_channel, alert_rule, alert_rule_channel};
+use http::StatusCode;
+use schemars::JsonSchema;
+use sea_orm::{IntoActiveModel, QueryOrder, QuerySelect, Set, prelude::*};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelKind {
+  /// Sent with the mailer configured in the mail settings.
+  Email,
+  /// JSON `POST` to an arbitrary URL.
+  Webhook,
+}
+
+impl ChannelKind {
+  fn as_str(self) -> &'static str {
+    match self {
+      ChannelKind::Email => "email",
+      ChannelKind::Webhook => "webhook",
+    }
+  }
+
+  fn parse(kind: &str) -> Option<Self> {
+    match kind {
+      "email" => Some(ChannelKind::Email),
+      "webhook" => Some(ChannelKind::Webhook),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct AlertChannel {
+  pub id: Uuid,
+  pub name: String,
+  pub kind: ChannelKind,
+  /// Email address or webhook URL depending on `kind`.
+  pub target: String,
+  pub created_at: NaiveDateTime,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertCondition {
+  /// Fires once a node has been without a wings connection for `minutes`.
+  /// Nodes in maintenance are ignored.
+  NodeDisconnected { minutes: u32 },
+  /// Fires while the latest reported disk usage of a node is at or above
+  /// `percent`.
+  DiskUsage { percent: f64 },
+}
+
+impl AlertCondition {
+  fn into_parts(self) -> (&'static str, f64) {
+    match self {
+      AlertCondition::NodeDisconnected { minutes } => ("node_disconnected", minutes as f64),
+      AlertCondition::DiskUsage { percent } => ("disk_usage", percent),
+    }
+  }
+
+  fn from_parts(kind: &str, threshold: f64) -> Option<Self> {
+    match kind {
+      "node_disconnected" => Some(AlertCondition::NodeDisconnected {
+        minutes: threshold as u32,
+      }),
+      "disk_usage" => Some(AlertCondition::DiskUsage { percent: threshold }),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct AlertRule {
+  pub id: Uuid,
+  pub name: String,
+  pub condition: AlertCondition,
+  /// Only this node is checked, otherwise every node is.
+  pub node_id: Option<Uuid>,
+  pub enabled: bool,
+  pub created_at: NaiveDateTime,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct Alert {
+  pub id: Uuid,
+  pub rule_id: Uuid,
+  pub node_id: Uuid,
+  pub message: String,
+  pub fired_at: NaiveDateTime,
+  pub resolved_at: Option<NaiveDateTime>,
+}
+
+pub struct AlertTable<'db> {
+  db: &'db DatabaseConnection,
+}
+
+impl<'db> AlertTable<'db> {
+  pub fn new(db: &'db DatabaseConnection) -> Self {
+    Self { db }
+  }
+
+  pub async fn create_channel(&self, channel: AlertChannel) -> Result<(), DbErr> {
+    let model: alert_channel::Model = channel.into();
+    model.into_active_model().insert(self.db).await?;
+    Ok(())
+  }
+
+  pub async fn find_channel(&self, id: Uuid) -> centaurus::error::Result<AlertChannel> {
+    let res = alert_channel::Entity::find_by_id(id).one(self.db).await?;
+
+    let channel = res.and_then(AlertChannel::from_model);
+    channel.status_context(StatusCode::NOT_FOUND, "Alert channel not found")
+  }
+
+  pub async fn find_channel_by_name(&self, name: &str) -> Result<Option<AlertChannel>, DbErr> {
+    let res = alert_channel::Entity::find()
+      .filter(alert_channel::Column::Name.eq(name))
+      .one(self.db)
+      .await?;
+
+    Ok(res.and_then(AlertChannel::from_model))
+  }
+
+  pub async fn list_channels(&self) -> Result<Vec<AlertChannel>, DbErr> {
+    let channels = alert_channel::Entity::find()
+      .order_by_asc(alert_channel::Column::Name)
+      .all(self.db)
+      .await?;
+
+    Ok(
+      channels
+        .into_iter()
+        .filter_map(AlertChannel::from_model)
+        .collect(),
+    )
+  }
+
+  pub async fn delete_channel(&self, id: Uuid) -> Result<(), DbErr> {
+    alert_channel::Entity::delete_by_id(id)
+      .exec(self.db)
+      .await?;
+    Ok(())
+  }
+
+  pub async fn create_rule(&self, rule: AlertRule) -> Result<(), DbErr> {
+    let model: alert_rule::Model = rule.into();
+    model.into_active_model().insert(self.db).await?;
+    Ok(())
+  }
+
+  pub async fn update_rule(&self, rule: AlertRule) -> Result<(), DbErr> {
+    let (kind, threshold) = rule.condition.into_parts();
+    alert_rule::ActiveModel {
+      id: Set(rule.id),
+      name: Set(rule.name),
+      kind: Set(kind.to_string()),
+      threshold: Set(threshold),
+      node_id: Set(rule.node_id),
+      enabled: Set(rule.enabled),
+      ..Default::default()
+    }
+    .update(self.db)
+    .await?;
+    Ok(())
+  }
+
+  pub async fn find_rule(&self, id: Uuid) -> centaurus::error::Result<AlertRule> {
+    let res = alert_rule::Entity::find_by_id(id).one(self.db).await?;
+
+    let rule = res.and_then(AlertRule::from_model);
+    rule.status_context(StatusCode::NOT_FOUND, "Alert rule not found")
+  }
+
+  pub async fn find_rule_by_name(&self, name: &str) -> Result<Option<AlertRule>, DbErr> {
+    let res = alert_rule::Entity::find()
+      .filter(alert_rule::Column::Name.eq(name))
+      .one(self.db)
+      .await?;
+
+    Ok(res.and_then(AlertRule::from_model))
+  }
+
+  pub async fn list_rules(&self) -> Result<Vec<AlertRule>, DbErr> {
+    let rules = alert_rule::Entity::find()
+      .order_by_asc(alert_rule::Column::Name)
+      .all(self.db)
+      .await?;
+
+    Ok(
+      rules
+        .into_iter()
+        .filter_map(AlertRule::from_model)
+        .collect(),
+    )
+  }
+
+  pub async fn delete_rule(&self, id: Uuid) -> Result<(), DbErr> {
+    alert_rule::Entity::delete_by_id(id).exec(self.db).await?;
+    Ok(())
+  }
+
+  pub async fn rule_channels(&self) -> Result<HashMap<Uuid, Vec<Uuid>>, DbErr> {
+    let links = alert_rule_channel::Entity::find().all(self.db).await?;
+
+    let mut res: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for link in links {
+      res.entry(link.rule_id).or_default().push(link.channel_id);
+    }
+    Ok(res)
+  }
+
+  pub async fn set_rule_channels(&self, rule_id: Uuid, channels: Vec<Uuid>) -> Result<(), DbErr> {
+    alert_rule_channel::Entity::delete_many()
+      .filter(alert_rule_channel::Column::RuleId.eq(rule_id))
+      .exec(self.db)
+      .await?;
+
+    if channels.is_empty() {
+      return Ok(());
+    }
+
+    let models = channels
+      .into_iter()
+      .map(|channel_id| alert_rule_channel::ActiveModel {
+        rule_id: Set(rule_id),
+        channel_id: Set(channel_id),
+      });
+    alert_rule_channel::Entity::insert_many(models)
+      .exec(self.db)
+      .await?;
+
+    Ok(())
+  }
+
+  pub async fn create_alert(&self, alert: Alert) -> Result<(), DbErr> {
+    let model: alert::Model = alert.into();
+    model.into_active_model().insert(self.db).await?;
+    Ok(())
+  }
+
+  pub async fn resolve_alert(&self, id: Uuid, at: NaiveDateTime) -> Result<(), DbErr> {
+    alert::ActiveModel {
+      id: Set(id),
+      resolved_at: Set(Some(at)),
+      ..Default::default()
+    }
+    .update(self.db)
+    .await?;
+    Ok(())
+  }
+
+  /// Alerts that fired and are not resolved yet, at most one per rule and node.
+  pub async fn open_alerts(&self) -> Result<Vec<Alert>, DbErr> {
+    let alerts = alert::Entity::find()
+      .filter(alert::Column::ResolvedAt.is_null())
+      .all(self.db)
+      .await?;
+
+    Ok(alerts.into_iter().map(Alert::from).collect())
+  }
+
+  /// Newest first.
+  pub async fn list_alerts(&self, open_only: bool, limit: u64) -> Result<Vec<Alert>, DbErr> {
+    let mut query = alert::Entity::find();
+    if open_only {
+      query = query.filter(alert::Column::ResolvedAt.is_null());
+    }
+
+    let alerts = query
+      .order_by_desc(alert::Column::FiredAt)
+      .limit(limit)
+      .all(self.db)
+      .await?;
+
+    Ok(alerts.into_iter().map(Alert::from).collect())
+  }
+}
+
+impl AlertChannel {
+  fn from_model(model: alert_channel::Model) -> Option<Self> {
+    Some(Self {
+      id: model.id,
+      name: model.name,
+      kind: ChannelKind::parse(&model.kind)?,
+      target: model.target,
+      created_at: model.created_at,
+    })
+  }
+}
+
+impl From<AlertChannel> for alert_channel::Model {
+  fn from(channel: AlertChannel) -> Self {
+    Self {
+      id: channel.id,
+      name: channel.name,
+      kind: channel.kind.as_str().to_string(),
+      target: channel.target,
+      created_at: channel.created_at,
+    }
+  }
+}
+
+impl AlertRule {
+  fn from_model(model: alert_rule::Model) -> Option<Self> {
+    Some(Self {
+      id: model.id,
+      name: model.name,
+      condition: AlertCondition::from_parts(&model.kind, model.threshold)?,
+      node_id: model.node_id,
+      enabled: model.enabled,
+      created_at: model.created_at,
+    })
+  }
+}
+
+impl From<AlertRule> for alert_rule::Model {
+  fn from(rule: AlertRule) -> Self {
+    let (kind, threshold) = rule.condition.into_parts();
+    Self {
+      id: rule.id,
+      name: rule.name,
+      kind: kind.to_string(),
+      threshold,
+      node_id: rule.node_id,
+      enabled: rule.enabled,
+      created_at: rule.created_at,
+    }
+  }
+}
+
+impl From<alert::Model> for Alert {
+  fn from(model: alert::Model) -> Self {
+    Self {
+      id: model.id,
+      rule_id: model.rule_id,
+      node_id: model.node_id,
+      message: model.message,
+      fired_at: model.fired_at,
+      resolved_at: model.resolved_at,
+    }
+  }
+}
+
+impl From<Alert> for alert::Model {
+  fn from(alert: Alert) -> Self {
+    Self {
+      id: alert.id,
+      rule_id: alert.rule_id,
+      node_id: alert.node_id,
+      message: alert.message,
+      fired_at: alert.fired_at,
+      resolved_at: alert.resolved_at,
+    }
+  }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use centaurus::error::ErrorReportStatusExt;
+use chrono::{NaiveDateTime, Utc};
+use entity::{api_key, api_key_permission, api_key_usage};
+use http::StatusCode;
+use sea_orm::{IntoActiveModel, QueryOrder, QuerySelect, Set, prelude::*};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ApiKey {
+  pub id: Uuid,
+  pub user_id: Uuid,
+  pub name: String,
+  pub prefix: String,
+  pub hash: String,
+  pub created_at: NaiveDateTime,
+  pub expires_at: Option<NaiveDateTime>,
+  pub last_used_at: Option<NaiveDateTime>,
+  pub permissions: Vec<String>,
+}
+
+pub struct ApiKeyTable<'db> {
+  db: &'db DatabaseConnection,
+}
+
+impl<'db> ApiKeyTable<'db> {
+  pub fn new(db: &'db DatabaseConnection) -> Self {
+    Self { db }
+  }
+
+  pub async fn create_key(&self, key: ApiKey) -> Result<(), DbErr> {
+    let permissions: Vec<_> = key
+      .permissions
+      .iter()
+      .map(|permission| {
+        api_key_permission::Model {
+          api_key_id: key.id,
+          permission: permission.clone(),
+        }
+        .into_active_model()
+      })
+      .collect();
+
+    let model: api_key::Model = key.into();
+    model.into_active_model().insert(self.db).await?;
+
+    if !permissions.is_empty() {
+      api_key_permission::Entity::insert_many(permissions)
+        .exec(self.db)
+        .await?;
+    }
+
+    Ok(())
+  }
+
+  pub async fn find_by_hash(&self, hash: &str) -> Result<Option<ApiKey>, DbErr> {
+    let Some(model) = api_key::Entity::find()
+      .filter(api_key::Column::Hash.eq(hash))
+      .one(self.db)
+      .await?
+    else {
+      return Ok(None);
+    };
+
+    let permissions = self.permissions(model.id).await?;
+    Ok(Some(ApiKey::from_model(model, permissions)))
+  }
+
+  pub async fn find_for_user(
+    &self,
+    id: Uuid,
+    user_id: Uuid,
+  ) -> centaurus::error::Result<api_key::Model> {
+    let res = api_key::Entity::find_by_id(id)
+      .filter(api_key::Column::UserId.eq(user_id))
+      .one(self.db)
+      .await?;
+
+    res.status_context(StatusCode::NOT_FOUND, "API key not found")
+  }
+
+  pub async fn list_for_user(&self, user_id: Uuid) -> Result<Vec<ApiKey>, DbErr> {
+    let models = api_key::Entity::find()
+      .filter(api_key::Column::UserId.eq(user_id))
+      .order_by_asc(api_key::Column::CreatedAt)
+      .all(self.db)
+      .await?;
+
+    let mut permissions: HashMap<Uuid, Vec<String>> = HashMap::new();
+    for permission in api_key_permission::Entity::find()
+      .filter(api_key_permission::Column::ApiKeyId.is_in(models.iter().map(|model| model.id)))
+      .all(self.db)
+      .await?
+    {
+      permissions
+        .entry(permission.api_key_id)
+        .or_default()
+        .push(permission.permission);
+    }
+
+    Ok(
+      models
+        .into_iter()
+        .map(|model| {
+          let permissions = permissions.remove(&model.id).unwrap_or_default();
+          ApiKey::from_model(model, permissions)
+        })
+        .collect(),
+    )
+  }
+
+  pub async fn delete_key(&self, id: Uuid) -> Result<(), DbErr> {
+    api_key::Entity::delete_by_id(id).exec(self.db).await?;
+    Ok(())
+  }
+
+  pub async fn record_usage(&self, id: Uuid, method: String, path: String) -> Result<(), DbErr> {
+    let now = Utc::now().naive_utc();
+
+    let key = api_key::ActiveModel {
+      id: Set(id),
+      last_used_at: Set(Some(now)),
+      ..Default::default()
+    };
+    key.update(self.db).await?;
+
+    let usage = api_key_usage::ActiveModel {
+      id: Set(Uuid::now_v7()),
+      api_key_id: Set(id),
+      method: Set(method),
+      path: Set(path),
+      created_at: Set(now),
+    };
+    usage.insert(self.db).await?;
+
+    Ok(())
+  }
+
+  pub async fn list_usage(&self, id: Uuid, limit: u64) -> Result<Vec<api_key_usage::Model>, DbErr> {
+    api_key_usage::Entity::find()
+      .filter(api_key_usage::Column::ApiKeyId.eq(id))
+      .order_by_desc(api_key_usage::Column::CreatedAt)
+      .limit(limit)
+      .all(self.db)
+      .await
+  }
+
+  pub async fn prune_usage(&self, before: NaiveDateTime) -> Result<u64, DbErr> {
+    let res = api_key_usage::Entity::delete_many()
+      .filter(api_key_usage::Column::CreatedAt.lt(before))
+      .exec(self.db)
+      .await?;
+    Ok(res.rows_affected)
+  }
+
+  async fn permissions(&self, id: Uuid) -> Result<Vec<String>, DbErr> {
+    let permissions = api_key_permission::Entity::find()
+      .filter(api_key_permission::Column::ApiKeyId.eq(id))
+      .all(self.db)
+      .await?;
+
+    Ok(permissions.into_iter().map(|p| p.permission).collect())
+  }
+}
+
+impl ApiKey {
+  fn from_model(model: api_key::Model, permissions: Vec<String>) -> Self {
+    Self {
+      id: model.id,
+      user_id: model.user_id,
+      name: model.name,
+      prefix: model.prefix,
+      hash: model.hash,
+      created_at: model.created_at,
+      expires_at: model.expires_at,
+      last_used_at: model.last_used_at,
+      permissions,
+    }
+  }
+}
+
+impl From<ApiKey> for api_key::Model {
+  fn from(key: ApiKey) -> Self {
+    Self {
+      id: key.id,
+      user_id: key.user_id,
+      name: key.name,
+      prefix: key.prefix,
+      hash: key.hash,
+      created_at: key.created_at,
+      expires_at: key.expires_at,
+      last_used_at: key.last_used_at,
+    }
+  }
+}
@@ -0,0 +1,90 @@
+use chrono::{NaiveDateTime, TimeDelta};
+use entity::lease;
+use sea_orm::{
+  Condition, Set,
+  prelude::*,
+  sea_query::{Expr, OnConflict},
+};
+
+pub struct LeaseTable<'db> {
+  db: &'db DatabaseConnection,
+}
+
+impl<'db> LeaseTable<'db> {
+  pub fn new(db: &'db DatabaseConnection) -> Self {
+    Self { db }
+  }
+
+  /// Renews the lease for its holder, or takes it over if nobody holds it or
+  /// the lease of the holder ran out. `false` if another holder has it.
+  pub async fn acquire(
+    &self,
+    name: &str,
+    holder: Uuid,
+    now: NaiveDateTime,
+    ttl: TimeDelta,
+  ) -> Result<bool, DbErr> {
+    let res = lease::Entity::update_many()
+      .col_expr(lease::Column::Holder, Expr::value(holder))
+      .col_expr(lease::Column::ExpiresAt, Expr::value(now + ttl))
+      .filter(lease::Column::Name.eq(name))
+      .filter(
+        Condition::any()
+          .add(lease::Column::Holder.eq(holder))
+          .add(lease::Column::ExpiresAt.lt(now)),
+      )
+      .exec(self.db)
+      .await?;
+    if res.rows_affected > 0 {
+      return Ok(true);
+    }
+
+    // whoever inserts first holds the lease
+    let inserted = lease::Entity::insert(lease::ActiveModel {
+      name: Set(name.to_string()),
+      holder: Set(holder),
+      expires_at: Set(now + ttl),
+    })
+    .on_conflict(
+      OnConflict::column(lease::Column::Name)
+        .do_nothing()
+        .to_owned(),
+    )
+    .exec_without_returning(self.db)
+    .await?;
+    Ok(inserted > 0)
+  }
+
+  /// Takes the lease regardless of who holds it.
+  pub async fn take(
+    &self,
+    name: &str,
+    holder: Uuid,
+    now: NaiveDateTime,
+    ttl: TimeDelta,
+  ) -> Result<(), DbErr> {
+    lease::Entity::insert(lease::ActiveModel {
+      name: Set(name.to_string()),
+      holder: Set(holder),
+      expires_at: Set(now + ttl),
+    })
+    .on_conflict(
+      OnConflict::column(lease::Column::Name)
+        .update_columns([lease::Column::Holder, lease::Column::ExpiresAt])
+        .to_owned(),
+    )
+    .exec_without_returning(self.db)
+    .await?;
+    Ok(())
+  }
+
+  /// Only releases the lease if it is still held by the holder.
+  pub async fn release(&self, name: &str, holder: Uuid) -> Result<(), DbErr> {
+    lease::Entity::delete_many()
+      .filter(lease::Column::Name.eq(name))
+      .filter(lease::Column::Holder.eq(holder))
+      .exec(self.db)
+      .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,162 @@
+use centaurus::error::ErrorReportStatusExt;
+use entity::{group_user, location, location_group, node};
+use http::StatusCode;
+use sea_orm::{IntoActiveModel, QueryOrder, Set, prelude::*, sea_query::Expr};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct Location {
+  pub id: Uuid,
+  pub name: String,
+  pub description: Option<String>,
+}
+
+/// Which nodes a user may view, based on `node:view` or location grants.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NodeScope {
+  All,
+  Locations(Vec<Uuid>),
+}
+
+impl NodeScope {
+  pub fn allows(&self, location: Option<Uuid>) -> bool {
+    match self {
+      NodeScope::All => true,
+      NodeScope::Locations(locations) => location.is_some_and(|l| locations.contains(&l)),
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    matches!(self, NodeScope::Locations(locations) if locations.is_empty())
+  }
+}
+
+pub struct LocationTable<'db> {
+  db: &'db DatabaseConnection,
+}
+
+impl<'db> LocationTable<'db> {
+  pub fn new(db: &'db DatabaseConnection) -> Self {
+    Self { db }
+  }
+
+  pub async fn create_location(&self, location: Location) -> Result<(), DbErr> {
+    let model: location::Model = location.into();
+    model.into_active_model().insert(self.db).await?;
+    Ok(())
+  }
+
+  pub async fn find_by_name(&self, name: String) -> Result<location::Model, DbErr> {
+    let res = location::Entity::find()
+      .filter(location::Column::Name.eq(name))
+      .one(self.db)
+      .await?;
+
+    res.ok_or(DbErr::RecordNotFound("Not Found".into()))
+  }
+
+  pub async fn find_by_id(&self, id: Uuid) -> centaurus::error::Result<location::Model> {
+    let res = location::Entity::find_by_id(id).one(self.db).await?;
+
+    res.status_context(StatusCode::NOT_FOUND, "Location not found")
+  }
+
+  pub async fn list_locations(&self) -> Result<Vec<Location>, DbErr> {
+    let locations = location::Entity::find()
+      .order_by_asc(location::Column::Name)
+      .all(self.db)
+      .await?;
+    Ok(locations.into_iter().map(Location::from).collect())
+  }
+
+  pub async fn update_location(&self, model: location::ActiveModel) -> Result<(), DbErr> {
+    model.update(self.db).await?;
+    Ok(())
+  }
+
+  pub async fn delete_location(&self, id: Uuid) -> Result<(), DbErr> {
+    node::Entity::update_many()
+      .col_expr(node::Column::LocationId, Expr::value(Option::<Uuid>::None))
+      .filter(node::Column::LocationId.eq(id))
+      .exec(self.db)
+      .await?;
+
+    location::Entity::delete_by_id(id).exec(self.db).await?;
+    Ok(())
+  }
+
+  pub async fn location_groups(&self, id: Uuid) -> Result<Vec<Uuid>, DbErr> {
+    let groups = location_group::Entity::find()
+      .filter(location_group::Column::LocationId.eq(id))
+      .all(self.db)
+      .await?;
+
+    Ok(groups.into_iter().map(|g| g.group_id).collect())
+  }
+
+  pub async fn set_location_groups(&self, id: Uuid, groups: Vec<Uuid>) -> Result<(), DbErr> {
+    location_group::Entity::delete_many()
+      .filter(location_group::Column::LocationId.eq(id))
+      .exec(self.db)
+      .await?;
+
+    if groups.is_empty() {
+      return Ok(());
+    }
+
+    let models = groups
+      .into_iter()
+      .map(|group_id| location_group::ActiveModel {
+        location_id: Set(id),
+        group_id: Set(group_id),
+      });
+    location_group::Entity::insert_many(models)
+      .exec(self.db)
+      .await?;
+
+    Ok(())
+  }
+
+  /// Locations the user was granted `node:view` for through one of their groups.
+  pub async fn granted_locations(&self, user_id: Uuid) -> Result<Vec<Uuid>, DbErr> {
+    let groups: Vec<Uuid> = group_user::Entity::find()
+      .filter(group_user::Column::UserId.eq(user_id))
+      .all(self.db)
+      .await?
+      .into_iter()
+      .map(|g| g.group_id)
+      .collect();
+
+    let mut locations: Vec<Uuid> = location_group::Entity::find()
+      .filter(location_group::Column::GroupId.is_in(groups))
+      .all(self.db)
+      .await?
+      .into_iter()
+      .map(|g| g.location_id)
+      .collect();
+    locations.sort();
+    locations.dedup();
+
+    Ok(locations)
+  }
+}
+
+impl From<location::Model> for Location {
+  fn from(model: location::Model) -> Self {
+    Self {
+      id: model.id,
+      name: model.name,
+      description: model.description,
+    }
+  }
+}
+
+impl From<Location> for location::Model {
+  fn from(location: Location) -> Self {
+    Self {
+      id: location.id,
+      name: location.name,
+      description: location.description,
+    }
+  }
+}
@@ -1,14 +1,74 @@
 use centaurus::db::init::Connection;
 
+pub mod alert;
+pub mod api_key;
+pub mod lease;
+pub mod location;
 pub mod node;
+pub mod node_enrollment;
+pub mod node_nonce;
+pub mod node_sample;
+pub mod node_sync;
+pub mod ssh_key;
+pub mod webhook;
 
 #[allow(unused)]
 pub trait DBTrait {
+  fn alert(&self) -> alert::AlertTable<'_>;
+  fn api_key(&self) -> api_key::ApiKeyTable<'_>;
+  fn lease(&self) -> lease::LeaseTable<'_>;
+  fn location(&self) -> location::LocationTable<'_>;
   fn node(&self) -> node::NodeTable<'_>;
+  fn node_enrollment(&self) -> node_enrollment::NodeEnrollmentTable<'_>;
+  fn node_nonce(&self) -> node_nonce::NodeNonceTable<'_>;
+  fn node_sample(&self) -> node_sample::NodeSampleTable<'_>;
+  fn node_sync(&self) -> node_sync::NodeSyncTable<'_>;
+  fn ssh_key(&self) -> ssh_key::SshKeyTable<'_>;
+  fn webhook(&self) -> webhook::WebhookTable<'_>;
 }
 
 impl DBTrait for Connection {
+  fn alert(&self) -> alert::AlertTable<'_> {
+    alert::AlertTable::new(&self.0)
+  }
+
+  fn api_key(&self) -> api_key::ApiKeyTable<'_> {
+    api_key::ApiKeyTable::new(&self.0)
+  }
+
+  fn lease(&self) -> lease::LeaseTable<'_> {
+    lease::LeaseTable::new(&self.0)
+  }
+
+  fn location(&self) -> location::LocationTable<'_> {
+    location::LocationTable::new(&self.0)
+  }
+
   fn node(&self) -> node::NodeTable<'_> {
     node::NodeTable::new(&self.0)
   }
+
+  fn node_enrollment(&self) -> node_enrollment::NodeEnrollmentTable<'_> {
+    node_enrollment::NodeEnrollmentTable::new(&self.0)
+  }
+
+  fn node_nonce(&self) -> node_nonce::NodeNonceTable<'_> {
+    node_nonce::NodeNonceTable::new(&self.0)
+  }
+
+  fn node_sample(&self) -> node_sample::NodeSampleTable<'_> {
+    node_sample::NodeSampleTable::new(&self.0)
+  }
+
+  fn node_sync(&self) -> node_sync::NodeSyncTable<'_> {
+    node_sync::NodeSyncTable::new(&self.0)
+  }
+
+  fn ssh_key(&self) -> ssh_key::SshKeyTable<'_> {
+    ssh_key::SshKeyTable::new(&self.0)
+  }
+
+  fn webhook(&self) -> webhook::WebhookTable<'_> {
+    webhook::WebhookTable::new(&self.0)
+  }
 }
@@ -1,20 +1,109 @@
+use std::collections::HashMap;
+
 use centaurus::error::ErrorReportStatusExt;
-use entity::node;
+use chrono::NaiveDateTime;
+use entity::{node, node_tag};
 use http::StatusCode;
-use sea_orm::{IntoActiveModel, prelude::*};
+use schemars::JsonSchema;
+use sea_orm::{IntoActiveModel, Set, prelude::*, sea_query::Expr};
 use serde::{Deserialize, Serialize};
 
+/// SHA-256 fingerprint the TLS certificate of a node has to match, hex encoded.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TlsPin {
+  /// Fingerprint of the DER encoded certificate.
+  Certificate { sha256: String },
+  /// Fingerprint of the DER encoded public key, stays valid when the
+  /// certificate is renewed with the same key.
+  Spki { sha256: String },
+}
+
+impl TlsPin {
+  pub fn sha256(&self) -> &str {
+    match self {
+      TlsPin::Certificate { sha256 } | TlsPin::Spki { sha256 } => sha256,
+    }
+  }
+
+  fn to_column(&self) -> String {
+    match self {
+      TlsPin::Certificate { sha256 } => format!("certificate:{}", sha256),
+      TlsPin::Spki { sha256 } => format!("spki:{}", sha256),
+    }
+  }
+
+  fn from_column(pin: &str) -> Option<Self> {
+    let (kind, sha256) = pin.split_once(':')?;
+    let sha256 = sha256.to_string();
+    match kind {
+      "certificate" => Some(TlsPin::Certificate { sha256 }),
+      "spki" => Some(TlsPin::Spki { sha256 }),
+      _ => None,
+    }
+  }
+}
+
+/// Where a node is in its setup, persisted so a node that was never set up
+/// can be told apart from one that went away.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeLifecycle {
+  /// Created in the panel, wings was not installed or did not enroll yet.
+  PendingSetup,
+  /// Wings redeemed an enrollment code, but has not been connected since.
+  Enrolled,
+  Connected,
+  /// Was connected before and lost the connection.
+  Lost,
+}
+
+impl NodeLifecycle {
+  fn as_str(self) -> &'static str {
+    match self {
+      NodeLifecycle::PendingSetup => "pending_setup",
+      NodeLifecycle::Enrolled => "enrolled",
+      NodeLifecycle::Connected => "connected",
+      NodeLifecycle::Lost => "lost",
+    }
+  }
+
+  fn parse(lifecycle: &str) -> Option<Self> {
+    match lifecycle {
+      "pending_setup" => Some(NodeLifecycle::PendingSetup),
+      "enrolled" => Some(NodeLifecycle::Enrolled),
+      "connected" => Some(NodeLifecycle::Connected),
+      "lost" => Some(NodeLifecycle::Lost),
+      _ => None,
+    }
+  }
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct Node {
   pub id: Uuid,
   pub name: String,
   pub address: String,
-  pub port: i16,
+  pub port: u16,
   pub secure: bool,
   pub disk_limit_mb: Option<f64>,
   pub memory_limit_mb: Option<f64>,
   pub cpu_limit: Option<i32>,
   pub token: String,
+  pub maintenance: bool,
+  pub maintenance_message: Option<String>,
+  pub location_id: Option<Uuid>,
+  pub reverse_connect: bool,
+  /// PEM bundle the certificate of the node has to chain to instead of the
+  /// public web roots.
+  pub tls_ca: Option<String>,
+  pub tls_pin: Option<TlsPin>,
+  pub lifecycle: NodeLifecycle,
+  /// When the panel and wings completed their first handshake.
+  pub first_connected_at: Option<NaiveDateTime>,
+  /// Last time wings was heard from, while connected it is kept current by
+  /// the messages wings sends.
+  pub last_seen_at: Option<NaiveDateTime>,
 }
 
 pub struct NodeTable<'db> {
@@ -63,6 +152,116 @@ impl<'db> NodeTable<'db> {
     model.update(self.db).await?;
     Ok(())
   }
+
+  /// Nodes that are already connected stay connected, a node that enrolls
+  /// again after a reinstall starts over otherwise.
+  pub async fn enrolled(&self, id: Uuid) -> Result<(), DbErr> {
+    node::Entity::update_many()
+      .col_expr(
+        node::Column::Lifecycle,
+        Expr::value(NodeLifecycle::Enrolled.as_str()),
+      )
+      .filter(node::Column::Id.eq(id))
+      .filter(node::Column::Lifecycle.ne(NodeLifecycle::Connected.as_str()))
+      .exec(self.db)
+      .await?;
+    Ok(())
+  }
+
+  pub async fn connected(&self, id: Uuid, at: NaiveDateTime) -> Result<(), DbErr> {
+    node::Entity::update_many()
+      .col_expr(node::Column::FirstConnectedAt, Expr::value(at))
+      .filter(node::Column::Id.eq(id))
+      .filter(node::Column::FirstConnectedAt.is_null())
+      .exec(self.db)
+      .await?;
+
+    node::Entity::update_many()
+      .col_expr(
+        node::Column::Lifecycle,
+        Expr::value(NodeLifecycle::Connected.as_str()),
+      )
+      .col_expr(node::Column::LastSeenAt, Expr::value(at))
+      .filter(node::Column::Id.eq(id))
+      .exec(self.db)
+      .await?;
+    Ok(())
+  }
+
+  pub async fn seen(&self, id: Uuid, at: NaiveDateTime) -> Result<(), DbErr> {
+    node::Entity::update_many()
+      .col_expr(node::Column::LastSeenAt, Expr::value(at))
+      .filter(node::Column::Id.eq(id))
+      .exec(self.db)
+      .await?;
+    Ok(())
+  }
+
+  pub async fn lost(&self, id: Uuid, at: NaiveDateTime) -> Result<(), DbErr> {
+    node::Entity::update_many()
+      .col_expr(
+        node::Column::Lifecycle,
+        Expr::value(NodeLifecycle::Lost.as_str()),
+      )
+      .col_expr(node::Column::LastSeenAt, Expr::value(at))
+      .filter(node::Column::Id.eq(id))
+      .exec(self.db)
+      .await?;
+    Ok(())
+  }
+
+  /// Connections do not move between instances of the panel, so a node still
+  /// marked as connected when an instance takes it over lost its connection
+  /// with the previous owner.
+  pub async fn reset_connected(&self, id: Uuid) -> Result<(), DbErr> {
+    node::Entity::update_many()
+      .col_expr(
+        node::Column::Lifecycle,
+        Expr::value(NodeLifecycle::Lost.as_str()),
+      )
+      .filter(node::Column::Id.eq(id))
+      .filter(node::Column::Lifecycle.eq(NodeLifecycle::Connected.as_str()))
+      .exec(self.db)
+      .await?;
+    Ok(())
+  }
+
+  pub async fn tags(&self, id: Uuid) -> Result<Vec<String>, DbErr> {
+    let tags = node_tag::Entity::find()
+      .filter(node_tag::Column::NodeId.eq(id))
+      .all(self.db)
+      .await?;
+    Ok(tags.into_iter().map(|t| t.tag).collect())
+  }
+
+  pub async fn all_tags(&self) -> Result<HashMap<Uuid, Vec<String>>, DbErr> {
+    let tags = node_tag::Entity::find().all(self.db).await?;
+
+    let mut res: HashMap<Uuid, Vec<String>> = HashMap::new();
+    for tag in tags {
+      res.entry(tag.node_id).or_default().push(tag.tag);
+    }
+    Ok(res)
+  }
+
+  pub async fn set_tags(&self, id: Uuid, tags: Vec<String>) -> Result<(), DbErr> {
+    node_tag::Entity::delete_many()
+      .filter(node_tag::Column::NodeId.eq(id))
+      .exec(self.db)
+      .await?;
+
+    if tags.is_empty() {
+      return Ok(());
+    }
+
+    let models = tags.into_iter().map(|tag| node_tag::ActiveModel {
+      node_id: Set(id),
+      tag: Set(tag),
+    });
+    node_tag::Entity::insert_many(models).exec(self.db).await?;
+
+    Ok(())
+  }
 }
 
 impl From<node::Model> for Node {
@@ -71,12 +270,22 @@ impl From<node::Model> for Node {
       id: model.id,
       name: model.name,
       address: model.address,
-      port: model.port,
+      // stored as a smallint, ports above i16::MAX come back negative
+      port: model.port as u16,
       secure: model.secure,
       disk_limit_mb: model.disk_limit_mb,
       memory_limit_mb: model.memory_limit_mb,
       cpu_limit: model.cpu_limit,
       token: model.token,
+      maintenance: model.maintenance,
+      maintenance_message: model.maintenance_message,
+      location_id: model.location_id,
+      reverse_connect: model.reverse_connect,
+      tls_ca: model.tls_ca,
+      tls_pin: model.tls_pin.as_deref().and_then(TlsPin::from_column),
+      lifecycle: NodeLifecycle::parse(&model.lifecycle).unwrap_or(NodeLifecycle::Enrolled),
+      first_connected_at: model.first_connected_at,
+      last_seen_at: model.last_seen_at,
     }
   }
 }
@@ -87,12 +296,21 @@ impl From<Node> for node::Model {
       id: node.id,
       name: node.name,
       address: node.address,
-      port: node.port,
+      port: node.port as i16,
       secure: node.secure,
       disk_limit_mb: node.disk_limit_mb,
       memory_limit_mb: node.memory_limit_mb,
       cpu_limit: node.cpu_limit,
       token: node.token,
+      maintenance: node.maintenance,
+      maintenance_message: node.maintenance_message,
+      location_id: node.location_id,
+      reverse_connect: node.reverse_connect,
+      tls_ca: node.tls_ca,
+      tls_pin: node.tls_pin.as_ref().map(TlsPin::to_column),
+      lifecycle: node.lifecycle.as_str().to_string(),
+      first_connected_at: node.first_connected_at,
+      last_seen_at: node.last_seen_at,
     }
   }
 }
@@ -0,0 +1,59 @@
+use chrono::NaiveDateTime;
+use entity::node_enrollment;
+use sea_orm::{Set, prelude::*};
+
+pub struct NodeEnrollmentTable<'db> {
+  db: &'db DatabaseConnection,
+}
+
+impl<'db> NodeEnrollmentTable<'db> {
+  pub fn new(db: &'db DatabaseConnection) -> Self {
+    Self { db }
+  }
+
+  /// Replaces the pending enrollment code of the node, if there is one.
+  pub async fn issue(
+    &self,
+    node_id: Uuid,
+    code_hash: String,
+    expires_at: NaiveDateTime,
+  ) -> Result<(), DbErr> {
+    node_enrollment::Entity::delete_by_id(node_id)
+      .exec(self.db)
+      .await?;
+
+    node_enrollment::ActiveModel {
+      node_id: Set(node_id),
+      code_hash: Set(code_hash),
+      expires_at: Set(expires_at),
+    }
+    .insert(self.db)
+    .await?;
+
+    Ok(())
+  }
+
+  /// Consumes the code and returns the node it was issued for. Expired codes
+  /// are consumed as well but return `None`.
+  pub async fn redeem(&self, code_hash: &str, now: NaiveDateTime) -> Result<Option<Uuid>, DbErr> {
+    let Some(enrollment) = node_enrollment::Entity::find()
+      .filter(node_enrollment::Column::CodeHash.eq(code_hash))
+      .one(self.db)
+      .await?
+    else {
+      return Ok(None);
+    };
+
+    // only the request that actually deletes the row may use the code
+    let res = node_enrollment::Entity::delete_many()
+      .filter(node_enrollment::Column::NodeId.eq(enrollment.node_id))
+      .filter(node_enrollment::Column::CodeHash.eq(code_hash))
+      .exec(self.db)
+      .await?;
+    if res.rows_affected == 0 || enrollment.expires_at <= now {
+      return Ok(None);
+    }
+
+    Ok(Some(enrollment.node_id))
+  }
+}
@@ -0,0 +1,44 @@
+use chrono::{NaiveDateTime, TimeDelta};
+use entity::node_nonce;
+use sea_orm::{Set, prelude::*, sea_query::OnConflict};
+
+pub struct NodeNonceTable<'db> {
+  db: &'db DatabaseConnection,
+}
+
+impl<'db> NodeNonceTable<'db> {
+  pub fn new(db: &'db DatabaseConnection) -> Self {
+    Self { db }
+  }
+
+  /// Records the nonce of a request signed by the node, `false` if it was used
+  /// before. Nonces older than `retention` are forgotten.
+  pub async fn use_nonce(
+    &self,
+    node_id: Uuid,
+    nonce: &str,
+    now: NaiveDateTime,
+    retention: TimeDelta,
+  ) -> Result<bool, DbErr> {
+    node_nonce::Entity::delete_many()
+      .filter(node_nonce::Column::NodeId.eq(node_id))
+      .filter(node_nonce::Column::UsedAt.lt(now - retention))
+      .exec(self.db)
+      .await?;
+
+    // whoever inserts first used the nonce
+    let inserted = node_nonce::Entity::insert(node_nonce::ActiveModel {
+      node_id: Set(node_id),
+      nonce: Set(nonce.to_string()),
+      used_at: Set(now),
+    })
+    .on_conflict(
+      OnConflict::columns([node_nonce::Column::NodeId, node_nonce::Column::Nonce])
+        .do_nothing()
+        .to_owned(),
+    )
+    .exec_without_returning(self.db)
+    .await?;
+    Ok(inserted > 0)
+  }
+}
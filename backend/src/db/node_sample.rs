@@ -0,0 +1,138 @@
+use chrono::NaiveDateTime;
+use entity::node_sample;
+use schemars::JsonSchema;
+use sea_orm::{QueryOrder, Set, prelude::*};
+use serde::{Deserialize, Serialize};
+use shared::msg::NodeStats;
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Resolution {
+  Raw,
+  Minute,
+  QuarterHour,
+}
+
+impl Resolution {
+  /// Width of the averaged buckets in seconds, raw samples are stored as sent.
+  pub fn seconds(self) -> i32 {
+    match self {
+      Resolution::Raw => 0,
+      Resolution::Minute => 60,
+      Resolution::QuarterHour => 15 * 60,
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sample {
+  pub timestamp: NaiveDateTime,
+  pub stats: NodeStats,
+}
+
+pub struct NodeSampleTable<'db> {
+  db: &'db DatabaseConnection,
+}
+
+impl<'db> NodeSampleTable<'db> {
+  pub fn new(db: &'db DatabaseConnection) -> Self {
+    Self { db }
+  }
+
+  pub async fn insert_samples(
+    &self,
+    node_id: Uuid,
+    resolution: Resolution,
+    samples: Vec<Sample>,
+  ) -> Result<(), DbErr> {
+    if samples.is_empty() {
+      return Ok(());
+    }
+
+    let models = samples.into_iter().map(|sample| node_sample::ActiveModel {
+      id: Set(Uuid::now_v7()),
+      node_id: Set(node_id),
+      resolution: Set(resolution.seconds()),
+      timestamp: Set(sample.timestamp),
+      cpu_percent: Set(sample.stats.cpu_percent),
+      memory_used_mb: Set(sample.stats.memory_used_mb),
+      memory_total_mb: Set(sample.stats.memory_total_mb),
+      disk_used_mb: Set(sample.stats.disk_used_mb),
+      disk_total_mb: Set(sample.stats.disk_total_mb),
+    });
+    node_sample::Entity::insert_many(models)
+      .exec(self.db)
+      .await?;
+
+    Ok(())
+  }
+
+  /// Samples in `[from, to)` ordered by time.
+  pub async fn list_samples(
+    &self,
+    node_id: Uuid,
+    resolution: Resolution,
+    from: NaiveDateTime,
+    to: NaiveDateTime,
+  ) -> Result<Vec<Sample>, DbErr> {
+    let samples = node_sample::Entity::find()
+      .filter(node_sample::Column::NodeId.eq(node_id))
+      .filter(node_sample::Column::Resolution.eq(resolution.seconds()))
+      .filter(node_sample::Column::Timestamp.gte(from))
+      .filter(node_sample::Column::Timestamp.lt(to))
+      .order_by_asc(node_sample::Column::Timestamp)
+      .all(self.db)
+      .await?;
+
+    Ok(samples.into_iter().map(Sample::from).collect())
+  }
+
+  pub async fn latest_sample(
+    &self,
+    node_id: Uuid,
+    resolution: Resolution,
+  ) -> Result<Option<Sample>, DbErr> {
+    let sample = node_sample::Entity::find()
+      .filter(node_sample::Column::NodeId.eq(node_id))
+      .filter(node_sample::Column::Resolution.eq(resolution.seconds()))
+      .order_by_desc(node_sample::Column::Timestamp)
+      .one(self.db)
+      .await?;
+
+    Ok(sample.map(Sample::from))
+  }
+
+  pub async fn latest_timestamp(
+    &self,
+    node_id: Uuid,
+    resolution: Resolution,
+  ) -> Result<Option<NaiveDateTime>, DbErr> {
+    let sample = self.latest_sample(node_id, resolution).await?;
+    Ok(sample.map(|s| s.timestamp))
+  }
+
+  pub async fn prune(&self, resolution: Resolution, before: NaiveDateTime) -> Result<u64, DbErr> {
+    let res = node_sample::Entity::delete_many()
+      .filter(node_sample::Column::Resolution.eq(resolution.seconds()))
+      .filter(node_sample::Column::Timestamp.lt(before))
+      .exec(self.db)
+      .await?;
+
+    Ok(res.rows_affected)
+  }
+}
+
+impl From<node_sample::Model> for Sample {
+  fn from(model: node_sample::Model) -> Self {
+    Self {
+      timestamp: model.timestamp,
+      stats: NodeStats {
+        cpu_percent: model.cpu_percent,
+        memory_used_mb: model.memory_used_mb,
+        memory_total_mb: model.memory_total_mb,
+        disk_used_mb: model.disk_used_mb,
+        disk_total_mb: model.disk_total_mb,
+      },
+    }
+  }
+}
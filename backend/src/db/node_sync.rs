@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+use entity::node_sync;
+use schemars::JsonSchema;
+use sea_orm::{IntoActiveModel, Set, prelude::*};
+use serde::{Deserialize, Serialize};
+use shared::msg::Drift;
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncStatus {
+  /// The configuration was sent, wings did not report back since.
+  Pending,
+  Synced,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, JsonSchema)]
+pub struct NodeSync {
+  pub status: SyncStatus,
+  pub requested_at: NaiveDateTime,
+  pub synced_at: Option<NaiveDateTime>,
+  /// What wings had configured differently before the last sync.
+  pub drift: Vec<SyncDrift>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SyncDrift {
+  pub field: String,
+  pub desired: Option<f64>,
+  pub actual: Option<f64>,
+}
+
+pub struct NodeSyncTable<'db> {
+  db: &'db DatabaseConnection,
+}
+
+impl<'db> NodeSyncTable<'db> {
+  pub fn new(db: &'db DatabaseConnection) -> Self {
+    Self { db }
+  }
+
+  pub async fn find(&self, node_id: Uuid) -> Result<Option<NodeSync>, DbErr> {
+    let sync = node_sync::Entity::find_by_id(node_id).one(self.db).await?;
+    Ok(sync.map(NodeSync::from))
+  }
+
+  pub async fn all(&self) -> Result<HashMap<Uuid, NodeSync>, DbErr> {
+    let syncs = node_sync::Entity::find().all(self.db).await?;
+    Ok(
+      syncs
+        .into_iter()
+        .map(|sync| (sync.node_id, sync.into()))
+        .collect(),
+    )
+  }
+
+  /// Marks the node as pending, the result of the previous sync is kept until
+  /// wings reports again.
+  pub async fn requested(&self, node_id: Uuid, at: NaiveDateTime) -> Result<(), DbErr> {
+    match node_sync::Entity::find_by_id(node_id).one(self.db).await? {
+      Some(sync) => {
+        let mut sync = sync.into_active_model();
+        sync.requested_at = Set(at);
+        sync.update(self.db).await?;
+      }
+      None => {
+        node_sync::ActiveModel {
+          node_id: Set(node_id),
+          requested_at: Set(at),
+          synced_at: Set(None),
+          drift: Set(None),
+        }
+        .insert(self.db)
+        .await?;
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Reports without a preceding request are ignored. Returns when the
+  /// answered request was sent, if it was still pending.
+  pub async fn reported(
+    &self,
+    node_id: Uuid,
+    at: NaiveDateTime,
+    drift: Vec<Drift>,
+  ) -> Result<Option<NaiveDateTime>, DbErr> {
+    let Some(sync) = node_sync::Entity::find_by_id(node_id).one(self.db).await? else {
+      return Ok(None);
+    };
+    let pending = sync
+      .synced_at
+      .is_none_or(|synced_at| synced_at < sync.requested_at)
+      .then_some(sync.requested_at);
+
+    let drift: Vec<SyncDrift> = drift.into_iter().map(SyncDrift::from).collect();
+    let mut sync = sync.into_active_model();
+    sync.synced_at = Set(Some(at));
+    sync.drift = Set(Some(
+      serde_json::to_string(&drift).map_err(|err| DbErr::Custom(err.to_string()))?,
+    ));
+    sync.update(self.db).await?;
+
+    Ok(pending)
+  }
+}
+
+impl From<node_sync::Model> for NodeSync {
+  fn from(model: node_sync::Model) -> Self {
+    let status = match model.synced_at {
+      Some(synced_at) if synced_at >= model.requested_at => SyncStatus::Synced,
+      _ => SyncStatus::Pending,
+    };
+
+    Self {
+      status,
+      requested_at: model.requested_at,
+      synced_at: model.synced_at,
+      drift: model
+        .drift
+        .and_then(|drift| serde_json::from_str(&drift).ok())
+        .unwrap_or_default(),
+    }
+  }
+}
+
+impl From<Drift> for SyncDrift {
+  fn from(drift: Drift) -> Self {
+    Self {
+      field: drift.field,
+      desired: drift.desired,
+      actual: drift.actual,
+    }
+  }
+}
@@ -0,0 +1,98 @@
+use centaurus::error::ErrorReportStatusExt;
+use chrono::NaiveDateTime;
+use entity::ssh_key;
+use http::StatusCode;
+use sea_orm::{IntoActiveModel, QueryOrder, prelude::*};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SshKey {
+  pub id: Uuid,
+  pub user_id: Uuid,
+  pub name: String,
+  pub key_type: String,
+  pub public_key: String,
+  pub fingerprint: String,
+  pub created_at: NaiveDateTime,
+}
+
+pub struct SshKeyTable<'db> {
+  db: &'db DatabaseConnection,
+}
+
+impl<'db> SshKeyTable<'db> {
+  pub fn new(db: &'db DatabaseConnection) -> Self {
+    Self { db }
+  }
+
+  pub async fn create_key(&self, key: SshKey) -> Result<(), DbErr> {
+    let model: ssh_key::Model = key.into();
+    model.into_active_model().insert(self.db).await?;
+    Ok(())
+  }
+
+  pub async fn find_by_fingerprint(&self, fingerprint: &str) -> Result<Option<SshKey>, DbErr> {
+    let res = ssh_key::Entity::find()
+      .filter(ssh_key::Column::Fingerprint.eq(fingerprint))
+      .one(self.db)
+      .await?;
+
+    Ok(res.map(SshKey::from))
+  }
+
+  pub async fn find_for_user(
+    &self,
+    id: Uuid,
+    user_id: Uuid,
+  ) -> centaurus::error::Result<ssh_key::Model> {
+    let res = ssh_key::Entity::find_by_id(id)
+      .filter(ssh_key::Column::UserId.eq(user_id))
+      .one(self.db)
+      .await?;
+
+    res.status_context(StatusCode::NOT_FOUND, "SSH key not found")
+  }
+
+  pub async fn list_for_user(&self, user_id: Uuid) -> Result<Vec<SshKey>, DbErr> {
+    let keys = ssh_key::Entity::find()
+      .filter(ssh_key::Column::UserId.eq(user_id))
+      .order_by_asc(ssh_key::Column::CreatedAt)
+      .all(self.db)
+      .await?;
+
+    Ok(keys.into_iter().map(SshKey::from).collect())
+  }
+
+  pub async fn delete_key(&self, id: Uuid) -> Result<(), DbErr> {
+    ssh_key::Entity::delete_by_id(id).exec(self.db).await?;
+    Ok(())
+  }
+}
+
+impl From<ssh_key::Model> for SshKey {
+  fn from(model: ssh_key::Model) -> Self {
+    Self {
+      id: model.id,
+      user_id: model.user_id,
+      name: model.name,
+      key_type: model.key_type,
+      public_key: model.public_key,
+      fingerprint: model.fingerprint,
+      created_at: model.created_at,
+    }
+  }
+}
+
+impl From<SshKey> for ssh_key::Model {
+  fn from(key: SshKey) -> Self {
+    Self {
+      id: key.id,
+      user_id: key.user_id,
+      name: key.name,
+      key_type: key.key_type,
+      public_key: key.public_key,
+      fingerprint: key.fingerprint,
+      created_at: key.created_at,
+    }
+  }
+}
@@ -0,0 +1,302 @@
+use std::collections::HashMap;
+
+use centaurus::error::ErrorReportStatusExt;
+use chrono::NaiveDateTime;
+use entity::{webhook, webhook_delivery, webhook_event};
+use http::StatusCode;
+use schemars::JsonSchema;
+use sea_orm::{IntoActiveModel, QueryOrder, QuerySelect, Set, prelude::*};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+  Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, JsonSchema,
+)]
+pub enum WebhookEvent {
+  #[serde(rename = "node.created")]
+  NodeCreated,
+  #[serde(rename = "node.updated")]
+  NodeUpdated,
+  #[serde(rename = "node.deleted")]
+  NodeDeleted,
+  #[serde(rename = "node.connected")]
+  NodeConnected,
+  #[serde(rename = "node.disconnected")]
+  NodeDisconnected,
+  #[serde(rename = "alert.fired")]
+  AlertFired,
+  #[serde(rename = "alert.resolved")]
+  AlertResolved,
+  /// Only sent by the ping endpoint, webhooks can not subscribe to it.
+  #[serde(rename = "ping")]
+  Ping,
+}
+
+impl WebhookEvent {
+  pub fn as_str(self) -> &'static str {
+    match self {
+      WebhookEvent::NodeCreated => "node.created",
+      WebhookEvent::NodeUpdated => "node.updated",
+      WebhookEvent::NodeDeleted => "node.deleted",
+      WebhookEvent::NodeConnected => "node.connected",
+      WebhookEvent::NodeDisconnected => "node.disconnected",
+      WebhookEvent::AlertFired => "alert.fired",
+      WebhookEvent::AlertResolved => "alert.resolved",
+      WebhookEvent::Ping => "ping",
+    }
+  }
+
+  fn parse(event: &str) -> Option<Self> {
+    serde_json::from_value(serde_json::Value::String(event.to_string())).ok()
+  }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct Webhook {
+  pub id: Uuid,
+  pub name: String,
+  pub url: String,
+  /// Key for the HMAC signature of every delivery.
+  pub secret: String,
+  pub enabled: bool,
+  pub created_at: NaiveDateTime,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct Delivery {
+  pub id: Uuid,
+  pub webhook_id: Uuid,
+  pub event: String,
+  pub payload: String,
+  pub attempts: i32,
+  /// Response status of the last attempt.
+  pub status_code: Option<i32>,
+  /// Why the last attempt failed, if it did.
+  pub error: Option<String>,
+  pub delivered: bool,
+  pub created_at: NaiveDateTime,
+  pub updated_at: NaiveDateTime,
+}
+
+pub struct WebhookTable<'db> {
+  db: &'db DatabaseConnection,
+}
+
+impl<'db> WebhookTable<'db> {
+  pub fn new(db: &'db DatabaseConnection) -> Self {
+    Self { db }
+  }
+
+  pub async fn create_webhook(&self, hook: Webhook) -> Result<(), DbErr> {
+    let model: webhook::Model = hook.into();
+    model.into_active_model().insert(self.db).await?;
+    Ok(())
+  }
+
+  pub async fn update_webhook(&self, hook: Webhook) -> Result<(), DbErr> {
+    webhook::ActiveModel {
+      id: Set(hook.id),
+      name: Set(hook.name),
+      url: Set(hook.url),
+      enabled: Set(hook.enabled),
+      ..Default::default()
+    }
+    .update(self.db)
+    .await?;
+    Ok(())
+  }
+
+  pub async fn find_by_id(&self, id: Uuid) -> centaurus::error::Result<Webhook> {
+    let res = webhook::Entity::find_by_id(id).one(self.db).await?;
+
+    let hook = res.map(Webhook::from);
+    hook.status_context(StatusCode::NOT_FOUND, "Webhook not found")
+  }
+
+  pub async fn find_by_name(&self, name: &str) -> Result<Option<Webhook>, DbErr> {
+    let res = webhook::Entity::find()
+      .filter(webhook::Column::Name.eq(name))
+      .one(self.db)
+      .await?;
+
+    Ok(res.map(Webhook::from))
+  }
+
+  pub async fn list_webhooks(&self) -> Result<Vec<Webhook>, DbErr> {
+    let hooks = webhook::Entity::find()
+      .order_by_asc(webhook::Column::Name)
+      .all(self.db)
+      .await?;
+
+    Ok(hooks.into_iter().map(Webhook::from).collect())
+  }
+
+  pub async fn delete_webhook(&self, id: Uuid) -> Result<(), DbErr> {
+    webhook::Entity::delete_by_id(id).exec(self.db).await?;
+    Ok(())
+  }
+
+  pub async fn all_events(&self) -> Result<HashMap<Uuid, Vec<WebhookEvent>>, DbErr> {
+    let events = webhook_event::Entity::find().all(self.db).await?;
+
+    let mut res: HashMap<Uuid, Vec<WebhookEvent>> = HashMap::new();
+    for event in events {
+      if let Some(parsed) = WebhookEvent::parse(&event.event) {
+        res.entry(event.webhook_id).or_default().push(parsed);
+      }
+    }
+    Ok(res)
+  }
+
+  pub async fn set_events(&self, id: Uuid, events: Vec<WebhookEvent>) -> Result<(), DbErr> {
+    webhook_event::Entity::delete_many()
+      .filter(webhook_event::Column::WebhookId.eq(id))
+      .exec(self.db)
+      .await?;
+
+    if events.is_empty() {
+      return Ok(());
+    }
+
+    let models = events.into_iter().map(|event| webhook_event::ActiveModel {
+      webhook_id: Set(id),
+      event: Set(event.as_str().to_string()),
+    });
+    webhook_event::Entity::insert_many(models)
+      .exec(self.db)
+      .await?;
+
+    Ok(())
+  }
+
+  /// Enabled webhooks that are subscribed to `event`.
+  pub async fn subscribers(&self, event: WebhookEvent) -> Result<Vec<Webhook>, DbErr> {
+    let hooks = webhook::Entity::find()
+      .inner_join(webhook_event::Entity)
+      .filter(webhook_event::Column::Event.eq(event.as_str()))
+      .filter(webhook::Column::Enabled.eq(true))
+      .all(self.db)
+      .await?;
+
+    Ok(hooks.into_iter().map(Webhook::from).collect())
+  }
+
+  pub async fn create_delivery(&self, delivery: Delivery) -> Result<(), DbErr> {
+    let model: webhook_delivery::Model = delivery.into();
+    model.into_active_model().insert(self.db).await?;
+    Ok(())
+  }
+
+  pub async fn update_delivery(&self, delivery: Delivery) -> Result<(), DbErr> {
+    webhook_delivery::ActiveModel {
+      id: Set(delivery.id),
+      attempts: Set(delivery.attempts),
+      status_code: Set(delivery.status_code),
+      error: Set(delivery.error),
+      delivered: Set(delivery.delivered),
+      updated_at: Set(delivery.updated_at),
+      ..Default::default()
+    }
+    .update(self.db)
+    .await?;
+    Ok(())
+  }
+
+  /// Deliveries of enabled webhooks that failed so far but have attempts left, oldest first.
+  pub async fn pending_deliveries(&self, max_attempts: i32) -> Result<Vec<Delivery>, DbErr> {
+    let deliveries = webhook_delivery::Entity::find()
+      .inner_join(webhook::Entity)
+      .filter(webhook::Column::Enabled.eq(true))
+      .filter(webhook_delivery::Column::Delivered.eq(false))
+      .filter(webhook_delivery::Column::Attempts.lt(max_attempts))
+      .order_by_asc(webhook_delivery::Column::CreatedAt)
+      .all(self.db)
+      .await?;
+
+    Ok(deliveries.into_iter().map(Delivery::from).collect())
+  }
+
+  /// Newest first.
+  pub async fn list_deliveries(
+    &self,
+    webhook_id: Uuid,
+    limit: u64,
+  ) -> Result<Vec<Delivery>, DbErr> {
+    let deliveries = webhook_delivery::Entity::find()
+      .filter(webhook_delivery::Column::WebhookId.eq(webhook_id))
+      .order_by_desc(webhook_delivery::Column::CreatedAt)
+      .limit(limit)
+      .all(self.db)
+      .await?;
+
+    Ok(deliveries.into_iter().map(Delivery::from).collect())
+  }
+
+  pub async fn prune_deliveries(&self, before: NaiveDateTime) -> Result<u64, DbErr> {
+    let res = webhook_delivery::Entity::delete_many()
+      .filter(webhook_delivery::Column::CreatedAt.lt(before))
+      .exec(self.db)
+      .await?;
+
+    Ok(res.rows_affected)
+  }
+}
+
+impl From<webhook::Model> for Webhook {
+  fn from(model: webhook::Model) -> Self {
+    Self {
+      id: model.id,
+      name: model.name,
+      url: model.url,
+      secret: model.secret,
+      enabled: model.enabled,
+      created_at: model.created_at,
+    }
+  }
+}
+
+impl From<Webhook> for webhook::Model {
+  fn from(hook: Webhook) -> Self {
+    Self {
+      id: hook.id,
+      name: hook.name,
+      url: hook.url,
+      secret: hook.secret,
+      enabled: hook.enabled,
+      created_at: hook.created_at,
+    }
+  }
+}
+
+impl From<webhook_delivery::Model> for Delivery {
+  fn from(model: webhook_delivery::Model) -> Self {
+    Self {
+      id: model.id,
+      webhook_id: model.webhook_id,
+      event: model.event,
+      payload: model.payload,
+      attempts: model.attempts,
+      status_code: model.status_code,
+      error: model.error,
+      delivered: model.delivered,
+      created_at: model.created_at,
+      updated_at: model.updated_at,
+    }
+  }
+}
+
+impl From<Delivery> for webhook_delivery::Model {
+  fn from(delivery: Delivery) -> Self {
+    Self {
+      id: delivery.id,
+      webhook_id: delivery.webhook_id,
+      event: delivery.event,
+      payload: delivery.payload,
+      attempts: delivery.attempts,
+      status_code: delivery.status_code,
+      error: delivery.error,
+      delivered: delivery.delivered,
+      created_at: delivery.created_at,
+      updated_at: delivery.updated_at,
+    }
+  }
+}
@@ -18,13 +18,22 @@ use centaurus::{
 use tokio::net::TcpListener;
 use tracing::info;
 
-use crate::{config::Config, utils::UpdateMessage};
+use crate::{
+  cluster::Cluster,
+  config::Config,
+  utils::{UpdateMessage, Updater},
+};
 
+mod alerts;
+mod api_keys;
+mod cluster;
 mod config;
 mod db;
 mod nodes;
 mod settings;
+mod ssh_keys;
 mod utils;
+mod webhooks;
 
 pub async fn serve() {
   let config = Config::parse();
@@ -74,11 +83,21 @@ fn api_router(rate_limiter: &mut RateLimiter) -> ApiRouter {
     .nest("/ws", websocket::router::<UpdateMessage>())
     .nest("/setup", setup::router())
     .nest("/auth", auth::router::<UpdateMessage>(rate_limiter))
-    .nest("/user", user::router::<UpdateMessage>(rate_limiter))
+    .nest(
+      "/user",
+      user::router::<UpdateMessage>(rate_limiter).merge(ssh_keys::router()),
+    )
     .nest("/settings", settings::router())
     .nest("/mail", mail::router(rate_limiter))
     .nest("/group", group::router::<UpdateMessage>())
-    .nest("/nodes", nodes::router())
+    .nest("/api_keys", api_keys::router())
+    .nest("/nodes", api_keys::accept(nodes::router()))
+    .nest("/alerts", api_keys::accept(alerts::router()))
+    .nest("/webhooks", api_keys::accept(webhooks::router()))
+    .nest(
+      "/wings",
+      ssh_keys::wings_router().merge(nodes::wings_router()),
+    )
 }
 
 async fn state(mut router: ApiRouter, config: Config) -> ApiRouter {
@@ -91,16 +110,39 @@ async fn state(mut router: ApiRouter, config: Config) -> ApiRouter {
   .await
   .expect("Failed to create admin group");
 
-  let (state, updater) = UpdateState::<UpdateMessage>::init().await;
+  let (state, local_updater) = UpdateState::<UpdateMessage>::init().await;
+  let cluster = Cluster::new(&config, &db).await;
+  let updater = Updater::new(local_updater.clone(), &cluster);
 
   router = endpoints::user::state(router);
   router = auth::state(router, &config, &db).await;
   router = mail::state(router, &db, &config).await;
-  router = nodes::state(router, &db, updater.clone()).await;
+  let (wings, hooks);
+  api_keys::state(&db, &cluster);
+  (router, hooks) = webhooks::state(router, &db, &cluster);
+  (router, wings) = nodes::state(
+    router,
+    &config,
+    &db,
+    &cluster,
+    updater.clone(),
+    hooks.clone(),
+  )
+  .await;
+  router = alerts::state(
+    router,
+    &config,
+    &db,
+    &cluster,
+    wings,
+    updater.clone(),
+    hooks,
+  );
 
   router
     .layer(Extension(db))
     .layer(Extension(state))
+    .layer(Extension(local_updater))
     .layer(Extension(updater))
 }
 
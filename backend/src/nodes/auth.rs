@@ -1,13 +1,36 @@
-use centaurus::eyre::Context;
-use http::Extensions;
+use std::sync::Arc;
+
+use axum::{
+  body::to_bytes,
+  extract::{FromRequest, FromRequestParts, Request},
+};
+use centaurus::{
+  backend::request::extract::StateExtractExt,
+  bail,
+  db::init::Connection,
+  error::{ErrorReport, ErrorReportStatusExt},
+  eyre::Context,
+};
+use chrono::Utc;
+use entity::node;
+use http::{Extensions, HeaderMap, StatusCode, request::Parts};
 use migration::async_trait;
 use reqwest_middleware::{Middleware, Next};
-use shared::auth::SignData;
+use rustls::ClientConfig;
+use serde::de::DeserializeOwned;
+use shared::auth::{MAX_SIGNATURE_AGE, NODE_HEADER, SignData};
 use tokio::net::TcpStream;
 use tokio_tungstenite::{
-  MaybeTlsStream, WebSocketStream, connect_async, tungstenite::client::IntoClientRequest,
+  Connector, MaybeTlsStream, WebSocketStream, connect_async_tls_with_config,
+  tungstenite::client::IntoClientRequest,
 };
 use tracing::debug;
+use uuid::Uuid;
+
+use crate::db::DBTrait;
+
+/// Wings only sends small json bodies to the panel.
+const MAX_BODY_SIZE: usize = 64 * 1024;
 
 pub struct WingsAuth {
   token: String,
@@ -49,7 +72,11 @@ impl WingsAuth {
     Self { token }
   }
 
-  pub async fn connect_websocket(addr: &str, token: &str) -> centaurus::error::Result<WsStream> {
+  pub async fn connect_websocket(
+    addr: &str,
+    token: &str,
+    tls: Option<Arc<ClientConfig>>,
+  ) -> centaurus::error::Result<WsStream> {
     let mut request = addr
       .into_client_request()
       .context("Invalid wings address")?;
@@ -61,9 +88,10 @@ impl WingsAuth {
       .add_to_header_map(request.headers_mut(), token)
       .context("Failed to sign websocket request")?;
 
-    let (stream, res) = connect_async(request)
-      .await
-      .context("Failed to connect to wings")?;
+    let (stream, res) =
+      connect_async_tls_with_config(request, None, false, tls.map(Connector::Rustls))
+        .await
+        .context("Failed to connect to wings")?;
 
     debug!("Verifying wings connection to {}", addr);
 
@@ -73,3 +101,90 @@ impl WingsAuth {
     Ok(stream)
   }
 }
+
+/// Authenticates requests wings sends to the panel. The node is identified by
+/// the [`NODE_HEADER`] and the request must be signed with its token. A
+/// signature is only accepted once and only while it is recent, so captured
+/// headers can not be replayed.
+pub struct NodeAuth {
+  pub node: node::Model,
+  pub timestamp: String,
+}
+
+impl NodeAuth {
+  /// Headers to sign the response with, so wings can verify it talks to the
+  /// panel its token belongs to.
+  pub fn response_headers(&self) -> centaurus::error::Result<HeaderMap> {
+    SignData::from_timestamp(self.timestamp.clone()).to_header_map(&self.node.token)
+  }
+}
+
+impl NodeAuth {
+  async fn authenticate(parts: &mut Parts, body: Option<&[u8]>) -> Result<Self, ErrorReport> {
+    let Some(node_id) = parts
+      .headers
+      .get(NODE_HEADER)
+      .and_then(|value| value.to_str().ok())
+      .and_then(|value| Uuid::parse_str(value).ok())
+    else {
+      bail!(UNAUTHORIZED, "Missing or invalid {} header", NODE_HEADER);
+    };
+
+    let db = parts.extract_state::<Connection>().await;
+    let Ok(node) = db.node().find_by_id(node_id).await else {
+      bail!(UNAUTHORIZED, "Unknown node {}", node_id);
+    };
+
+    let data = SignData::validate_request(&parts.headers, &node.token, body)?;
+    // timestamps may be ahead of the clock of the panel as well
+    if !db
+      .node_nonce()
+      .use_nonce(
+        node.id,
+        data.nonce(),
+        Utc::now().naive_utc(),
+        MAX_SIGNATURE_AGE * 2,
+      )
+      .await?
+    {
+      bail!(UNAUTHORIZED, "Replayed request from node {}", node_id);
+    }
+    debug!("Authenticated wings request from node {}", node_id);
+
+    Ok(Self {
+      node,
+      timestamp: data.timestamp().to_string(),
+    })
+  }
+}
+
+impl<S: Sync> FromRequestParts<S> for NodeAuth {
+  type Rejection = ErrorReport;
+
+  async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+    Self::authenticate(parts, None).await
+  }
+}
+
+/// Json body of a request from wings, which the signature has to cover too.
+pub struct SignedJson<T> {
+  pub auth: NodeAuth,
+  pub data: T,
+}
+
+impl<S: Send + Sync, T: DeserializeOwned> FromRequest<S> for SignedJson<T> {
+  type Rejection = ErrorReport;
+
+  async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+    let (mut parts, body) = req.into_parts();
+    let body = to_bytes(body, MAX_BODY_SIZE)
+      .await
+      .status_context(StatusCode::BAD_REQUEST, "Failed to read request body")?;
+
+    let auth = NodeAuth::authenticate(&mut parts, Some(&body)).await?;
+    let data = serde_json::from_slice(&body)
+      .status_context(StatusCode::BAD_REQUEST, "Invalid request body")?;
+
+    Ok(Self { auth, data })
+  }
+}
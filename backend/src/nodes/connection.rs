@@ -1,20 +1,27 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+  pin::Pin,
+  sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+  },
+  time::{Duration, Instant},
+};
 
 use centaurus::{
   bail,
+  db::init::Connection,
   error::{ErrorReportStatusExt, Result},
 };
-use futures_util::{
-  SinkExt, StreamExt,
-  stream::{SplitSink, SplitStream},
-};
+use chrono::{DateTime, Utc};
+use futures_util::{Sink, SinkExt, Stream, StreamExt, future::pending};
 use http::StatusCode;
 use reqwest::Client;
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
-use shared::msg::WingsMessage;
+use rustls::ClientConfig;
+use shared::msg::{NodeConfig, WingsMessage};
 use tokio::{
   spawn,
-  sync::{Mutex, Notify, oneshot},
+  sync::{Mutex, Notify, mpsc, oneshot},
   task::JoinHandle,
   time::sleep,
 };
@@ -23,50 +30,104 @@ use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use crate::{
-  nodes::auth::{WingsAuth, WsStream},
+  db::{
+    DBTrait,
+    node::{Node, NodeLifecycle},
+    node_sample::{Resolution, Sample},
+    webhook::WebhookEvent,
+  },
+  nodes::{auth::WingsAuth, metrics, tls},
   utils::{UpdateMessage, Updater},
+  webhooks::Webhooks,
 };
 
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+const MAINTENANCE_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+/// Wings is usually installed a while after the node was created.
+const PENDING_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+/// How stale `last_seen_at` may get while a node is connected.
+const SEEN_INTERVAL: Duration = Duration::from_secs(30);
+
+pub type WsSender = Pin<Box<dyn Sink<tungstenite::Message, Error = tungstenite::Error> + Send>>;
+pub type WsReceiver =
+  Pin<Box<dyn Stream<Item = std::result::Result<tungstenite::Message, tungstenite::Error>> + Send>>;
+
 pub struct WingsConnection {
   uuid: Uuid,
-  sender: Option<SplitSink<WsStream, tungstenite::Message>>,
+  sender: Option<WsSender>,
   #[allow(unused)]
   client: ClientWithMiddleware,
   receiver: Option<JoinHandle<()>>,
   reconnect: JoinHandle<()>,
   disconnect: Arc<Notify>,
+  maintenance: Arc<AtomicBool>,
+  disconnected_since: Option<DateTime<Utc>>,
+  /// Set for nodes that open the websocket to the panel themselves.
+  inbound: Option<mpsc::Sender<(WsSender, WsReceiver)>>,
 }
 
 impl WingsConnection {
   pub async fn new(
-    uuid: Uuid,
-    addr: &str,
-    port: i16,
-    secure: bool,
-    token: String,
+    node: &Node,
+    db: Connection,
     updater: Updater,
+    webhooks: Webhooks,
   ) -> Result<Arc<Mutex<Self>>> {
+    let uuid = node.id;
     let addr = format!(
       "{}://{}:{}/api",
-      if secure { "wss" } else { "ws" },
-      addr,
-      port
+      if node.secure { "wss" } else { "ws" },
+      node.address,
+      node.port
     );
 
-    let client = Client::new();
+    let tls = tls::client_config(node.tls_ca.as_deref(), node.tls_pin.as_ref())?;
+    let mut client = Client::builder();
+    if let Some(tls) = &tls {
+      client = client.tls_backend_preconfigured(ClientConfig::clone(tls));
+    }
+    let client = client.build().status_context(
+      StatusCode::INTERNAL_SERVER_ERROR,
+      "Failed to build wings client",
+    )?;
     let client = ClientBuilder::new(client)
-      .with(WingsAuth::new(token.clone()))
+      .with(WingsAuth::new(node.token.clone()))
       .build();
 
     let (sender, receiver) = oneshot::channel();
     let disconnect = Arc::new(Notify::new());
+    let maintenance = Arc::new(AtomicBool::new(node.maintenance));
+
+    let (inbound, reconnect) = if node.reverse_connect {
+      let (inbound, streams) = mpsc::channel(1);
+      let task = spawn(accept_task(
+        uuid,
+        receiver,
+        streams,
+        disconnect.clone(),
+        db,
+        updater,
+        webhooks,
+      ));
+      (Some(inbound), task)
+    } else {
+      let task = spawn(reconnect_task(
+        uuid,
+        receiver,
+        addr,
+        node.token.clone(),
+        tls,
+        node.lifecycle == NodeLifecycle::PendingSetup,
+        disconnect.clone(),
+        maintenance.clone(),
+        db,
+        updater,
+        webhooks,
+      ));
+      (None, task)
+    };
 
-    let reconnect = spawn({
-      let disconnect = disconnect.clone();
-
-      reconnect_task(uuid, receiver, addr, token, disconnect, updater)
-    });
-
+    metrics::node_added();
     let conn = Arc::new(Mutex::new(Self {
       uuid,
       sender: None,
@@ -74,6 +135,9 @@ impl WingsConnection {
       client,
       reconnect,
       disconnect,
+      maintenance,
+      disconnected_since: Some(Utc::now()),
+      inbound,
     }));
 
     sender.send(conn.clone()).ok().status_context(
@@ -88,6 +152,22 @@ impl WingsConnection {
     self.sender.is_some()
   }
 
+  /// When the connection was lost, or the connection was created if it was
+  /// never established.
+  pub fn disconnected_since(&self) -> Option<DateTime<Utc>> {
+    self.disconnected_since
+  }
+
+  /// Channel to hand websockets opened by wings to the connection task, `None`
+  /// if the panel dials the node instead.
+  pub fn inbound(&self) -> Option<mpsc::Sender<(WsSender, WsReceiver)>> {
+    self.inbound.clone()
+  }
+
+  pub fn set_maintenance(&self, maintenance: bool) {
+    self.maintenance.store(maintenance, Ordering::Relaxed);
+  }
+
   pub fn disconnect(&self) {
     self.disconnect.notify_waiters();
     if let Some(handle) = &self.receiver {
@@ -96,12 +176,12 @@ impl WingsConnection {
     self.reconnect.abort();
   }
 
-  #[allow(unused)]
   pub async fn send(&mut self, msg: &WingsMessage) -> Result<()> {
     let Some(sender) = &mut self.sender else {
       bail!("Wings connection to {} is not established", self.uuid);
     };
 
+    metrics::message_sent(self.uuid, msg.kind());
     let msg = serde_json::to_string(msg)?;
     sender
       .send(tungstenite::Message::Binary(msg.into()))
@@ -118,16 +198,23 @@ impl WingsConnection {
 impl Drop for WingsConnection {
   fn drop(&mut self) {
     self.disconnect();
+    metrics::node_removed(self.is_connected());
   }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn reconnect_task(
   uuid: Uuid,
   receiver: oneshot::Receiver<Arc<Mutex<WingsConnection>>>,
   addr: String,
   token: String,
+  tls: Option<Arc<ClientConfig>>,
+  mut pending: bool,
   disconnect: Arc<Notify>,
+  maintenance: Arc<AtomicBool>,
+  db: Connection,
   updater: Updater,
+  webhooks: Webhooks,
 ) {
   let Ok(conn) = receiver.await else {
     error!(
@@ -151,28 +238,41 @@ async fn reconnect_task(
       }
     }
 
-    let mut conn_ref = conn.lock().await;
-
-    // only send update if we were previously connected
-    if conn_ref.sender.is_some() {
-      updater.broadcast(UpdateMessage::Nodes { uuid }).await;
-    }
-
-    conn_ref.sender = None;
-    conn_ref.receiver = None;
-    drop(conn_ref);
+    lost(&conn, uuid, &db, &updater, &webhooks).await;
 
-    let stream = match WingsAuth::connect_websocket(&addr, &token).await {
-      Ok(stream) => stream,
+    metrics::connect_attempt(uuid);
+    let start = Instant::now();
+    let stream = match WingsAuth::connect_websocket(&addr, &token, tls.clone()).await {
+      Ok(stream) => {
+        metrics::connect_duration(uuid, start.elapsed());
+        stream
+      }
       Err(err) => {
-        warn!(
-          "Failed to reconnect to wings websocket for {}: {:?}",
-          uuid, err
-        );
+        // nodes in maintenance are expected to be unreachable, so retry less often and quietly
+        let delay = if maintenance.load(Ordering::Relaxed) {
+          debug!(
+            "Failed to reconnect to wings websocket for {} (maintenance): {:?}",
+            uuid, err
+          );
+          MAINTENANCE_RECONNECT_DELAY
+        } else if pending {
+          debug!(
+            "Failed to connect to wings for {} (pending setup): {:?}",
+            uuid, err
+          );
+          PENDING_RECONNECT_DELAY
+        } else {
+          warn!(
+            "Failed to reconnect to wings websocket for {}: {:?}",
+            uuid, err
+          );
+          RECONNECT_DELAY
+        };
+
         spawn({
           let reconnect = reconnect.clone();
           async move {
-            sleep(Duration::from_secs(5)).await;
+            sleep(delay).await;
             reconnect.notify_one();
           }
         });
@@ -180,31 +280,183 @@ async fn reconnect_task(
       }
     };
 
+    pending = false;
     let (sender, receiver) = stream.split();
     let receiver = spawn(receiver_task(
       uuid,
-      receiver,
+      Box::pin(receiver),
+      db.clone(),
+      updater.clone(),
       reconnect.clone(),
       disconnect.clone(),
     ));
+    established(
+      &conn,
+      uuid,
+      Box::pin(sender),
+      Some(receiver),
+      &db,
+      &updater,
+      &webhooks,
+    )
+    .await;
 
-    let mut conn_ref = conn.lock().await;
-    conn_ref.sender = Some(sender);
-    conn_ref.receiver = Some(receiver);
-    drop(conn_ref);
+    debug!("Wings connection to {} re-established", uuid);
+  }
+}
 
+/// Waits for wings to open websockets to the panel. A new websocket replaces
+/// the current one, wings only opens another one if it lost the previous.
+async fn accept_task(
+  uuid: Uuid,
+  receiver: oneshot::Receiver<Arc<Mutex<WingsConnection>>>,
+  mut streams: mpsc::Receiver<(WsSender, WsReceiver)>,
+  disconnect: Arc<Notify>,
+  db: Connection,
+  updater: Updater,
+  webhooks: Webhooks,
+) {
+  let Ok(conn) = receiver.await else {
+    error!(
+      "Wings connection task failed to receive initial connection for {}",
+      uuid
+    );
+    return;
+  };
+
+  let mut current: Option<WsReceiver> = None;
+  let mut last_seen = Instant::now();
+  loop {
+    let msg = tokio::select! {
+      _ = disconnect.notified() => {
+        debug!("Wings connection for {} received disconnect signal, stopping accept task", uuid);
+        return;
+      }
+      Some((sender, receiver)) = streams.recv() => {
+        current = Some(receiver);
+        established(&conn, uuid, sender, None, &db, &updater, &webhooks).await;
+        last_seen = Instant::now();
+        continue;
+      }
+      msg = async {
+        match &mut current {
+          Some(receiver) => receiver.next().await,
+          None => pending().await,
+        }
+      } => msg,
+    };
+
+    if let Some(Ok(msg)) = msg
+      && receive(uuid, &db, &updater, &mut last_seen, msg).await
+    {
+      continue;
+    }
+
+    warn!("Websocket opened by wings closed for {}", uuid);
+    current = None;
+    lost(&conn, uuid, &db, &updater, &webhooks).await;
+  }
+}
+
+async fn lost(
+  conn: &Mutex<WingsConnection>,
+  uuid: Uuid,
+  db: &Connection,
+  updater: &Updater,
+  webhooks: &Webhooks,
+) {
+  let mut conn_ref = conn.lock().await;
+
+  // only send update if we were previously connected
+  if conn_ref.sender.is_some() {
+    let now = Utc::now();
+    conn_ref.disconnected_since = Some(now);
+    if let Err(err) = db.node().lost(uuid, now.naive_utc()).await {
+      warn!("Failed to record lost connection for {}: {:?}", uuid, err);
+    }
+    metrics::connection_changed(false);
     updater.broadcast(UpdateMessage::Nodes { uuid }).await;
+    webhooks.emit(
+      WebhookEvent::NodeDisconnected,
+      serde_json::json!({ "node": uuid }),
+    );
+  }
 
-    debug!("Wings connection to {} re-established", uuid);
+  conn_ref.sender = None;
+  conn_ref.receiver = None;
+}
+
+async fn established(
+  conn: &Mutex<WingsConnection>,
+  uuid: Uuid,
+  sender: WsSender,
+  receiver: Option<JoinHandle<()>>,
+  db: &Connection,
+  updater: &Updater,
+  webhooks: &Webhooks,
+) {
+  let mut conn_ref = conn.lock().await;
+  let replaced = conn_ref.sender.replace(sender).is_some();
+  conn_ref.receiver = receiver;
+  conn_ref.disconnected_since = None;
+  drop(conn_ref);
+
+  if let Err(err) = db.node().connected(uuid, Utc::now().naive_utc()).await {
+    warn!("Failed to record connection for {}: {:?}", uuid, err);
   }
+
+  // a new websocket may come from a restarted wings, so it is synced as well
+  if let Err(err) = sync(conn, uuid, db).await {
+    warn!(
+      "Failed to sync configuration to wings for {}: {:?}",
+      uuid, err
+    );
+  }
+
+  if replaced {
+    debug!("Replaced wings websocket for {}", uuid);
+    return;
+  }
+
+  metrics::connection_changed(true);
+  updater.broadcast(UpdateMessage::Nodes { uuid }).await;
+  webhooks.emit(
+    WebhookEvent::NodeConnected,
+    serde_json::json!({ "node": uuid }),
+  );
+}
+
+/// Pushes the desired configuration of the node, wings answers with a
+/// `SyncReport` once it applied it.
+pub async fn sync(conn: &Mutex<WingsConnection>, uuid: Uuid, db: &Connection) -> Result<()> {
+  let node = db.node().find_by_id(uuid).await?;
+  let config = NodeConfig {
+    memory_limit_mb: node.memory_limit_mb,
+    disk_limit_mb: node.disk_limit_mb,
+    cpu_limit: node.cpu_limit,
+  };
+
+  // recorded first, so a fast report is not overtaken by the request
+  db.node_sync()
+    .requested(uuid, Utc::now().naive_utc())
+    .await?;
+  conn
+    .lock()
+    .await
+    .send(&WingsMessage::SyncConfig(config))
+    .await
 }
 
 async fn receiver_task(
   uuid: Uuid,
-  mut receiver: SplitStream<WsStream>,
+  mut receiver: WsReceiver,
+  db: Connection,
+  updater: Updater,
   reconnect: Arc<Notify>,
   disconnect: Arc<Notify>,
 ) {
+  // written when the connection was established
+  let mut last_seen = Instant::now();
   loop {
     let msg = tokio::select! {
       _ = disconnect.notified() => {
@@ -217,23 +469,8 @@ async fn receiver_task(
     let Some(Ok(next)) = msg else {
       break;
     };
-
-    info!("Received wings message for {}: {:?}", uuid, next);
-    match next {
-      tungstenite::Message::Binary(raw_msg) => {
-        match serde_json::from_slice::<WingsMessage>(&raw_msg) {
-          Ok(msg) => {
-            info!("Parsed wings message for {}: {:?}", uuid, msg);
-          }
-          Err(err) => {
-            info!("Failed to parse wings message for {}: {}", uuid, err);
-          }
-        }
-      }
-      tungstenite::Message::Close(_) => {
-        break;
-      }
-      _ => (),
+    if !receive(uuid, &db, &updater, &mut last_seen, next).await {
+      break;
     }
   }
 
@@ -243,3 +480,76 @@ async fn receiver_task(
   );
   reconnect.notify_one();
 }
+
+/// Returns `false` once wings closed the websocket. `last_seen` is when
+/// `last_seen_at` was last written for this websocket.
+async fn receive(
+  uuid: Uuid,
+  db: &Connection,
+  updater: &Updater,
+  last_seen: &mut Instant,
+  msg: tungstenite::Message,
+) -> bool {
+  debug!("Received wings message for {}: {:?}", uuid, msg);
+  match msg {
+    tungstenite::Message::Binary(raw_msg) => match serde_json::from_slice::<WingsMessage>(&raw_msg)
+    {
+      Ok(msg) => {
+        metrics::message_received(uuid, msg.kind());
+        if last_seen.elapsed() >= SEEN_INTERVAL {
+          *last_seen = Instant::now();
+          if let Err(err) = db.node().seen(uuid, Utc::now().naive_utc()).await {
+            warn!("Failed to record last seen for {}: {:?}", uuid, err);
+          }
+        }
+        handle_message(uuid, db, updater, msg).await;
+      }
+      Err(err) => {
+        info!("Failed to parse wings message for {}: {}", uuid, err);
+      }
+    },
+    tungstenite::Message::Close(_) => return false,
+    _ => (),
+  }
+  true
+}
+
+async fn handle_message(uuid: Uuid, db: &Connection, updater: &Updater, msg: WingsMessage) {
+  match msg {
+    WingsMessage::NodeStats(stats) => {
+      let sample = Sample {
+        timestamp: Utc::now().naive_utc(),
+        stats,
+      };
+      if let Err(err) = db
+        .node_sample()
+        .insert_samples(uuid, Resolution::Raw, vec![sample])
+        .await
+      {
+        warn!("Failed to store node stats for {}: {:?}", uuid, err);
+      }
+    }
+    WingsMessage::SyncReport(report) => {
+      if !report.drift.is_empty() {
+        info!(
+          "Wings {} drifted from the panel configuration: {:?}",
+          uuid, report.drift
+        );
+      }
+      let now = Utc::now().naive_utc();
+      match db.node_sync().reported(uuid, now, report.drift).await {
+        Ok(Some(requested_at)) => {
+          if let Ok(duration) = (now - requested_at).to_std() {
+            metrics::rpc_duration(uuid, duration);
+          }
+        }
+        Ok(None) => (),
+        Err(err) => warn!("Failed to store sync report for {}: {:?}", uuid, err),
+      }
+      updater.broadcast(UpdateMessage::Nodes { uuid }).await;
+    }
+    msg => {
+      info!("Parsed wings message for {}: {:?}", uuid, msg);
+    }
+  }
+}
@@ -0,0 +1,378 @@
+use std::{future::Future, net::SocketAddr, time::Duration};
+
+use aide::axum::{ApiRouter, routing::post_with};
+use axum::{Json, extract::Path};
+use centaurus::{
+  bail,
+  db::init::Connection,
+  error::{ErrorReportStatusExt, Result},
+};
+use http::{StatusCode, Uri};
+use rustls::pki_types::ServerName;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use shared::auth::SignData;
+use tokio::{
+  io::{AsyncRead, AsyncWrite},
+  net::{TcpStream, lookup_host},
+  time::{Instant, timeout},
+};
+use tokio_rustls::TlsConnector;
+use tokio_tungstenite::{client_async, tungstenite::client::IntoClientRequest};
+use uuid::Uuid;
+
+use crate::{
+  auth::jwt_auth::JwtAuth,
+  db::{
+    DBTrait,
+    node::{Node, TlsPin},
+  },
+  nodes::{location::node_scope, tls},
+  utils::NodeEditPerm,
+};
+
+const STEP_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub fn router() -> ApiRouter {
+  ApiRouter::new()
+    .api_route(
+      "/test-connection",
+      post_with(test_connection, |op| op.id("testNodeConnection")),
+    )
+    .api_route(
+      "/{uuid}/diagnose",
+      post_with(diagnose_node, |op| op.id("diagnoseNode")),
+    )
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Step {
+  Dns,
+  Tcp,
+  Tls,
+  /// The websocket upgrade of the signed request.
+  Upgrade,
+  /// Wings signed its response with the same token.
+  Signature,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+  Ok,
+  Failed,
+  /// Not needed for the node, or an earlier step failed.
+  Skipped,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct StepResult {
+  step: Step,
+  status: StepStatus,
+  duration_ms: u64,
+  /// What the step found, like the resolved addresses.
+  detail: Option<String>,
+  error: Option<String>,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct Diagnosis {
+  ok: bool,
+  failed_step: Option<Step>,
+  /// Every step in order, including skipped ones.
+  steps: Vec<StepResult>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct TestConnection {
+  address: String,
+  secure: bool,
+  /// Without a token only the network and TLS are checked.
+  #[serde(default)]
+  token: Option<String>,
+  #[serde(default)]
+  tls_ca: Option<String>,
+  #[serde(default)]
+  tls_pin: Option<TlsPin>,
+}
+
+/// Dials a node that does not exist yet, nothing is stored.
+async fn test_connection(
+  _auth: JwtAuth<NodeEditPerm>,
+  Json(data): Json<TestConnection>,
+) -> Result<Json<Diagnosis>> {
+  let url =
+    Uri::try_from(data.address).status_context(StatusCode::BAD_REQUEST, "Invalid Address")?;
+  let address = url
+    .host()
+    .status_context(StatusCode::BAD_REQUEST, "Address must contain a valid host")?
+    .to_string();
+  let port = url.port_u16().unwrap_or(if data.secure { 443 } else { 80 });
+  let (tls_ca, tls_pin) = tls::normalize(data.tls_ca, data.tls_pin)?;
+
+  let target = Target {
+    address,
+    port,
+    secure: data.secure,
+    token: data.token,
+    tls_ca,
+    tls_pin,
+  };
+  Ok(Json(target.diagnose().await?))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct DiagnoseRequest {
+  uuid: Uuid,
+}
+
+/// Dials the node like the connection does, on a connection of its own, so
+/// the current one is not affected. Requires `node:edit` like testing a new
+/// node, since the handshake is signed with the node token.
+async fn diagnose_node(
+  auth: JwtAuth<NodeEditPerm>,
+  db: Connection,
+  Path(req): Path<DiagnoseRequest>,
+) -> Result<Json<Diagnosis>> {
+  let node = db.node().find_by_id(req.uuid).await?;
+  if !node_scope(&db, auth.user_id)
+    .await?
+    .allows(node.location_id)
+  {
+    bail!(NOT_FOUND, "Node not found");
+  }
+  if node.reverse_connect {
+    bail!(
+      BAD_REQUEST,
+      "Node opens the connection to the panel itself and can not be dialed"
+    );
+  }
+
+  let node = Node::from(node);
+  let target = Target {
+    address: node.address,
+    port: node.port,
+    secure: node.secure,
+    token: Some(node.token),
+    tls_ca: node.tls_ca,
+    tls_pin: node.tls_pin,
+  };
+  Ok(Json(target.diagnose().await?))
+}
+
+struct Target {
+  address: String,
+  port: u16,
+  secure: bool,
+  token: Option<String>,
+  tls_ca: Option<String>,
+  tls_pin: Option<TlsPin>,
+}
+
+impl Target {
+  async fn diagnose(&self) -> Result<Diagnosis> {
+    // fails for invalid CA bundles, which is a problem of the request
+    let tls = match tls::client_config(self.tls_ca.as_deref(), self.tls_pin.as_ref())? {
+      Some(tls) => tls,
+      None => tls::web_roots()?,
+    };
+    let mut report = Report::default();
+    // hosts of ipv6 addresses keep their brackets in the uri
+    let host = self.address.trim_start_matches('[').trim_end_matches(']');
+
+    let Some(addrs) = report
+      .run(Step::Dns, async {
+        let addrs = lookup_host((host, self.port))
+          .await
+          .map_err(|err| err.to_string())?
+          .collect::<Vec<SocketAddr>>();
+        if addrs.is_empty() {
+          return Err("No addresses found".to_string());
+        }
+        let detail = addrs
+          .iter()
+          .map(ToString::to_string)
+          .collect::<Vec<_>>()
+          .join(", ");
+        Ok((addrs, Some(detail)))
+      })
+      .await
+    else {
+      return Ok(report.finish());
+    };
+
+    let Some(stream) = report
+      .run(Step::Tcp, async {
+        let mut last_err = None;
+        for addr in addrs {
+          match TcpStream::connect(addr).await {
+            Ok(stream) => return Ok((stream, Some(addr.to_string()))),
+            Err(err) => last_err = Some(format!("{addr}: {err}")),
+          }
+        }
+        Err(last_err.unwrap_or_default())
+      })
+      .await
+    else {
+      return Ok(report.finish());
+    };
+
+    if !self.secure {
+      report.skip(Step::Tls, "Node does not use TLS");
+      self.upgrade(report, stream).await
+    } else {
+      let server_name = ServerName::try_from(host.to_string())
+        .status_context(StatusCode::BAD_REQUEST, "Invalid host name")?;
+      let Some(stream) = report
+        .run(Step::Tls, async {
+          let stream = TlsConnector::from(tls)
+            .connect(server_name, stream)
+            .await
+            .map_err(|err| err.to_string())?;
+          let version = stream
+            .get_ref()
+            .1
+            .protocol_version()
+            .map(|version| format!("{version:?}"));
+          Ok((stream, version))
+        })
+        .await
+      else {
+        return Ok(report.finish());
+      };
+      self.upgrade(report, stream).await
+    }
+  }
+
+  async fn upgrade<S>(&self, mut report: Report, stream: S) -> Result<Diagnosis>
+  where
+    S: AsyncRead + AsyncWrite + Unpin,
+  {
+    let Some(token) = &self.token else {
+      report.skip(Step::Upgrade, "No token given");
+      return Ok(report.finish());
+    };
+
+    let addr = format!(
+      "{}://{}:{}/api",
+      if self.secure { "wss" } else { "ws" },
+      self.address,
+      self.port
+    );
+    let mut request = addr
+      .into_client_request()
+      .status_context(StatusCode::BAD_REQUEST, "Invalid Address")?;
+    let data = SignData::new();
+    data.add_to_header_map(request.headers_mut(), token)?;
+
+    let Some(res) = report
+      .run(Step::Upgrade, async {
+        let (mut stream, res) = client_async(request, stream)
+          .await
+          .map_err(|err| err.to_string())?;
+        stream.close(None).await.ok();
+        Ok((res, None))
+      })
+      .await
+    else {
+      return Ok(report.finish());
+    };
+
+    report
+      .run(Step::Signature, async {
+        SignData::validate_header_map(res.headers(), token, Some(data))
+          .map(|_| ((), None))
+          .map_err(|err| format!("{err:#}"))
+      })
+      .await;
+
+    Ok(report.finish())
+  }
+}
+
+#[derive(Default)]
+struct Report {
+  steps: Vec<StepResult>,
+}
+
+impl Report {
+  /// Runs a step with a timeout, `None` if it failed.
+  async fn run<T>(
+    &mut self,
+    step: Step,
+    fut: impl Future<Output = std::result::Result<(T, Option<String>), String>>,
+  ) -> Option<T> {
+    let start = Instant::now();
+    let res = match timeout(STEP_TIMEOUT, fut).await {
+      Ok(res) => res,
+      Err(_) => Err(format!("Timed out after {}s", STEP_TIMEOUT.as_secs())),
+    };
+    let duration_ms = start.elapsed().as_millis() as u64;
+
+    match res {
+      Ok((value, detail)) => {
+        self.steps.push(StepResult {
+          step,
+          status: StepStatus::Ok,
+          duration_ms,
+          detail,
+          error: None,
+        });
+        Some(value)
+      }
+      Err(error) => {
+        self.steps.push(StepResult {
+          step,
+          status: StepStatus::Failed,
+          duration_ms,
+          detail: None,
+          error: Some(error),
+        });
+        None
+      }
+    }
+  }
+
+  fn skip(&mut self, step: Step, reason: &str) {
+    self.steps.push(StepResult {
+      step,
+      status: StepStatus::Skipped,
+      duration_ms: 0,
+      detail: Some(reason.to_string()),
+      error: None,
+    });
+  }
+
+  /// Marks the steps that were not reached as skipped.
+  fn finish(mut self) -> Diagnosis {
+    let failed_step = self
+      .steps
+      .iter()
+      .find(|step| step.status == StepStatus::Failed)
+      .map(|step| step.step);
+
+    for step in [
+      Step::Dns,
+      Step::Tcp,
+      Step::Tls,
+      Step::Upgrade,
+      Step::Signature,
+    ] {
+      if !self.steps.iter().any(|result| result.step == step) {
+        let reason = if failed_step.is_some() {
+          "An earlier step failed"
+        } else {
+          "Not needed without a token"
+        };
+        self.skip(step, reason);
+      }
+    }
+
+    Diagnosis {
+      ok: failed_step.is_none(),
+      failed_step,
+      steps: self.steps,
+    }
+  }
+}
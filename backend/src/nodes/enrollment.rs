@@ -0,0 +1,121 @@
+use aide::axum::{ApiRouter, routing::post_with};
+use axum::{Json, extract::Path, routing::post};
+use centaurus::{bail, db::init::Connection, error::Result};
+use chrono::{DateTime, TimeDelta, Utc};
+use http::HeaderMap;
+use rand::Rng;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use shared::auth::SignData;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::{
+  auth::jwt_auth::JwtAuth,
+  db::DBTrait,
+  nodes::state::Wings,
+  utils::{NodeEditPerm, UpdateMessage, Updater},
+};
+
+const ENROLLMENT_TTL: TimeDelta = TimeDelta::minutes(15);
+
+pub fn router() -> ApiRouter {
+  ApiRouter::new().api_route(
+    "/{uuid}/enrollment",
+    post_with(create_enrollment, |op| op.id("createNodeEnrollment")),
+  )
+}
+
+pub fn wings_router() -> ApiRouter {
+  // internal endpoint for wings, so it is left out of the api docs
+  ApiRouter::new().route("/enroll", post(enroll))
+}
+
+fn hash_code(code: &str) -> String {
+  hex::encode(Sha256::digest(code.as_bytes()))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct EnrollmentRequest {
+  uuid: Uuid,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct CreateEnrollmentRes {
+  /// Single use, wings exchanges it for the node token on startup.
+  code: String,
+  expires_at: DateTime<Utc>,
+}
+
+/// Issues a new enrollment code for the node, invalidating any pending one.
+async fn create_enrollment(
+  _auth: JwtAuth<NodeEditPerm>,
+  db: Connection,
+  Path(req): Path<EnrollmentRequest>,
+) -> Result<Json<CreateEnrollmentRes>> {
+  db.node().find_by_id(req.uuid).await?;
+
+  let mut raw_code = [0u8; 16];
+  rand::rng().fill_bytes(&mut raw_code);
+  let code = hex::encode(raw_code);
+  let expires_at = Utc::now() + ENROLLMENT_TTL;
+
+  db.node_enrollment()
+    .issue(req.uuid, hash_code(&code), expires_at.naive_utc())
+    .await?;
+  info!("Issued enrollment code for node with ID {}", req.uuid);
+
+  Ok(Json(CreateEnrollmentRes { code, expires_at }))
+}
+
+#[derive(Deserialize)]
+struct Enroll {
+  code: String,
+}
+
+#[derive(Serialize)]
+struct Enrollment {
+  node: Uuid,
+  token: String,
+}
+
+/// Exchanges an enrollment code for the node token. Request and response are
+/// signed with the code, so wings only accepts a token from the panel that
+/// issued the code.
+async fn enroll(
+  db: Connection,
+  wings: Wings,
+  updater: Updater,
+  headers: HeaderMap,
+  Json(data): Json<Enroll>,
+) -> Result<(HeaderMap, Json<Enrollment>)> {
+  let timestamp = SignData::validate_header_map(&headers, &data.code, None)?;
+
+  let Some(node_id) = db
+    .node_enrollment()
+    .redeem(&hash_code(&data.code), Utc::now().naive_utc())
+    .await?
+  else {
+    bail!(UNAUTHORIZED, "Invalid or expired enrollment code");
+  };
+  db.node().enrolled(node_id).await?;
+  let node = db.node().find_by_id(node_id).await?;
+  info!("Node with ID {} enrolled", node_id);
+
+  if let Err(err) = wings.enrolled(node.clone().into()).await {
+    warn!("Failed to dial enrolled node {}: {:?}", node_id, err);
+  }
+
+  updater
+    .broadcast(UpdateMessage::Nodes { uuid: node_id })
+    .await;
+
+  Ok((
+    SignData::from_timestamp(timestamp).to_header_map(&data.code)?,
+    Json(Enrollment {
+      node: node_id,
+      token: node.token,
+    }),
+  ))
+}
@@ -0,0 +1,293 @@
+use std::time::Duration;
+
+use aide::axum::{ApiRouter, routing::get_with};
+use axum::{
+  Json,
+  extract::{Path, Query},
+};
+use centaurus::{
+  bail,
+  db::{init::Connection, tables::ConnectionExt},
+  error::Result,
+};
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use shared::msg::NodeStats;
+use tokio::{spawn, time::interval};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::{
+  auth::jwt_auth::JwtAuth,
+  cluster::Cluster,
+  db::{
+    DBTrait,
+    node_sample::{Resolution, Sample},
+  },
+  nodes::location::node_scope,
+  settings::RetentionSettings,
+  utils::NodeViewPerm,
+};
+
+const ROLLUP_INTERVAL: Duration = Duration::from_secs(60);
+const DEFAULT_RANGE: TimeDelta = TimeDelta::hours(1);
+
+pub fn router() -> ApiRouter {
+  ApiRouter::new().api_route(
+    "/{uuid}/history",
+    get_with(node_history, |op| op.id("nodeHistory")),
+  )
+}
+
+/// Periodically averages samples into the coarser resolutions and drops the
+/// ones that are past their retention. Only the leader rolls up, the samples
+/// are shared by all instances.
+pub fn spawn_rollup(db: Connection, cluster: Cluster) {
+  spawn(async move {
+    let mut interval = interval(ROLLUP_INTERVAL);
+    loop {
+      interval.tick().await;
+      if !cluster.is_leader() {
+        continue;
+      }
+      if let Err(err) = rollup(&db, Utc::now().naive_utc()).await {
+        warn!("Failed to roll up node samples: {:?}", err);
+      }
+    }
+  });
+}
+
+async fn rollup(db: &Connection, now: NaiveDateTime) -> Result<()> {
+  let retention = db.settings().get_settings::<RetentionSettings>().await?;
+
+  for node in db.node().list_nodes().await? {
+    rollup_node(db, node.id, Resolution::Raw, Resolution::Minute, now).await?;
+    rollup_node(
+      db,
+      node.id,
+      Resolution::Minute,
+      Resolution::QuarterHour,
+      now,
+    )
+    .await?;
+  }
+
+  for (resolution, keep) in [
+    (
+      Resolution::Raw,
+      TimeDelta::minutes(retention.raw_minutes as i64),
+    ),
+    (
+      Resolution::Minute,
+      TimeDelta::hours(retention.minute_hours as i64),
+    ),
+    (
+      Resolution::QuarterHour,
+      TimeDelta::days(retention.quarter_hour_days as i64),
+    ),
+  ] {
+    let pruned = db.node_sample().prune(resolution, now - keep).await?;
+    if pruned > 0 {
+      debug!("Pruned {} {:?} node samples", pruned, resolution);
+    }
+  }
+
+  Ok(())
+}
+
+async fn rollup_node(
+  db: &Connection,
+  node_id: Uuid,
+  source: Resolution,
+  target: Resolution,
+  now: NaiveDateTime,
+) -> Result<()> {
+  let width = TimeDelta::seconds(target.seconds() as i64);
+  // only complete buckets are rolled up, the current one is still filling
+  let end = bucket_start(now, width);
+  let start = match db.node_sample().latest_timestamp(node_id, target).await? {
+    Some(latest) => latest + width,
+    None => DateTime::UNIX_EPOCH.naive_utc(),
+  };
+  if start >= end {
+    return Ok(());
+  }
+
+  let samples = db
+    .node_sample()
+    .list_samples(node_id, source, start, end)
+    .await?;
+  db.node_sample()
+    .insert_samples(node_id, target, downsample(&samples, width))
+    .await?;
+
+  Ok(())
+}
+
+fn bucket_start(timestamp: NaiveDateTime, width: TimeDelta) -> NaiveDateTime {
+  let width = width.num_seconds();
+  let seconds = timestamp.and_utc().timestamp();
+  DateTime::from_timestamp(seconds - seconds.rem_euclid(width), 0)
+    .unwrap_or_default()
+    .naive_utc()
+}
+
+/// Averages time ordered samples into buckets of `width`, each stamped with
+/// the start of its bucket. Empty buckets produce no sample.
+fn downsample(samples: &[Sample], width: TimeDelta) -> Vec<Sample> {
+  samples
+    .chunk_by(|a, b| bucket_start(a.timestamp, width) == bucket_start(b.timestamp, width))
+    .map(|bucket| {
+      let count = bucket.len() as f64;
+      let mut sum = NodeStats::default();
+      for sample in bucket {
+        sum.cpu_percent += sample.stats.cpu_percent;
+        sum.memory_used_mb += sample.stats.memory_used_mb;
+        sum.memory_total_mb += sample.stats.memory_total_mb;
+        sum.disk_used_mb += sample.stats.disk_used_mb;
+        sum.disk_total_mb += sample.stats.disk_total_mb;
+      }
+
+      Sample {
+        timestamp: bucket_start(bucket[0].timestamp, width),
+        stats: NodeStats {
+          cpu_percent: sum.cpu_percent / count,
+          memory_used_mb: sum.memory_used_mb / count,
+          memory_total_mb: sum.memory_total_mb / count,
+          disk_used_mb: sum.disk_used_mb / count,
+          disk_total_mb: sum.disk_total_mb / count,
+        },
+      }
+    })
+    .collect()
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct NodeHistoryRequest {
+  uuid: Uuid,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct NodeHistoryQuery {
+  /// Defaults to one hour before `to`.
+  from: Option<DateTime<Utc>>,
+  /// Defaults to now.
+  to: Option<DateTime<Utc>>,
+  /// Defaults to the finest resolution that is still retained at `from`.
+  resolution: Option<Resolution>,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct HistorySample {
+  timestamp: DateTime<Utc>,
+  cpu_percent: f64,
+  memory_used_mb: f64,
+  memory_total_mb: f64,
+  disk_used_mb: f64,
+  disk_total_mb: f64,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct NodeHistory {
+  resolution: Resolution,
+  samples: Vec<HistorySample>,
+}
+
+async fn node_history(
+  auth: JwtAuth<NodeViewPerm>,
+  db: Connection,
+  Path(req): Path<NodeHistoryRequest>,
+  Query(query): Query<NodeHistoryQuery>,
+) -> Result<Json<NodeHistory>> {
+  let node = db.node().find_by_id(req.uuid).await?;
+  if !node_scope(&db, auth.user_id)
+    .await?
+    .allows(node.location_id)
+  {
+    bail!(NOT_FOUND, "Node not found");
+  }
+
+  let now = Utc::now();
+  let to = query.to.unwrap_or(now);
+  let from = query.from.unwrap_or(to - DEFAULT_RANGE);
+  if from >= to {
+    bail!(BAD_REQUEST, "History range must start before it ends");
+  }
+
+  let resolution = match query.resolution {
+    Some(resolution) => resolution,
+    None => {
+      let retention = db.settings().get_settings::<RetentionSettings>().await?;
+      if from >= now - TimeDelta::minutes(retention.raw_minutes as i64) {
+        Resolution::Raw
+      } else if from >= now - TimeDelta::hours(retention.minute_hours as i64) {
+        Resolution::Minute
+      } else {
+        Resolution::QuarterHour
+      }
+    }
+  };
+
+  let samples = db
+    .node_sample()
+    .list_samples(node.id, resolution, from.naive_utc(), to.naive_utc())
+    .await?;
+
+  Ok(Json(NodeHistory {
+    resolution,
+    samples: samples
+      .into_iter()
+      .map(|sample| HistorySample {
+        timestamp: sample.timestamp.and_utc(),
+        cpu_percent: sample.stats.cpu_percent,
+        memory_used_mb: sample.stats.memory_used_mb,
+        memory_total_mb: sample.stats.memory_total_mb,
+        disk_used_mb: sample.stats.disk_used_mb,
+        disk_total_mb: sample.stats.disk_total_mb,
+      })
+      .collect(),
+  }))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn sample(timestamp: &str, cpu_percent: f64) -> Sample {
+    Sample {
+      timestamp: timestamp.parse().unwrap(),
+      stats: NodeStats {
+        cpu_percent,
+        ..Default::default()
+      },
+    }
+  }
+
+  #[test]
+  fn downsample_averages_per_bucket() {
+    let samples = [
+      sample("2026-02-12T10:00:05", 10.0),
+      sample("2026-02-12T10:00:55", 30.0),
+      sample("2026-02-12T10:02:10", 50.0),
+    ];
+
+    let rolled = downsample(&samples, TimeDelta::minutes(1));
+    assert_eq!(
+      rolled,
+      vec![
+        sample("2026-02-12T10:00:00", 20.0),
+        sample("2026-02-12T10:02:00", 50.0)
+      ]
+    );
+  }
+
+  #[test]
+  fn bucket_start_aligns_to_width() {
+    let timestamp = "2026-02-12T10:44:59".parse().unwrap();
+    assert_eq!(
+      bucket_start(timestamp, TimeDelta::minutes(15)),
+      "2026-02-12T10:30:00".parse::<NaiveDateTime>().unwrap()
+    );
+  }
+}
@@ -0,0 +1,223 @@
+use aide::axum::{
+  ApiRouter,
+  routing::{delete_with, get_with, post_with},
+};
+use axum::{Json, extract::Path};
+use centaurus::{
+  backend::auth::permission::Permission,
+  bail,
+  db::{init::Connection, tables::ConnectionExt},
+  error::Result,
+};
+use schemars::JsonSchema;
+use sea_orm::{IntoActiveModel, Set};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+  auth::jwt_auth::JwtAuth,
+  db::{
+    DBTrait,
+    location::{Location, NodeScope},
+    webhook::WebhookEvent,
+  },
+  utils::{NodeEditPerm, NodeViewPerm, UpdateMessage, Updater},
+  webhooks::Webhooks,
+};
+
+pub fn router() -> ApiRouter {
+  ApiRouter::new()
+    .api_route(
+      "/locations",
+      post_with(create_location, |op| op.id("createLocation")),
+    )
+    .api_route(
+      "/locations",
+      get_with(list_locations, |op| op.id("listLocations")),
+    )
+    .api_route(
+      "/locations",
+      delete_with(delete_location, |op| op.id("deleteLocation")),
+    )
+    .api_route(
+      "/locations/{uuid}",
+      post_with(update_location, |op| op.id("updateLocation")),
+    )
+    .api_route(
+      "/locations/{uuid}/groups",
+      post_with(set_location_groups, |op| op.id("setLocationGroups")),
+    )
+}
+
+/// Users with `node:view` see every node, everybody else only the nodes in
+/// locations one of their groups was granted access to.
+pub async fn node_scope(db: &Connection, user_id: Uuid) -> Result<NodeScope> {
+  if db
+    .group()
+    .user_hash_permissions(user_id, NodeViewPerm::name())
+    .await?
+  {
+    return Ok(NodeScope::All);
+  }
+
+  Ok(NodeScope::Locations(
+    db.location().granted_locations(user_id).await?,
+  ))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct CreateLocation {
+  name: String,
+  description: Option<String>,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct CreateLocationRes {
+  uuid: Uuid,
+}
+
+async fn create_location(
+  _auth: JwtAuth<NodeEditPerm>,
+  db: Connection,
+  Json(data): Json<CreateLocation>,
+) -> Result<Json<CreateLocationRes>> {
+  if db.location().find_by_name(data.name.clone()).await.is_ok() {
+    bail!(CONFLICT, "Location with this name already exists");
+  }
+
+  let id = Uuid::now_v7();
+  db.location()
+    .create_location(Location {
+      id,
+      name: data.name,
+      description: data.description,
+    })
+    .await?;
+  info!("Created location with ID {}", id);
+
+  Ok(Json(CreateLocationRes { uuid: id }))
+}
+
+#[derive(Serialize, JsonSchema)]
+struct LocationInfo {
+  id: Uuid,
+  name: String,
+  description: Option<String>,
+  /// Groups that may view the nodes of this location without `node:view`.
+  groups: Vec<Uuid>,
+}
+
+async fn list_locations(
+  auth: JwtAuth<NodeViewPerm>,
+  db: Connection,
+) -> Result<Json<Vec<LocationInfo>>> {
+  let scope = node_scope(&db, auth.user_id).await?;
+  let locations = db.location().list_locations().await?;
+
+  let mut infos = Vec::new();
+  for location in locations {
+    if !scope.allows(Some(location.id)) {
+      continue;
+    }
+
+    infos.push(LocationInfo {
+      groups: db.location().location_groups(location.id).await?,
+      id: location.id,
+      name: location.name,
+      description: location.description,
+    });
+  }
+
+  Ok(Json(infos))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct LocationRequest {
+  uuid: Uuid,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct UpdateLocation {
+  name: String,
+  description: Option<String>,
+}
+
+async fn update_location(
+  _auth: JwtAuth<NodeEditPerm>,
+  db: Connection,
+  Path(req): Path<LocationRequest>,
+  Json(data): Json<UpdateLocation>,
+) -> Result<()> {
+  let read_location = db.location().find_by_id(req.uuid).await?;
+  let mut location = read_location.clone().into_active_model();
+
+  if read_location.name != data.name {
+    if let Ok(location) = db.location().find_by_name(data.name.clone()).await
+      && location.id != req.uuid
+    {
+      bail!(CONFLICT, "Location with this name already exists");
+    }
+    location.name = Set(data.name);
+  }
+  location.description = Set(data.description);
+
+  db.location().update_location(location).await?;
+  info!("Updated location with ID {}", req.uuid);
+
+  Ok(())
+}
+
+async fn delete_location(
+  _auth: JwtAuth<NodeEditPerm>,
+  db: Connection,
+  updater: Updater,
+  webhooks: Webhooks,
+  Json(data): Json<LocationRequest>,
+) -> Result<()> {
+  let nodes = db.node().list_nodes().await?;
+  db.location().delete_location(data.uuid).await?;
+  info!("Deleted location with ID {}", data.uuid);
+
+  for node in nodes {
+    if node.location_id == Some(data.uuid) {
+      updater
+        .broadcast(UpdateMessage::Nodes { uuid: node.id })
+        .await;
+      webhooks.emit(
+        WebhookEvent::NodeUpdated,
+        serde_json::json!({ "node": node.id }),
+      );
+    }
+  }
+
+  Ok(())
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct SetLocationGroups {
+  groups: Vec<Uuid>,
+}
+
+async fn set_location_groups(
+  _auth: JwtAuth<NodeEditPerm>,
+  db: Connection,
+  Path(req): Path<LocationRequest>,
+  Json(data): Json<SetLocationGroups>,
+) -> Result<()> {
+  db.location().find_by_id(req.uuid).await?;
+
+  let mut groups = data.groups;
+  groups.sort();
+  groups.dedup();
+  for group in &groups {
+    if db.group().group_info(*group).await?.is_none() {
+      bail!(NOT_FOUND, "Group {} not found", group);
+    }
+  }
+
+  db.location().set_location_groups(req.uuid, groups).await?;
+  info!("Updated groups of location with ID {}", req.uuid);
+
+  Ok(())
+}
@@ -2,25 +2,36 @@ use aide::axum::{
   ApiRouter,
   routing::{delete_with, get_with, post_with},
 };
-use axum::{Json, extract::Path};
+use axum::{
+  Json,
+  extract::{Path, Query},
+};
 use centaurus::{
   bail,
   db::init::Connection,
   error::{ErrorReportStatusExt, Result},
 };
+use chrono::NaiveDateTime;
+use entity::node;
 use http::{StatusCode, Uri};
 use rand::Rng;
 use schemars::JsonSchema;
 use sea_orm::{IntoActiveModel, Set};
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 use crate::{
   auth::jwt_auth::JwtAuth,
-  db::{DBTrait, node::Node},
-  nodes::state::Wings,
-  utils::{NodeEditPerm, NodeViewPerm, UpdateMessage, Updater},
+  db::{
+    DBTrait,
+    node::{Node, NodeLifecycle, TlsPin},
+    node_sync::NodeSync,
+    webhook::WebhookEvent,
+  },
+  nodes::{location::node_scope, state::Wings, tls},
+  utils::{NodeEditPerm, NodeViewPerm, UpdateMessage, Updater, double_option},
+  webhooks::Webhooks,
 };
 
 pub fn router() -> ApiRouter {
@@ -30,6 +41,10 @@ pub fn router() -> ApiRouter {
     .api_route("/", delete_with(delete_node, |op| op.id("deleteNode")))
     .api_route("/{uuid}", get_with(node_info, |op| op.id("nodeInfo")))
     .api_route("/{uuid}", post_with(update_node, |op| op.id("updateNode")))
+    .api_route(
+      "/{uuid}/maintenance",
+      post_with(set_maintenance, |op| op.id("setNodeMaintenance")),
+    )
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -40,6 +55,18 @@ struct CreateNode {
   disk_limit_mb: Option<f64>,
   memory_limit_mb: Option<f64>,
   cpu_limit: Option<u32>,
+  #[serde(default)]
+  location: Option<Uuid>,
+  #[serde(default)]
+  tags: Vec<String>,
+  /// Wings opens the websocket to the panel, for nodes the panel can not reach.
+  #[serde(default)]
+  reverse_connect: bool,
+  /// PEM bundle of the CA that issued the certificate of the node.
+  #[serde(default)]
+  tls_ca: Option<String>,
+  #[serde(default)]
+  tls_pin: Option<TlsPin>,
 }
 
 #[derive(Serialize, JsonSchema)]
@@ -52,6 +79,7 @@ async fn create_node(
   db: Connection,
   wings: Wings,
   updater: Updater,
+  webhooks: Webhooks,
   Json(data): Json<CreateNode>,
 ) -> Result<Json<CreateNodeRes>> {
   if db.node().find_by_name(data.name.clone()).await.is_ok() {
@@ -69,24 +97,26 @@ async fn create_node(
     bail!(BAD_REQUEST, "Disk and Memory limits must be non-negative");
   }
 
+  if let Some(location) = data.location {
+    db.location().find_by_id(location).await?;
+  }
+  let tags = normalize_tags(data.tags)?;
+
   let url =
     Uri::try_from(data.address).status_context(StatusCode::BAD_REQUEST, "Invalid Address")?;
   let address = url
     .host()
     .status_context(StatusCode::BAD_REQUEST, "Address must contain a valid host")?
     .to_string();
-  let port = url.port_u16().unwrap_or(if data.secure { 443 } else { 80 }) as i16;
+  let port = url.port_u16().unwrap_or(if data.secure { 443 } else { 80 });
+
+  let (tls_ca, tls_pin) = tls::normalize(data.tls_ca, data.tls_pin)?;
 
   let mut raw_token = [0u8; 32];
   rand::rng().fill_bytes(&mut raw_token);
   let token = hex::encode(raw_token);
 
   let id = Uuid::now_v7();
-
-  wings
-    .connect(id, &address, port, data.secure, &token)
-    .await?;
-
   let model = Node {
     id,
     name: data.name,
@@ -97,12 +127,27 @@ async fn create_node(
     memory_limit_mb: data.memory_limit_mb,
     cpu_limit: data.cpu_limit.map(|v| v as i32),
     token,
+    maintenance: false,
+    maintenance_message: None,
+    location_id: data.location,
+    reverse_connect: data.reverse_connect,
+    tls_ca,
+    tls_pin,
+    lifecycle: NodeLifecycle::PendingSetup,
+    first_connected_at: None,
+    last_seen_at: None,
   };
 
-  db.node().create_node(model).await?;
+  // reserved first, other instances would take over the stored node otherwise
+  wings.reserve(id).await?;
+  // stored first, so the connection can record its first handshake
+  db.node().create_node(model.clone()).await?;
+  db.node().set_tags(id, tags).await?;
+  wings.connect(&model).await?;
   info!("Created node with ID {}", id);
 
   updater.broadcast(UpdateMessage::Nodes { uuid: id }).await;
+  webhooks.emit(WebhookEvent::NodeCreated, serde_json::json!({ "node": id }));
 
   Ok(Json(CreateNodeRes { uuid: id }))
 }
@@ -112,17 +157,37 @@ pub struct NodeInfo {
   pub id: Uuid,
   pub name: String,
   pub address: String,
-  pub port: i16,
+  pub port: u16,
   pub secure: bool,
   pub disk_limit_mb: Option<f64>,
   pub memory_limit_mb: Option<f64>,
   pub cpu_limit: Option<i32>,
-  pub token: String,
+  /// Secret of the wings channel, only included for users with `node:edit`.
+  pub token: Option<String>,
   pub connected: bool,
+  pub maintenance: bool,
+  pub maintenance_message: Option<String>,
+  pub location: Option<Uuid>,
+  pub tags: Vec<String>,
+  pub reverse_connect: bool,
+  pub tls_ca: Option<String>,
+  pub tls_pin: Option<TlsPin>,
+  /// Result of pushing the configuration to wings, `None` until it connected
+  /// for the first time.
+  pub sync: Option<NodeSync>,
+  pub lifecycle: NodeLifecycle,
+  pub first_connected_at: Option<NaiveDateTime>,
+  pub last_seen_at: Option<NaiveDateTime>,
 }
 
 impl NodeInfo {
-  async fn from_node(node: Node, wings: &Wings) -> Self {
+  async fn from_node(
+    node: Node,
+    tags: Vec<String>,
+    sync: Option<NodeSync>,
+    wings: &Wings,
+    show_token: bool,
+  ) -> Self {
     NodeInfo {
       id: node.id,
       name: node.name,
@@ -132,24 +197,109 @@ impl NodeInfo {
       disk_limit_mb: node.disk_limit_mb,
       memory_limit_mb: node.memory_limit_mb,
       cpu_limit: node.cpu_limit,
-      token: node.token,
+      token: show_token.then_some(node.token),
       connected: wings.is_connected(node.id).await,
+      maintenance: node.maintenance,
+      maintenance_message: node.maintenance_message,
+      location: node.location_id,
+      tags,
+      reverse_connect: node.reverse_connect,
+      tls_ca: node.tls_ca,
+      tls_pin: node.tls_pin,
+      sync,
+      lifecycle: node.lifecycle,
+      first_connected_at: node.first_connected_at,
+      last_seen_at: node.last_seen_at,
     }
   }
 }
 
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum NodeSort {
+  #[default]
+  Name,
+  Address,
+  Connected,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum SortOrder {
+  #[default]
+  Asc,
+  Desc,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ListNodes {
+  location: Option<Uuid>,
+  tag: Option<String>,
+  connected: Option<bool>,
+  /// Case insensitive substring of the node name.
+  name: Option<String>,
+  #[serde(default)]
+  sort: NodeSort,
+  #[serde(default)]
+  order: SortOrder,
+  offset: Option<usize>,
+  limit: Option<usize>,
+}
+
 async fn list_nodes(
-  _auth: JwtAuth<NodeViewPerm>,
+  auth: JwtAuth<NodeViewPerm>,
+  edit: Option<JwtAuth<NodeEditPerm>>,
   db: Connection,
   wings: Wings,
+  Query(query): Query<ListNodes>,
 ) -> Result<Json<Vec<NodeInfo>>> {
+  let scope = node_scope(&db, auth.user_id).await?;
   let nodes = db.node().list_nodes().await?;
-  let mut node_infos = Vec::new();
+  let mut tags = db.node().all_tags().await?;
+  let mut syncs = db.node_sync().all().await?;
+  let name = query.name.map(|name| name.to_lowercase());
 
+  let mut node_infos = Vec::new();
   for node in nodes {
-    node_infos.push(NodeInfo::from_node(node, &wings).await);
+    if !scope.allows(node.location_id)
+      || query.location.is_some_and(|l| node.location_id != Some(l))
+      || name
+        .as_ref()
+        .is_some_and(|name| !node.name.to_lowercase().contains(name))
+    {
+      continue;
+    }
+
+    let node_tags = tags.remove(&node.id).unwrap_or_default();
+    if let Some(tag) = &query.tag
+      && !node_tags.contains(tag)
+    {
+      continue;
+    }
+
+    let sync = syncs.remove(&node.id);
+    let info = NodeInfo::from_node(node, node_tags, sync, &wings, edit.is_some()).await;
+    if query.connected.is_some_and(|c| info.connected != c) {
+      continue;
+    }
+    node_infos.push(info);
   }
 
+  node_infos.sort_by(|a, b| match query.sort {
+    NodeSort::Name => a.name.cmp(&b.name),
+    NodeSort::Address => a.address.cmp(&b.address).then(a.port.cmp(&b.port)),
+    NodeSort::Connected => a.connected.cmp(&b.connected).then(a.name.cmp(&b.name)),
+  });
+  if let SortOrder::Desc = query.order {
+    node_infos.reverse();
+  }
+
+  let node_infos = node_infos
+    .into_iter()
+    .skip(query.offset.unwrap_or(0))
+    .take(query.limit.unwrap_or(usize::MAX))
+    .collect();
+
   Ok(Json(node_infos))
 }
 
@@ -163,6 +313,7 @@ async fn delete_node(
   db: Connection,
   wings: Wings,
   updater: Updater,
+  webhooks: Webhooks,
   Json(data): Json<DeleteNode>,
 ) -> Result<()> {
   wings.disconnect(data.uuid).await?;
@@ -173,6 +324,10 @@ async fn delete_node(
   updater
     .broadcast(UpdateMessage::Nodes { uuid: data.uuid })
     .await;
+  webhooks.emit(
+    WebhookEvent::NodeDeleted,
+    serde_json::json!({ "node": data.uuid }),
+  );
 
   Ok(())
 }
@@ -183,13 +338,23 @@ struct NodeInfoRequest {
 }
 
 async fn node_info(
-  _auth: JwtAuth<NodeViewPerm>,
+  auth: JwtAuth<NodeViewPerm>,
+  edit: Option<JwtAuth<NodeEditPerm>>,
   db: Connection,
   wings: Wings,
   Path(req): Path<NodeInfoRequest>,
 ) -> Result<Json<NodeInfo>> {
   let node = db.node().find_by_id(req.uuid).await?;
-  let node_info = NodeInfo::from_node(node.into(), &wings).await;
+  if !node_scope(&db, auth.user_id)
+    .await?
+    .allows(node.location_id)
+  {
+    bail!(NOT_FOUND, "Node not found");
+  }
+
+  let tags = db.node().tags(node.id).await?;
+  let sync = db.node_sync().find(node.id).await?;
+  let node_info = NodeInfo::from_node(node.into(), tags, sync, &wings, edit.is_some()).await;
 
   Ok(Json(node_info))
 }
@@ -202,6 +367,21 @@ struct UpdateNode {
   disk_limit_mb: Option<f64>,
   memory_limit_mb: Option<f64>,
   cpu_limit: Option<u32>,
+  /// Left unchanged if missing, `null` removes the node from its location.
+  #[serde(default, deserialize_with = "double_option")]
+  location: Option<Option<Uuid>>,
+  /// Left unchanged if missing.
+  tags: Option<Vec<String>>,
+  /// Wings opens the websocket to the panel, for nodes the panel can not reach.
+  /// Left unchanged if missing.
+  reverse_connect: Option<bool>,
+  /// PEM bundle of the CA that issued the certificate of the node. Left
+  /// unchanged if missing, `null` removes it.
+  #[serde(default, deserialize_with = "double_option")]
+  tls_ca: Option<Option<String>>,
+  /// Left unchanged if missing, `null` removes it.
+  #[serde(default, deserialize_with = "double_option")]
+  tls_pin: Option<Option<TlsPin>>,
 }
 
 async fn update_node(
@@ -209,6 +389,7 @@ async fn update_node(
   db: Connection,
   wings: Wings,
   updater: Updater,
+  webhooks: Webhooks,
   Path(req): Path<NodeInfoRequest>,
   Json(data): Json<UpdateNode>,
 ) -> Result<()> {
@@ -223,6 +404,11 @@ async fn update_node(
     bail!(BAD_REQUEST, "Disk and Memory limits must be non-negative");
   }
 
+  if let Some(Some(location)) = data.location {
+    db.location().find_by_id(location).await?;
+  }
+  let tags = data.tags.map(normalize_tags).transpose()?;
+
   let read_node = db.node().find_by_id(req.uuid).await?;
   let mut node = read_node.clone().into_active_model();
 
@@ -241,29 +427,143 @@ async fn update_node(
     .host()
     .status_context(StatusCode::BAD_REQUEST, "Address must contain a valid host")?
     .to_string();
-  let port = url.port_u16().unwrap_or(if data.secure { 443 } else { 80 }) as i16;
-
-  if read_node.address != address || read_node.port != port || read_node.secure != data.secure {
-    wings.disconnect(read_node.id).await.ok();
-
-    wings
-      .connect(read_node.id, &address, port, data.secure, &read_node.token)
-      .await?;
-
-    node.address = Set(address);
-    node.port = Set(port);
-    node.secure = Set(data.secure);
+  let port = url.port_u16().unwrap_or(if data.secure { 443 } else { 80 });
+
+  let current = Node::from(read_node);
+  // the pin and CA are only dropped on request, never by leaving them out
+  let (tls_ca, tls_pin) = tls::normalize(
+    data.tls_ca.unwrap_or_else(|| current.tls_ca.clone()),
+    data.tls_pin.unwrap_or_else(|| current.tls_pin.clone()),
+  )?;
+  let reverse_connect = data.reverse_connect.unwrap_or(current.reverse_connect);
+  let cpu_limit = data.cpu_limit.map(|v| v as i32);
+  let limits_changed = current.disk_limit_mb != data.disk_limit_mb
+    || current.memory_limit_mb != data.memory_limit_mb
+    || current.cpu_limit != cpu_limit;
+
+  let reconnect = current.address != address
+    || current.port != port
+    || current.secure != data.secure
+    || current.reverse_connect != reverse_connect
+    || current.tls_ca != tls_ca
+    || current.tls_pin != tls_pin;
+  let mut updated = None;
+  if reconnect {
+    let node_update = Node {
+      address,
+      port,
+      secure: data.secure,
+      reverse_connect,
+      tls_ca,
+      tls_pin,
+      ..current
+    };
+    let model: node::Model = node_update.clone().into();
+    node.address = Set(model.address);
+    node.port = Set(model.port);
+    node.secure = Set(model.secure);
+    node.reverse_connect = Set(model.reverse_connect);
+    node.tls_ca = Set(model.tls_ca);
+    node.tls_pin = Set(model.tls_pin);
+    updated = Some(node_update);
   }
 
   node.disk_limit_mb = Set(data.disk_limit_mb);
   node.memory_limit_mb = Set(data.memory_limit_mb);
-  node.cpu_limit = Set(data.cpu_limit.map(|v| v as i32));
+  node.cpu_limit = Set(cpu_limit);
+  if let Some(location) = data.location {
+    node.location_id = Set(location);
+  }
 
   db.node().update_node(node).await?;
+  if let Some(tags) = tags {
+    db.node().set_tags(req.uuid, tags).await?;
+  }
+  // after the update, the instance owning the node reloads it from the database
+  if let Some(updated) = &updated {
+    wings.reconnect(updated).await?;
+  }
+  // a new connection syncs on its own
+  if limits_changed
+    && !reconnect
+    && let Err(err) = wings.sync(req.uuid).await
+  {
+    warn!(
+      "Failed to sync configuration to wings for {}: {:?}",
+      req.uuid, err
+    );
+  }
   info!("Updated node with ID {}", req.uuid);
   updater
     .broadcast(UpdateMessage::Nodes { uuid: req.uuid })
     .await;
+  webhooks.emit(
+    WebhookEvent::NodeUpdated,
+    serde_json::json!({ "node": req.uuid }),
+  );
 
   Ok(())
 }
+
+#[derive(Deserialize, JsonSchema)]
+struct SetMaintenance {
+  enabled: bool,
+  /// Shown to users trying to create or start servers on the node.
+  message: Option<String>,
+}
+
+async fn set_maintenance(
+  _auth: JwtAuth<NodeEditPerm>,
+  db: Connection,
+  wings: Wings,
+  updater: Updater,
+  webhooks: Webhooks,
+  Path(req): Path<NodeInfoRequest>,
+  Json(data): Json<SetMaintenance>,
+) -> Result<()> {
+  let read_node = db.node().find_by_id(req.uuid).await?;
+  let mut node = read_node.into_active_model();
+
+  node.maintenance = Set(data.enabled);
+  node.maintenance_message = Set(data.message.filter(|_| data.enabled));
+
+  db.node().update_node(node).await?;
+  wings.set_maintenance(req.uuid, data.enabled).await;
+  info!(
+    "{} maintenance for node with ID {}",
+    if data.enabled { "Enabled" } else { "Disabled" },
+    req.uuid
+  );
+
+  updater
+    .broadcast(UpdateMessage::Nodes { uuid: req.uuid })
+    .await;
+  webhooks.emit(
+    WebhookEvent::NodeUpdated,
+    serde_json::json!({ "node": req.uuid }),
+  );
+
+  Ok(())
+}
+
+const MAX_TAG_LEN: usize = 64;
+
+fn normalize_tags(tags: Vec<String>) -> Result<Vec<String>> {
+  let mut normalized: Vec<String> = Vec::new();
+
+  for tag in tags {
+    let tag = tag.trim().to_string();
+    if tag.is_empty() || tag.len() > MAX_TAG_LEN {
+      bail!(
+        BAD_REQUEST,
+        "Tags must be between 1 and {} characters long",
+        MAX_TAG_LEN
+      );
+    }
+    if !normalized.contains(&tag) {
+      normalized.push(tag);
+    }
+  }
+
+  Ok(normalized)
+}
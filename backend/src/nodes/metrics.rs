@@ -0,0 +1,101 @@
+use std::{sync::OnceLock, time::Duration};
+
+use metrics::{
+  Unit, counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram,
+};
+use uuid::Uuid;
+
+static PREFIX: OnceLock<String> = OnceLock::new();
+
+fn name(metric: &str) -> String {
+  let prefix = PREFIX.get().map(String::as_str).unwrap_or("smaug");
+  format!("{}_{}", prefix, metric)
+}
+
+/// Uses the same prefix as the http metrics of centaurus.
+pub fn init(prefix: &str) {
+  PREFIX.get_or_init(|| prefix.to_string());
+
+  describe_gauge!(name("nodes"), Unit::Count, "Number of registered nodes");
+  describe_gauge!(
+    name("wings_connected"),
+    Unit::Count,
+    "Number of nodes with an established wings connection"
+  );
+  describe_gauge!(
+    name("wings_disconnected"),
+    Unit::Count,
+    "Number of nodes without a wings connection"
+  );
+  describe_counter!(
+    name("wings_connect_attempts_total"),
+    Unit::Count,
+    "Attempts to (re)connect to a wings websocket"
+  );
+  describe_counter!(
+    name("wings_messages_sent_total"),
+    Unit::Count,
+    "Websocket messages sent to wings"
+  );
+  describe_counter!(
+    name("wings_messages_received_total"),
+    Unit::Count,
+    "Websocket messages received from wings"
+  );
+  describe_histogram!(
+    name("wings_connect_duration"),
+    Unit::Seconds,
+    "Duration of the websocket handshake with wings"
+  );
+  describe_histogram!(
+    name("wings_rpc_duration"),
+    Unit::Seconds,
+    "Round trip from pushing the configuration to wings until it reported back"
+  );
+}
+
+pub fn node_added() {
+  gauge!(name("nodes")).increment(1);
+  gauge!(name("wings_disconnected")).increment(1);
+}
+
+pub fn node_removed(connected: bool) {
+  gauge!(name("nodes")).decrement(1);
+  if connected {
+    gauge!(name("wings_connected")).decrement(1);
+  } else {
+    gauge!(name("wings_disconnected")).decrement(1);
+  }
+}
+
+pub fn connection_changed(connected: bool) {
+  let (from, to) = if connected {
+    ("wings_disconnected", "wings_connected")
+  } else {
+    ("wings_connected", "wings_disconnected")
+  };
+  gauge!(name(from)).decrement(1);
+  gauge!(name(to)).increment(1);
+}
+
+pub fn connect_attempt(uuid: Uuid) {
+  counter!(name("wings_connect_attempts_total"), "node" => uuid.to_string()).increment(1);
+}
+
+pub fn connect_duration(uuid: Uuid, duration: Duration) {
+  histogram!(name("wings_connect_duration"), "node" => uuid.to_string()).record(duration);
+}
+
+pub fn rpc_duration(uuid: Uuid, duration: Duration) {
+  histogram!(name("wings_rpc_duration"), "node" => uuid.to_string()).record(duration);
+}
+
+pub fn message_sent(uuid: Uuid, kind: &'static str) {
+  counter!(name("wings_messages_sent_total"), "node" => uuid.to_string(), "type" => kind)
+    .increment(1);
+}
+
+pub fn message_received(uuid: Uuid, kind: &'static str) {
+  counter!(name("wings_messages_received_total"), "node" => uuid.to_string(), "type" => kind)
+    .increment(1);
+}
@@ -2,21 +2,54 @@ use aide::axum::ApiRouter;
 use axum::Extension;
 use centaurus::db::init::Connection;
 
-use crate::{nodes::state::Wings, utils::Updater};
+use crate::{cluster::Cluster, config::Config, utils::Updater, webhooks::Webhooks};
+
+pub use auth::{NodeAuth, SignedJson};
+pub use location::node_scope;
+pub use state::Wings;
 
 mod auth;
 mod connection;
+mod diagnose;
+mod enrollment;
+mod history;
+mod location;
 mod management;
+mod metrics;
+mod placement;
+mod reverse;
 mod state;
+mod tls;
 
 pub fn router() -> ApiRouter {
   management::router()
+    .merge(history::router())
+    .merge(location::router())
+    .merge(placement::router())
+    .merge(enrollment::router())
+    .merge(diagnose::router())
+}
+
+/// Endpoints wings uses to enroll itself and to connect to the panel.
+pub fn wings_router() -> ApiRouter {
+  enrollment::wings_router().merge(reverse::router())
 }
 
-pub async fn state(router: ApiRouter, db: &Connection, updater: Updater) -> ApiRouter {
-  router.layer(Extension(
-    Wings::new(db, updater)
-      .await
-      .expect("Failed to create Wings state"),
-  ))
+/// Also returns the wings connections for background tasks outside of requests.
+pub async fn state(
+  router: ApiRouter,
+  config: &Config,
+  db: &Connection,
+  cluster: &Cluster,
+  updater: Updater,
+  webhooks: Webhooks,
+) -> (ApiRouter, Wings) {
+  metrics::init(&config.metrics.metrics_name);
+  history::spawn_rollup(db.clone(), cluster.clone());
+
+  let wings = Wings::new(db, cluster, updater, webhooks)
+    .await
+    .expect("Failed to create Wings state");
+
+  (router.layer(Extension(wings.clone())), wings)
 }
@@ -0,0 +1,468 @@
+use aide::axum::{ApiRouter, routing::post_with};
+use axum::Json;
+use centaurus::{bail, db::init::Connection, error::Result};
+use chrono::{TimeDelta, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use shared::msg::NodeStats;
+use uuid::Uuid;
+
+use crate::{
+  auth::jwt_auth::JwtAuth,
+  db::{DBTrait, location::NodeScope, node::Node, node_sample::Resolution},
+  nodes::{location::node_scope, state::Wings},
+  utils::NodeViewPerm,
+};
+
+/// Older stats do not describe the current usage of the node anymore.
+const STATS_MAX_AGE: TimeDelta = TimeDelta::minutes(5);
+
+pub fn router() -> ApiRouter {
+  ApiRouter::new().api_route(
+    "/placement",
+    post_with(dry_run_placement, |op| op.id("dryRunPlacement")),
+  )
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PlacementStrategy {
+  /// Fill up the most utilized node that still fits the server.
+  #[default]
+  BinPack,
+  /// Prefer the least utilized node.
+  Spread,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, JsonSchema)]
+pub struct Resources {
+  pub memory_mb: f64,
+  pub disk_mb: f64,
+  pub cpu: u32,
+}
+
+/// Restricts which nodes are considered at all, independent of capacity.
+#[derive(Deserialize, Clone, Debug, Default, JsonSchema)]
+pub struct PlacementFilter {
+  pub location: Option<Uuid>,
+  /// Nodes must carry all of these tags.
+  #[serde(default)]
+  pub tags: Vec<String>,
+}
+
+pub struct Candidate {
+  pub node: Node,
+  pub tags: Vec<String>,
+  pub connected: bool,
+  pub allocated: Resources,
+  /// Latest stats wings reported, `None` if there are no recent ones.
+  pub stats: Option<NodeStats>,
+}
+
+#[derive(Serialize, Debug, JsonSchema)]
+pub struct NodeEvaluation {
+  pub id: Uuid,
+  pub name: String,
+  pub accepted: bool,
+  /// Why the node was rejected, empty for accepted nodes.
+  pub reasons: Vec<String>,
+  /// Utilization of the node after placing the server, between 0 and 1.
+  pub utilization: f64,
+}
+
+#[derive(Serialize, Debug, JsonSchema)]
+pub struct Placement {
+  pub node: Option<Uuid>,
+  pub evaluations: Vec<NodeEvaluation>,
+}
+
+pub fn place(
+  request: &Resources,
+  filter: &PlacementFilter,
+  strategy: PlacementStrategy,
+  candidates: Vec<Candidate>,
+) -> Placement {
+  let evaluations: Vec<_> = candidates
+    .into_iter()
+    .map(|candidate| evaluate(request, filter, candidate))
+    .collect();
+
+  let accepted = evaluations.iter().filter(|e| e.accepted);
+  let chosen = match strategy {
+    PlacementStrategy::BinPack => accepted.max_by(|a, b| a.utilization.total_cmp(&b.utilization)),
+    PlacementStrategy::Spread => accepted.min_by(|a, b| a.utilization.total_cmp(&b.utilization)),
+  };
+
+  Placement {
+    node: chosen.map(|e| e.id),
+    evaluations,
+  }
+}
+
+fn evaluate(request: &Resources, filter: &PlacementFilter, candidate: Candidate) -> NodeEvaluation {
+  let Candidate {
+    node,
+    tags,
+    connected,
+    allocated,
+    stats,
+  } = candidate;
+  let mut reasons = Vec::new();
+
+  if let Some(location) = filter.location
+    && node.location_id != Some(location)
+  {
+    reasons.push("Node is not in the requested location".to_string());
+  }
+  let missing: Vec<_> = filter.tags.iter().filter(|t| !tags.contains(t)).collect();
+  if !missing.is_empty() {
+    reasons.push(format!(
+      "Node is missing tags: {}",
+      missing
+        .iter()
+        .map(|t| t.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+    ));
+  }
+
+  if node.maintenance {
+    match &node.maintenance_message {
+      Some(message) => reasons.push(format!("Node is in maintenance: {}", message)),
+      None => reasons.push("Node is in maintenance".to_string()),
+    }
+  }
+  if !connected {
+    reasons.push("Node is not connected".to_string());
+  }
+
+  let mut usage = Vec::new();
+  // limits are what the panel hands out, the stats what the node has left
+  let mut check =
+    |kind: &str, limit: Option<f64>, allocated: f64, requested: f64, live: Option<(f64, f64)>| {
+      let mut fractions = Vec::new();
+      if let Some(limit) = limit {
+        let available = limit - allocated;
+        if requested > available {
+          reasons.push(format!(
+            "Insufficient {}: requested {}, available {}",
+            kind,
+            requested,
+            available.max(0.0)
+          ));
+        }
+        if limit > 0.0 {
+          fractions.push((allocated + requested) / limit);
+        }
+      }
+      if let Some((used, total)) = live {
+        let free = total - used;
+        if requested > free {
+          reasons.push(format!(
+            "Insufficient free {} on the node: requested {}, free {}",
+            kind,
+            requested,
+            free.max(0.0)
+          ));
+        }
+        if total > 0.0 {
+          fractions.push((used + requested) / total);
+        }
+      }
+      if let Some(fraction) = fractions.into_iter().reduce(f64::max) {
+        usage.push(fraction.min(1.0));
+      }
+    };
+
+  check(
+    "memory (MB)",
+    node.memory_limit_mb,
+    allocated.memory_mb,
+    request.memory_mb,
+    stats.map(|stats| (stats.memory_used_mb, stats.memory_total_mb)),
+  );
+  check(
+    "disk (MB)",
+    node.disk_limit_mb,
+    allocated.disk_mb,
+    request.disk_mb,
+    stats.map(|stats| (stats.disk_used_mb, stats.disk_total_mb)),
+  );
+  // the load says nothing about how many cores are free, so it only counts
+  // towards the utilization
+  check(
+    "cpu",
+    node.cpu_limit.map(f64::from),
+    allocated.cpu as f64,
+    request.cpu as f64,
+    None,
+  );
+  if let Some(stats) = stats {
+    usage.push((stats.cpu_percent / 100.0).clamp(0.0, 1.0));
+  }
+
+  let utilization = if usage.is_empty() {
+    0.0
+  } else {
+    usage.iter().sum::<f64>() / usage.len() as f64
+  };
+
+  NodeEvaluation {
+    id: node.id,
+    name: node.name,
+    accepted: reasons.is_empty(),
+    reasons,
+    utilization,
+  }
+}
+
+pub async fn candidates(
+  db: &Connection,
+  wings: &Wings,
+  scope: &NodeScope,
+) -> Result<Vec<Candidate>> {
+  let nodes = db.node().list_nodes().await?;
+  let mut tags = db.node().all_tags().await?;
+  let fresh = (Utc::now() - STATS_MAX_AGE).naive_utc();
+  let mut candidates = Vec::new();
+
+  for node in nodes {
+    if !scope.allows(node.location_id) {
+      continue;
+    }
+
+    let stats = db
+      .node_sample()
+      .latest_sample(node.id, Resolution::Raw)
+      .await?
+      .filter(|sample| sample.timestamp >= fresh)
+      .map(|sample| sample.stats);
+
+    candidates.push(Candidate {
+      tags: tags.remove(&node.id).unwrap_or_default(),
+      connected: wings.is_connected(node.id).await,
+      // servers are not tracked by the panel yet, so nothing is allocated
+      allocated: Resources::default(),
+      stats,
+      node,
+    });
+  }
+
+  Ok(candidates)
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct PlacementRequest {
+  #[serde(flatten)]
+  resources: Resources,
+  #[serde(flatten)]
+  filter: PlacementFilter,
+  #[serde(default)]
+  strategy: PlacementStrategy,
+}
+
+async fn dry_run_placement(
+  auth: JwtAuth<NodeViewPerm>,
+  db: Connection,
+  wings: Wings,
+  Json(data): Json<PlacementRequest>,
+) -> Result<Json<Placement>> {
+  if data.resources.memory_mb < 0.0 || data.resources.disk_mb < 0.0 {
+    bail!(BAD_REQUEST, "Disk and Memory requests must be non-negative");
+  }
+
+  let scope = node_scope(&db, auth.user_id).await?;
+  let candidates = candidates(&db, &wings, &scope).await?;
+  Ok(Json(place(
+    &data.resources,
+    &data.filter,
+    data.strategy,
+    candidates,
+  )))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::db::node::NodeLifecycle;
+
+  fn node(name: &str, memory: Option<f64>, disk: Option<f64>, cpu: Option<i32>) -> Node {
+    Node {
+      id: Uuid::now_v7(),
+      name: name.to_string(),
+      address: "localhost".to_string(),
+      port: 8000,
+      secure: false,
+      disk_limit_mb: disk,
+      memory_limit_mb: memory,
+      cpu_limit: cpu,
+      token: String::new(),
+      maintenance: false,
+      maintenance_message: None,
+      location_id: None,
+      reverse_connect: false,
+      tls_ca: None,
+      tls_pin: None,
+      lifecycle: NodeLifecycle::Connected,
+      first_connected_at: None,
+      last_seen_at: None,
+    }
+  }
+
+  fn candidate(node: Node, allocated_memory: f64) -> Candidate {
+    Candidate {
+      node,
+      tags: Vec::new(),
+      connected: true,
+      allocated: Resources {
+        memory_mb: allocated_memory,
+        ..Default::default()
+      },
+      stats: None,
+    }
+  }
+
+  fn stats(memory_used_mb: f64, memory_total_mb: f64) -> NodeStats {
+    NodeStats {
+      cpu_percent: 0.0,
+      memory_used_mb,
+      memory_total_mb,
+      disk_used_mb: 0.0,
+      disk_total_mb: 0.0,
+    }
+  }
+
+  fn request(memory_mb: f64) -> Resources {
+    Resources {
+      memory_mb,
+      disk_mb: 0.0,
+      cpu: 0,
+    }
+  }
+
+  #[test]
+  fn strategies_pick_most_and_least_utilized_node() {
+    let busy = node("busy", Some(4096.0), None, None);
+    let idle = node("idle", Some(4096.0), None, None);
+    let (busy_id, idle_id) = (busy.id, idle.id);
+
+    let placement = place(
+      &request(1024.0),
+      &PlacementFilter::default(),
+      PlacementStrategy::BinPack,
+      vec![
+        candidate(busy.clone(), 2048.0),
+        candidate(idle.clone(), 0.0),
+      ],
+    );
+    assert_eq!(placement.node, Some(busy_id));
+
+    let placement = place(
+      &request(1024.0),
+      &PlacementFilter::default(),
+      PlacementStrategy::Spread,
+      vec![candidate(busy, 2048.0), candidate(idle, 0.0)],
+    );
+    assert_eq!(placement.node, Some(idle_id));
+  }
+
+  #[test]
+  fn rejected_nodes_explain_why() {
+    let mut maintenance = node("maintenance", None, None, None);
+    maintenance.maintenance = true;
+    maintenance.maintenance_message = Some("Kernel upgrade".to_string());
+    let full = node("full", Some(1024.0), None, Some(2));
+    let mut offline = candidate(node("offline", None, None, None), 0.0);
+    offline.connected = false;
+
+    let placement = place(
+      &Resources {
+        memory_mb: 2048.0,
+        disk_mb: 0.0,
+        cpu: 4,
+      },
+      &PlacementFilter::default(),
+      PlacementStrategy::BinPack,
+      vec![candidate(maintenance, 0.0), candidate(full, 0.0), offline],
+    );
+
+    assert_eq!(placement.node, None);
+    assert_eq!(
+      placement.evaluations[0].reasons,
+      vec!["Node is in maintenance: Kernel upgrade"]
+    );
+    assert_eq!(placement.evaluations[1].reasons.len(), 2);
+    assert_eq!(
+      placement.evaluations[2].reasons,
+      vec!["Node is not connected"]
+    );
+  }
+
+  #[test]
+  fn filter_by_location_and_tags() {
+    let location = Uuid::now_v7();
+    let mut tagged = candidate(node("tagged", None, None, None), 0.0);
+    tagged.node.location_id = Some(location);
+    tagged.tags = vec!["ssd".to_string(), "eu".to_string()];
+    let tagged_id = tagged.node.id;
+    let untagged = candidate(node("untagged", None, None, None), 0.0);
+
+    let placement = place(
+      &request(0.0),
+      &PlacementFilter {
+        location: Some(location),
+        tags: vec!["ssd".to_string()],
+      },
+      PlacementStrategy::Spread,
+      vec![tagged, untagged],
+    );
+
+    assert_eq!(placement.node, Some(tagged_id));
+    assert_eq!(
+      placement.evaluations[1].reasons,
+      vec![
+        "Node is not in the requested location",
+        "Node is missing tags: ssd"
+      ]
+    );
+  }
+
+  #[test]
+  fn live_stats_reject_and_rank_nodes() {
+    let full = node("full", Some(8192.0), None, None);
+    let busy = node("busy", None, None, None);
+    let idle = node("idle", None, None, None);
+    let (busy_id, idle_id) = (busy.id, idle.id);
+    let candidates = || {
+      [(&full, 3584.0), (&busy, 2048.0), (&idle, 512.0)]
+        .into_iter()
+        .map(|(node, used)| Candidate {
+          stats: Some(stats(used, 4096.0)),
+          ..candidate(node.clone(), 0.0)
+        })
+        .collect::<Vec<_>>()
+    };
+
+    let placement = place(
+      &request(1024.0),
+      &PlacementFilter::default(),
+      PlacementStrategy::BinPack,
+      candidates(),
+    );
+    assert_eq!(placement.node, Some(busy_id));
+    assert_eq!(
+      placement.evaluations[0].reasons,
+      vec!["Insufficient free memory (MB) on the node: requested 1024, free 512"]
+    );
+    // memory ends up at 75%, the idle cpu pulls the average down
+    assert_eq!(placement.evaluations[1].utilization, 0.375);
+
+    let placement = place(
+      &request(1024.0),
+      &PlacementFilter::default(),
+      PlacementStrategy::Spread,
+      candidates(),
+    );
+    assert_eq!(placement.node, Some(idle_id));
+  }
+}
@@ -0,0 +1,90 @@
+use std::future::ready;
+
+use aide::axum::ApiRouter;
+use axum::{
+  extract::{
+    WebSocketUpgrade,
+    ws::{self, WebSocket},
+  },
+  response::Response,
+  routing::any,
+};
+use centaurus::{bail, error::Result};
+use futures_util::{SinkExt, StreamExt};
+use http::HeaderMap;
+use tokio_tungstenite::tungstenite;
+use tracing::{info, warn};
+
+use crate::nodes::{
+  NodeAuth,
+  connection::{WsReceiver, WsSender},
+  state::Wings,
+};
+
+pub fn router() -> ApiRouter {
+  // internal endpoint for wings, so it is left out of the api docs
+  ApiRouter::new().route("/connect", any(connect))
+}
+
+/// Websocket opened by wings for nodes in reverse connect mode. It replaces
+/// the websocket the panel would otherwise open to the node.
+async fn connect(
+  auth: NodeAuth,
+  wings: Wings,
+  ws: WebSocketUpgrade,
+) -> Result<(HeaderMap, Response)> {
+  if !auth.node.reverse_connect {
+    bail!(CONFLICT, "Node does not use reverse connections");
+  }
+  let headers = auth.response_headers()?;
+  let uuid = auth.node.id;
+
+  info!("Wings of node {} opened a websocket to the panel", uuid);
+
+  Ok((
+    headers,
+    ws.on_upgrade(move |socket| async move {
+      let (sender, receiver) = split(socket);
+      if let Err(err) = wings.accept(uuid, sender, receiver).await {
+        warn!("Failed to accept websocket of node {}: {:?}", uuid, err);
+      }
+    }),
+  ))
+}
+
+/// Adapts the server side websocket to the message types of the websocket the
+/// panel opens itself, so connections behave the same in both directions.
+fn split(socket: WebSocket) -> (WsSender, WsReceiver) {
+  let (sender, receiver) = socket.split();
+
+  let sender = sender
+    .sink_map_err(|err| tungstenite::Error::Io(std::io::Error::other(err)))
+    .with(|msg| ready(Ok(to_axum(msg))));
+  let receiver = receiver.map(|msg| {
+    msg
+      .map(from_axum)
+      .map_err(|err| tungstenite::Error::Io(std::io::Error::other(err)))
+  });
+
+  (Box::pin(sender), Box::pin(receiver))
+}
+
+fn to_axum(msg: tungstenite::Message) -> ws::Message {
+  match msg {
+    tungstenite::Message::Binary(data) => ws::Message::Binary(data),
+    tungstenite::Message::Text(text) => ws::Message::Text(text.as_str().into()),
+    tungstenite::Message::Ping(data) => ws::Message::Ping(data),
+    tungstenite::Message::Pong(data) => ws::Message::Pong(data),
+    tungstenite::Message::Close(_) | tungstenite::Message::Frame(_) => ws::Message::Close(None),
+  }
+}
+
+fn from_axum(msg: ws::Message) -> tungstenite::Message {
+  match msg {
+    ws::Message::Binary(data) => tungstenite::Message::Binary(data),
+    ws::Message::Text(text) => tungstenite::Message::Text(text.as_str().into()),
+    ws::Message::Ping(data) => tungstenite::Message::Ping(data),
+    ws::Message::Pong(data) => tungstenite::Message::Pong(data),
+    ws::Message::Close(_) => tungstenite::Message::Close(None),
+  }
+}
@@ -1,75 +1,327 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use aide::OperationIo;
 use axum::{Extension, extract::FromRequestParts};
-use centaurus::{db::init::Connection, error::Result};
+use centaurus::{
+  bail,
+  db::init::Connection,
+  error::{ErrorReportStatusExt, Result},
+};
+use chrono::{DateTime, Utc};
 use dashmap::DashMap;
-use tokio::sync::Mutex;
+use http::StatusCode;
+use tokio::{
+  spawn,
+  sync::{
+    Mutex,
+    broadcast::{self, error::RecvError},
+  },
+  time::interval,
+};
+use tracing::{info, warn};
 use uuid::Uuid;
 
-use crate::{db::DBTrait, nodes::connection::WingsConnection, utils::Updater};
+use crate::{
+  cluster::{Cluster, Event, WingsCall, node_lease},
+  db::{
+    DBTrait,
+    node::{Node, NodeLifecycle},
+  },
+  nodes::connection::{self, WingsConnection, WsReceiver, WsSender},
+  utils::Updater,
+  webhooks::Webhooks,
+};
 
+type Conn = Arc<Mutex<WingsConnection>>;
+
+/// Connections to the nodes this instance holds the lease of. Calls for nodes
+/// owned by another instance are forwarded to it.
 #[derive(Clone, FromRequestParts, OperationIo)]
 #[from_request(via(Extension))]
 pub struct Wings {
-  wings: Arc<DashMap<Uuid, Arc<Mutex<WingsConnection>>>>,
+  wings: Arc<DashMap<Uuid, Conn>>,
+  /// Held while connections are added or removed, so a node is not connected
+  /// twice by a request and the lease renewal at the same time.
+  claims: Arc<Mutex<()>>,
+  db: Connection,
+  cluster: Cluster,
   updater: Updater,
+  webhooks: Webhooks,
 }
 
 impl Wings {
-  pub async fn new(db: &Connection, updater: Updater) -> Result<Self> {
-    let nodes = db.node().list_nodes().await?;
-    let wings = Arc::new(DashMap::new());
-
-    for node in nodes {
-      let conn = WingsConnection::new(
-        node.id,
-        &node.address,
-        node.port,
-        node.secure,
-        node.token.clone(),
-        updater.clone(),
-      )
-      .await?;
-
-      wings.insert(node.id, conn);
-    }
-
-    Ok(Self { wings, updater })
-  }
-
-  pub async fn connect(
-    &self,
-    uuid: Uuid,
-    addr: &str,
-    port: i16,
-    secure: bool,
-    token: &str,
-  ) -> Result<()> {
-    let conn = WingsConnection::new(
-      uuid,
-      addr,
-      port,
-      secure,
-      token.to_string(),
-      self.updater.clone(),
-    )
-    .await?;
-    self.wings.insert(uuid, conn);
+  pub async fn new(
+    db: &Connection,
+    cluster: &Cluster,
+    updater: Updater,
+    webhooks: Webhooks,
+  ) -> Result<Self> {
+    let wings = Self {
+      wings: Arc::new(DashMap::new()),
+      claims: Arc::new(Mutex::new(())),
+      db: db.clone(),
+      cluster: cluster.clone(),
+      updater,
+      webhooks,
+    };
+
+    wings.claim().await?;
+    spawn(wings.clone().keep_claiming());
+    if let Some(bus) = cluster.bus() {
+      spawn(wings.clone().serve(bus.subscribe()));
+    }
+
+    Ok(wings)
+  }
+
+  /// Holds the lease of a node before it is created, so this instance is the
+  /// one to connect it.
+  pub async fn reserve(&self, uuid: Uuid) -> Result<()> {
+    self.cluster.take(&self.db, &node_lease(uuid)).await?;
+    Ok(())
+  }
+
+  pub async fn connect(&self, node: &Node) -> Result<()> {
+    let _claims = self.claims.lock().await;
+    if self.cluster.acquire(&self.db, &node_lease(node.id)).await? {
+      self.connect_local(node).await?;
+    } else {
+      self.forward(node.id, WingsCall::Reconnect).await;
+    }
+    Ok(())
+  }
+
+  /// Replaces the connection after the node changed. The node must already be
+  /// stored, another owner reloads it from the database.
+  pub async fn reconnect(&self, node: &Node) -> Result<()> {
+    let _claims = self.claims.lock().await;
+    if self.wings.contains_key(&node.id)
+      || self.cluster.acquire(&self.db, &node_lease(node.id)).await?
+    {
+      self.disconnect_local(node.id).await;
+      self.connect_local(node).await?;
+    } else {
+      self.forward(node.id, WingsCall::Reconnect).await;
+    }
     Ok(())
   }
 
   pub async fn disconnect(&self, uuid: Uuid) -> Result<()> {
-    if let Some((_, conn)) = self.wings.remove(&uuid) {
-      conn.lock().await.disconnect();
+    let _claims = self.claims.lock().await;
+    if self.wings.contains_key(&uuid) {
+      self.disconnect_local(uuid).await;
+      self.cluster.release(&self.db, &node_lease(uuid)).await?;
+    } else {
+      self.forward(uuid, WingsCall::Disconnect).await;
     }
     Ok(())
   }
 
+  /// Nodes pending setup are polled slowly, so the node is dialed again right
+  /// away once wings enrolled instead of at the next slow attempt.
+  pub async fn enrolled(&self, node: Node) -> Result<()> {
+    if node.reverse_connect || self.is_connected(node.id).await {
+      return Ok(());
+    }
+
+    self.reconnect(&node).await
+  }
+
+  /// Hands a websocket wings opened to the panel to the connection of the node.
+  /// Wings may reach any instance, the node moves to the one it reached.
+  pub async fn accept(&self, uuid: Uuid, sender: WsSender, receiver: WsReceiver) -> Result<()> {
+    let conn = match self.wings.get(&uuid).map(|conn| conn.clone()) {
+      Some(conn) => conn,
+      None => self.take_over(uuid).await?,
+    };
+    let Some(inbound) = conn.lock().await.inbound() else {
+      bail!(CONFLICT, "Node {} does not use reverse connections", uuid);
+    };
+
+    inbound.send((sender, receiver)).await.ok().status_context(
+      StatusCode::INTERNAL_SERVER_ERROR,
+      &format!("Failed to hand over websocket for node {}", uuid),
+    )
+  }
+
+  /// Pushes the configuration of the node again after it changed. Nodes that
+  /// are not connected get it once they connect.
+  pub async fn sync(&self, uuid: Uuid) -> Result<()> {
+    let Some(conn) = self.wings.get(&uuid).map(|conn| conn.clone()) else {
+      self.forward(uuid, WingsCall::Sync).await;
+      return Ok(());
+    };
+    if !conn.lock().await.is_connected() {
+      return Ok(());
+    }
+
+    connection::sync(&conn, uuid, &self.db).await
+  }
+
+  pub async fn set_maintenance(&self, uuid: Uuid, maintenance: bool) {
+    match self.wings.get(&uuid).map(|conn| conn.clone()) {
+      Some(conn) => conn.lock().await.set_maintenance(maintenance),
+      None => {
+        self
+          .forward(
+            uuid,
+            WingsCall::Maintenance {
+              enabled: maintenance,
+            },
+          )
+          .await
+      }
+    }
+  }
+
+  /// Nodes connected to another instance are looked up in the database.
   pub async fn is_connected(&self, uuid: Uuid) -> bool {
-    if let Some(conn) = self.wings.get(&uuid) {
+    if let Some(conn) = self.wings.get(&uuid).map(|conn| conn.clone()) {
       return conn.lock().await.is_connected();
     }
-    false
+    match self.db.node().find_by_id(uuid).await {
+      Ok(node) => Node::from(node).lifecycle == NodeLifecycle::Connected,
+      Err(_) => false,
+    }
+  }
+
+  /// Nodes connected to another instance are looked up in the database, where
+  /// the last time they were seen is the closest to when they disconnected.
+  pub async fn disconnected_since(&self, uuid: Uuid) -> Option<DateTime<Utc>> {
+    if let Some(conn) = self.wings.get(&uuid).map(|conn| conn.clone()) {
+      return conn.lock().await.disconnected_since();
+    }
+    let node = Node::from(self.db.node().find_by_id(uuid).await.ok()?);
+    if node.lifecycle == NodeLifecycle::Connected {
+      return None;
+    }
+    node.last_seen_at.map(|at| at.and_utc())
+  }
+
+  /// Renews the leases of the nodes connected here and takes over the ones
+  /// nobody holds, like the nodes of an instance that went away.
+  async fn claim(&self) -> Result<()> {
+    let _claims = self.claims.lock().await;
+    let nodes = self.db.node().list_nodes().await?;
+
+    for node in &nodes {
+      let owned = self.cluster.acquire(&self.db, &node_lease(node.id)).await?;
+      let local = self.wings.contains_key(&node.id);
+      if owned && !local {
+        self.db.node().reset_connected(node.id).await?;
+        if let Err(err) = self.connect_local(node).await {
+          warn!("Failed to connect to node {}: {:?}", node.id, err);
+        }
+      } else if !owned && local {
+        info!("Node {} was taken over by another instance", node.id);
+        self.disconnect_local(node.id).await;
+      }
+    }
+
+    // deleted by another instance
+    let ids = nodes.iter().map(|node| node.id).collect::<HashSet<_>>();
+    let deleted = self
+      .wings
+      .iter()
+      .map(|conn| *conn.key())
+      .filter(|uuid| !ids.contains(uuid))
+      .collect::<Vec<_>>();
+    for uuid in deleted {
+      self.disconnect_local(uuid).await;
+      self.cluster.release(&self.db, &node_lease(uuid)).await?;
+    }
+
+    Ok(())
+  }
+
+  async fn keep_claiming(self) {
+    let mut interval = interval(self.cluster.renew_interval());
+    // the first round already ran
+    interval.tick().await;
+    loop {
+      interval.tick().await;
+      if let Err(err) = self.claim().await {
+        warn!("Failed to renew node leases: {:?}", err);
+      }
+    }
+  }
+
+  /// Wings opened its websocket to this instance, so the node moves here from
+  /// the instance that owned it.
+  async fn take_over(&self, uuid: Uuid) -> Result<Conn> {
+    let _claims = self.claims.lock().await;
+    let node = Node::from(self.db.node().find_by_id(uuid).await?);
+    self.cluster.take(&self.db, &node_lease(uuid)).await?;
+    info!("Took over node {} from another instance", uuid);
+    self.connect_local(&node).await
+  }
+
+  /// Handles the calls other instances forwarded for the nodes connected here.
+  async fn serve(self, mut events: broadcast::Receiver<Event>) {
+    loop {
+      let (uuid, call) = match events.recv().await {
+        Ok(Event::Wings { node, call }) => (node, call),
+        Ok(_) => continue,
+        Err(RecvError::Lagged(missed)) => {
+          warn!("Missed {} wings calls of other instances", missed);
+          continue;
+        }
+        Err(RecvError::Closed) => return,
+      };
+      if !self.wings.contains_key(&uuid) {
+        continue;
+      }
+
+      let res = match call {
+        WingsCall::Reconnect => match self.db.node().find_by_id(uuid).await {
+          Ok(node) => self.reconnect(&node.into()).await,
+          Err(err) => Err(err),
+        },
+        WingsCall::Disconnect => self.disconnect(uuid).await,
+        WingsCall::Sync => self.sync(uuid).await,
+        WingsCall::Maintenance { enabled } => {
+          self.set_maintenance(uuid, enabled).await;
+          Ok(())
+        }
+      };
+      if let Err(err) = res {
+        warn!(
+          "Failed to handle {:?} for node {} from another instance: {:?}",
+          call, uuid, err
+        );
+      }
+    }
+  }
+
+  async fn forward(&self, uuid: Uuid, call: WingsCall) {
+    match self.cluster.bus() {
+      Some(bus) => bus.publish(Event::Wings { node: uuid, call }).await,
+      None => warn!(
+        "Node {} is connected to another instance, {:?} can not be forwarded without a shared Postgres database",
+        uuid, call
+      ),
+    }
+  }
+
+  async fn connect_local(&self, node: &Node) -> Result<Conn> {
+    if let Some(conn) = self.wings.get(&node.id) {
+      return Ok(conn.clone());
+    }
+
+    let conn = WingsConnection::new(
+      node,
+      self.db.clone(),
+      self.updater.clone(),
+      self.webhooks.clone(),
+    )
+    .await?;
+    self.wings.insert(node.id, conn.clone());
+    Ok(conn)
+  }
+
+  async fn disconnect_local(&self, uuid: Uuid) {
+    if let Some((_, conn)) = self.wings.remove(&uuid) {
+      conn.lock().await.disconnect();
+    }
   }
 }
@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use centaurus::{
+  bail,
+  error::{ErrorReportStatusExt, Result},
+};
+use http::StatusCode;
+use rustls::{
+  ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme,
+  client::{
+    WebPkiServerVerifier,
+    danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+  },
+  crypto::{CryptoProvider, aws_lc_rs, verify_tls12_signature, verify_tls13_signature},
+  pki_types::{CertificateDer, ServerName, UnixTime, pem::PemObject},
+};
+use sha2::{Digest, Sha256};
+use webpki::EndEntityCert;
+
+use crate::db::node::TlsPin;
+
+/// Validates and normalizes the TLS options of a node. Fingerprints may be
+/// given with colons and in any case, they are stored as lowercase hex.
+pub fn normalize(
+  ca: Option<String>,
+  pin: Option<TlsPin>,
+) -> Result<(Option<String>, Option<TlsPin>)> {
+  let ca = ca
+    .map(|ca| ca.trim().to_string())
+    .filter(|ca| !ca.is_empty());
+  let pin = pin.map(|pin| {
+    let sha256 = pin
+      .sha256()
+      .chars()
+      .filter(|c| *c != ':' && !c.is_whitespace())
+      .collect::<String>()
+      .to_lowercase();
+    match pin {
+      TlsPin::Certificate { .. } => TlsPin::Certificate { sha256 },
+      TlsPin::Spki { .. } => TlsPin::Spki { sha256 },
+    }
+  });
+
+  if let Some(pin) = &pin
+    && !(pin.sha256().len() == 64 && pin.sha256().chars().all(|c| c.is_ascii_hexdigit()))
+  {
+    bail!(BAD_REQUEST, "Pinned fingerprint must be a SHA-256 hash");
+  }
+  client_config(ca.as_deref(), pin.as_ref())?;
+
+  Ok((ca, pin))
+}
+
+/// TLS config for connections to a node, `None` if the default web roots are
+/// used. A pinned fingerprint alone replaces the chain and hostname checks, so
+/// nodes can use self-signed certificates.
+pub fn client_config(ca: Option<&str>, pin: Option<&TlsPin>) -> Result<Option<Arc<ClientConfig>>> {
+  let provider = Arc::new(aws_lc_rs::default_provider());
+  let builder = || {
+    ClientConfig::builder_with_provider(provider.clone())
+      .with_safe_default_protocol_versions()
+      .status_context(StatusCode::INTERNAL_SERVER_ERROR, "Invalid TLS provider")
+  };
+
+  let config = match (ca, pin) {
+    (None, None) => return Ok(None),
+    (Some(ca), None) => builder()?
+      .with_root_certificates(roots(ca)?)
+      .with_no_client_auth(),
+    (ca, Some(pin)) => {
+      let chain = ca
+        .map(|ca| {
+          WebPkiServerVerifier::builder_with_provider(Arc::new(roots(ca)?), provider.clone())
+            .build()
+            .status_context(StatusCode::BAD_REQUEST, "Invalid CA certificate")
+        })
+        .transpose()?;
+      builder()?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(PinnedVerifier {
+          pin: pin.clone(),
+          chain,
+          provider: provider.clone(),
+        }))
+        .with_no_client_auth()
+    }
+  };
+
+  Ok(Some(Arc::new(config)))
+}
+
+/// The web roots tungstenite falls back to if [`client_config`] returns `None`.
+pub fn web_roots() -> Result<Arc<ClientConfig>> {
+  let roots = RootCertStore {
+    roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+  };
+  let config = ClientConfig::builder_with_provider(Arc::new(aws_lc_rs::default_provider()))
+    .with_safe_default_protocol_versions()
+    .status_context(StatusCode::INTERNAL_SERVER_ERROR, "Invalid TLS provider")?
+    .with_root_certificates(roots)
+    .with_no_client_auth();
+  Ok(Arc::new(config))
+}
+
+fn roots(ca: &str) -> Result<RootCertStore> {
+  let mut roots = RootCertStore::empty();
+  for cert in CertificateDer::pem_slice_iter(ca.as_bytes()) {
+    let cert = cert.status_context(StatusCode::BAD_REQUEST, "Invalid CA certificate")?;
+    roots
+      .add(cert)
+      .status_context(StatusCode::BAD_REQUEST, "Invalid CA certificate")?;
+  }
+  if roots.is_empty() {
+    bail!(BAD_REQUEST, "CA bundle does not contain a certificate");
+  }
+  Ok(roots)
+}
+
+/// Fingerprint of the certificate or its public key, depending on the pin.
+fn fingerprint(pin: &TlsPin, cert: &CertificateDer<'_>) -> Option<String> {
+  let der = match pin {
+    TlsPin::Certificate { .. } => cert.to_vec(),
+    TlsPin::Spki { .. } => EndEntityCert::try_from(cert)
+      .ok()?
+      .subject_public_key_info()
+      .to_vec(),
+  };
+  Some(hex::encode(Sha256::digest(der)))
+}
+
+#[derive(Debug)]
+struct PinnedVerifier {
+  pin: TlsPin,
+  /// Also verifies the chain against a custom CA if one is configured.
+  chain: Option<Arc<WebPkiServerVerifier>>,
+  provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for PinnedVerifier {
+  fn verify_server_cert(
+    &self,
+    end_entity: &CertificateDer<'_>,
+    intermediates: &[CertificateDer<'_>],
+    server_name: &ServerName<'_>,
+    ocsp_response: &[u8],
+    now: UnixTime,
+  ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+    if let Some(chain) = &self.chain {
+      chain.verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)?;
+    }
+
+    if fingerprint(&self.pin, end_entity).as_deref() != Some(self.pin.sha256()) {
+      return Err(rustls::Error::General(
+        "Certificate does not match the pinned fingerprint".into(),
+      ));
+    }
+    Ok(ServerCertVerified::assertion())
+  }
+
+  fn verify_tls12_signature(
+    &self,
+    message: &[u8],
+    cert: &CertificateDer<'_>,
+    dss: &DigitallySignedStruct,
+  ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+    verify_tls12_signature(
+      message,
+      cert,
+      dss,
+      &self.provider.signature_verification_algorithms,
+    )
+  }
+
+  fn verify_tls13_signature(
+    &self,
+    message: &[u8],
+    cert: &CertificateDer<'_>,
+    dss: &DigitallySignedStruct,
+  ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+    verify_tls13_signature(
+      message,
+      cert,
+      dss,
+      &self.provider.signature_verification_algorithms,
+    )
+  }
+
+  fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+    self
+      .provider
+      .signature_verification_algorithms
+      .supported_schemes()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rcgen::PublicKeyData;
+
+  use super::*;
+
+  fn self_signed() -> (CertificateDer<'static>, String, String) {
+    let generated = rcgen::generate_simple_self_signed(vec!["node.internal".to_string()]).unwrap();
+    let cert = generated.cert.der().clone();
+    let cert_sha256 = hex::encode(Sha256::digest(&cert));
+    let spki_sha256 = hex::encode(Sha256::digest(
+      generated.signing_key.subject_public_key_info(),
+    ));
+    (cert, cert_sha256, spki_sha256)
+  }
+
+  fn verify(pin: TlsPin, cert: &CertificateDer<'_>) -> bool {
+    let verifier = PinnedVerifier {
+      pin,
+      chain: None,
+      provider: Arc::new(aws_lc_rs::default_provider()),
+    };
+    verifier
+      .verify_server_cert(
+        cert,
+        &[],
+        &ServerName::try_from("other.host").unwrap(),
+        &[],
+        UnixTime::now(),
+      )
+      .is_ok()
+  }
+
+  #[test]
+  fn pinned_verifier_matches_certificate_and_spki() {
+    let (cert, cert_sha256, spki_sha256) = self_signed();
+
+    assert!(verify(
+      TlsPin::Certificate {
+        sha256: cert_sha256
+      },
+      &cert
+    ));
+    assert!(verify(
+      TlsPin::Spki {
+        sha256: spki_sha256.clone()
+      },
+      &cert
+    ));
+    assert!(!verify(
+      TlsPin::Certificate {
+        sha256: spki_sha256
+      },
+      &cert
+    ));
+  }
+
+  #[test]
+  fn normalize_accepts_colon_separated_fingerprints() {
+    let (_, cert_sha256, _) = self_signed();
+    let colons = cert_sha256
+      .to_uppercase()
+      .as_bytes()
+      .chunks(2)
+      .map(|pair| std::str::from_utf8(pair).unwrap())
+      .collect::<Vec<_>>()
+      .join(":");
+
+    let (ca, pin) = normalize(
+      Some("  ".to_string()),
+      Some(TlsPin::Certificate { sha256: colons }),
+    )
+    .unwrap();
+    assert_eq!(ca, None);
+    assert_eq!(
+      pin,
+      Some(TlsPin::Certificate {
+        sha256: cert_sha256
+      })
+    );
+
+    assert!(
+      normalize(
+        None,
+        Some(TlsPin::Spki {
+          sha256: "abcd".to_string()
+        })
+      )
+      .is_err()
+    );
+  }
+
+  #[test]
+  fn normalize_rejects_invalid_ca() {
+    let generated = rcgen::generate_simple_self_signed(vec!["ca.internal".to_string()]).unwrap();
+
+    assert!(normalize(Some(generated.cert.pem()), None).is_ok());
+    assert!(normalize(Some("not a certificate".to_string()), None).is_err());
+  }
+}
@@ -1,14 +1,17 @@
 use aide::axum::ApiRouter;
-use aide::axum::routing::get_with;
+use aide::axum::routing::{get_with, post_with};
 use axum::Json;
+use centaurus::backend::auth::permission::{SettingsEdit, SettingsView};
 use centaurus::backend::{auth::jwt_auth::JwtAuth, endpoints::settings};
+use centaurus::db::{init::Connection, tables::ConnectionExt};
 use centaurus::error::Result;
+use centaurus::{Settings, bail};
 use schemars::JsonSchema;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::config::Config;
-use crate::utils::UpdateMessage;
+use crate::utils::{UpdateMessage, Updater};
 
 pub fn router() -> ApiRouter {
   ApiRouter::new()
@@ -16,6 +19,14 @@ pub fn router() -> ApiRouter {
       "/general",
       get_with(general_settings, |op| op.id("getGeneralSettings")),
     )
+    .api_route(
+      "/retention",
+      get_with(retention_settings, |op| op.id("getRetentionSettings")),
+    )
+    .api_route(
+      "/retention",
+      post_with(save_retention_settings, |op| op.id("saveRetentionSettings")),
+    )
     .merge(settings::router::<UpdateMessage>())
 }
 
@@ -29,3 +40,52 @@ async fn general_settings(_auth: JwtAuth, config: Config) -> Result<Json<General
     site_url: config.site.site_url,
   }))
 }
+
+/// How long node samples are kept at each resolution. Older samples are only
+/// available as averages of the next coarser resolution.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema, Settings)]
+#[settings(id = 10)]
+pub struct RetentionSettings {
+  pub raw_minutes: u32,
+  pub minute_hours: u32,
+  pub quarter_hour_days: u32,
+}
+
+impl Default for RetentionSettings {
+  fn default() -> Self {
+    Self {
+      raw_minutes: 60,
+      minute_hours: 24,
+      quarter_hour_days: 30,
+    }
+  }
+}
+
+async fn retention_settings(
+  _auth: JwtAuth<SettingsView>,
+  db: Connection,
+) -> Result<Json<RetentionSettings>> {
+  Ok(Json(db.settings().get_settings().await?))
+}
+
+async fn save_retention_settings(
+  _auth: JwtAuth<SettingsEdit>,
+  db: Connection,
+  updater: Updater,
+  Json(settings): Json<RetentionSettings>,
+) -> Result<()> {
+  let raw = settings.raw_minutes as u64 * 60;
+  let minute = settings.minute_hours as u64 * 60 * 60;
+  let quarter_hour = settings.quarter_hour_days as u64 * 24 * 60 * 60;
+  if raw == 0 || raw > minute || minute > quarter_hour {
+    bail!(
+      BAD_REQUEST,
+      "Retention must be positive and not shorter than that of finer resolutions"
+    );
+  }
+
+  db.settings().save_settings(&settings).await?;
+  updater.broadcast(UpdateMessage::Settings).await;
+
+  Ok(())
+}
@@ -0,0 +1,159 @@
+use base64::{
+  Engine,
+  prelude::{BASE64_STANDARD, BASE64_STANDARD_NO_PAD},
+};
+use centaurus::{bail, error::Result};
+use sha2::{Digest, Sha256};
+
+const ED25519: &str = "ssh-ed25519";
+const RSA: &str = "ssh-rsa";
+const ECDSA: [(&str, &str); 3] = [
+  ("ecdsa-sha2-nistp256", "nistp256"),
+  ("ecdsa-sha2-nistp384", "nistp384"),
+  ("ecdsa-sha2-nistp521", "nistp521"),
+];
+/// Security key variants are accepted as is, their blob is not inspected.
+const SECURITY_KEYS: [&str; 2] = [
+  "sk-ssh-ed25519@openssh.com",
+  "sk-ecdsa-sha2-nistp256@openssh.com",
+];
+const MIN_RSA_BITS: usize = 2048;
+
+/// A validated public key in the OpenSSH `authorized_keys` format.
+#[derive(Debug)]
+pub struct PublicKey {
+  pub key_type: String,
+  /// `<type> <base64>` without the comment.
+  pub public_key: String,
+  pub comment: Option<String>,
+  /// `SHA256:<base64>` as printed by `ssh-keygen -l`.
+  pub fingerprint: String,
+}
+
+pub fn parse(raw: &str) -> Result<PublicKey> {
+  let mut parts = raw.split_whitespace();
+  let (Some(key_type), Some(encoded)) = (parts.next(), parts.next()) else {
+    bail!(
+      BAD_REQUEST,
+      "SSH key must be in the format '<type> <key> [comment]'"
+    );
+  };
+  let comment = parts.collect::<Vec<_>>().join(" ");
+
+  let Ok(blob) = BASE64_STANDARD.decode(encoded) else {
+    bail!(BAD_REQUEST, "SSH key is not valid base64");
+  };
+  validate_blob(key_type, &blob)?;
+
+  Ok(PublicKey {
+    key_type: key_type.to_string(),
+    public_key: format!("{} {}", key_type, encoded),
+    comment: (!comment.is_empty()).then_some(comment),
+    fingerprint: fingerprint(&blob),
+  })
+}
+
+pub fn fingerprint(blob: &[u8]) -> String {
+  format!(
+    "SHA256:{}",
+    BASE64_STANDARD_NO_PAD.encode(Sha256::digest(blob))
+  )
+}
+
+fn validate_blob(key_type: &str, blob: &[u8]) -> Result<()> {
+  let mut reader = Reader(blob);
+  if reader.string()? != key_type.as_bytes() {
+    bail!(BAD_REQUEST, "SSH key type does not match the encoded key");
+  }
+
+  if key_type == ED25519 {
+    if reader.string()?.len() != 32 {
+      bail!(BAD_REQUEST, "Invalid ed25519 key");
+    }
+  } else if key_type == RSA {
+    let _exponent = reader.string()?;
+    let modulus = reader.string()?;
+    let bits = modulus
+      .iter()
+      .position(|b| *b != 0)
+      .map(|i| (modulus.len() - i) * 8 - modulus[i].leading_zeros() as usize)
+      .unwrap_or(0);
+    if bits < MIN_RSA_BITS {
+      bail!(
+        BAD_REQUEST,
+        "RSA keys must be at least {} bits long",
+        MIN_RSA_BITS
+      );
+    }
+  } else if let Some((_, curve)) = ECDSA.iter().find(|(name, _)| *name == key_type) {
+    if reader.string()? != curve.as_bytes() || reader.string()?.is_empty() {
+      bail!(BAD_REQUEST, "Invalid ecdsa key");
+    }
+  } else if SECURITY_KEYS.contains(&key_type) {
+    return Ok(());
+  } else {
+    bail!(BAD_REQUEST, "Unsupported SSH key type {}", key_type);
+  }
+
+  if !reader.0.is_empty() {
+    bail!(BAD_REQUEST, "SSH key contains trailing data");
+  }
+
+  Ok(())
+}
+
+/// Reads the length prefixed strings of the SSH wire format.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+  fn string(&mut self) -> Result<&'a [u8]> {
+    let Some((len, rest)) = self.0.split_first_chunk::<4>() else {
+      bail!(BAD_REQUEST, "SSH key is truncated");
+    };
+    let len = u32::from_be_bytes(*len) as usize;
+    if rest.len() < len {
+      bail!(BAD_REQUEST, "SSH key is truncated");
+    }
+
+    let (value, rest) = rest.split_at(len);
+    self.0 = rest;
+    Ok(value)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const ED25519_KEY: &str =
+    "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIC1VNbd43EwaRSgH4ti0Y8sgR4H4qwEnr5WW7nx4mPyB me@host";
+  const ECDSA_KEY: &str = "ecdsa-sha2-nistp256 AAAAE2VjZHNhLXNoYTItbmlzdHAyNTYAAAAIbmlzdHAyNTYAAABBBNxlHSgGumasfiOLCw506fusXqn+jClto/LP1pSmi5jlHuJ3F3cNMGuEGl8Hcrnv32uIGHK2qI3asYj4u8s8bxk=";
+  const RSA_1024_KEY: &str = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAAAgQDKHt8VFqD7TZ+ZfGW6ZZSYi3h0gs9dTrHtcOBTQQ3OGoPM1/g+9tcwcu7rCCTjMCiwi8fJhq2RyYNDEOwnKPlaSUwCP6uFu2Gx22k4HQ0NLYcv2ykY6PSjxSGcseBPZghLdBw6Fb8Uw8PzQdLQ41xNgZdU/1dkri3el50eOLGi/Q==";
+
+  #[test]
+  fn fingerprints_match_ssh_keygen() {
+    let key = parse(ED25519_KEY).unwrap();
+    assert_eq!(key.key_type, "ssh-ed25519");
+    assert_eq!(key.comment.as_deref(), Some("me@host"));
+    assert_eq!(
+      key.fingerprint,
+      "SHA256:CpL150WkK1ZbuAIzyrz6YxkYhBsIe7jONkBKnct5rhM"
+    );
+
+    let key = parse(ECDSA_KEY).unwrap();
+    assert_eq!(key.comment, None);
+    assert_eq!(
+      key.fingerprint,
+      "SHA256:Ki3TT8Bk/svrHEo/r64+8fbwufMyemKSo5bW38jdM7w"
+    );
+  }
+
+  #[test]
+  fn rejects_invalid_keys() {
+    assert!(parse(RSA_1024_KEY).is_err());
+    assert!(parse("ssh-dss AAAAB3NzaC1kc3M=").is_err());
+    assert!(parse("ssh-ed25519").is_err());
+    // type prefix and encoded key disagree
+    assert!(parse(&ED25519_KEY.replace("ssh-ed25519 ", "ssh-rsa ")).is_err());
+  }
+}
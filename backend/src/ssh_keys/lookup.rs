@@ -0,0 +1,54 @@
+use aide::axum::ApiRouter;
+use axum::{Json, routing::post};
+use centaurus::{
+  bail,
+  db::{init::Connection, tables::ConnectionExt},
+  error::Result,
+};
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::{db::DBTrait, nodes::SignedJson};
+
+pub fn router() -> ApiRouter {
+  // internal endpoint for wings, so it is left out of the api docs
+  ApiRouter::new().route("/ssh_keys/lookup", post(lookup_ssh_key))
+}
+
+#[derive(Deserialize)]
+struct LookupSshKey {
+  fingerprint: String,
+}
+
+#[derive(Serialize)]
+struct SshKeyOwner {
+  key: Uuid,
+  user: Uuid,
+  user_name: String,
+}
+
+/// The signature covers the body, so the fingerprint can not be swapped.
+async fn lookup_ssh_key(
+  db: Connection,
+  SignedJson { auth, data }: SignedJson<LookupSshKey>,
+) -> Result<(HeaderMap, Json<SshKeyOwner>)> {
+  let Some(key) = db.ssh_key().find_by_fingerprint(&data.fingerprint).await? else {
+    bail!(NOT_FOUND, "SSH key not found");
+  };
+  let user = db.user().get_user_by_id(key.user_id).await?;
+  debug!(
+    "Node {} looked up ssh key {} of user {}",
+    auth.node.id, key.id, user.id
+  );
+
+  Ok((
+    auth.response_headers()?,
+    Json(SshKeyOwner {
+      key: key.id,
+      user: user.id,
+      user_name: user.name,
+    }),
+  ))
+}
@@ -0,0 +1,135 @@
+use aide::axum::{
+  ApiRouter,
+  routing::{delete_with, get_with, post_with},
+};
+use axum::Json;
+use centaurus::{bail, db::init::Connection, error::Result};
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+  auth::jwt_auth::JwtAuth,
+  db::{DBTrait, ssh_key::SshKey},
+  ssh_keys::key,
+};
+
+pub fn router() -> ApiRouter {
+  ApiRouter::new()
+    .api_route("/ssh_keys", post_with(add_ssh_key, |op| op.id("addSshKey")))
+    .api_route(
+      "/ssh_keys",
+      get_with(list_ssh_keys, |op| op.id("listSshKeys")),
+    )
+    .api_route(
+      "/ssh_keys",
+      delete_with(delete_ssh_key, |op| op.id("deleteSshKey")),
+    )
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct AddSshKey {
+  /// Falls back to the comment of the key if empty.
+  #[serde(default)]
+  name: String,
+  /// Public key in the OpenSSH `authorized_keys` format.
+  key: String,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct AddSshKeyRes {
+  uuid: Uuid,
+  fingerprint: String,
+}
+
+async fn add_ssh_key(
+  auth: JwtAuth,
+  db: Connection,
+  Json(data): Json<AddSshKey>,
+) -> Result<Json<AddSshKeyRes>> {
+  let key = key::parse(&data.key)?;
+
+  let name = match data.name.trim() {
+    "" => key.comment.clone().unwrap_or_default(),
+    name => name.to_string(),
+  };
+  if name.is_empty() {
+    bail!(BAD_REQUEST, "SSH key name must not be empty");
+  }
+
+  if db
+    .ssh_key()
+    .find_by_fingerprint(&key.fingerprint)
+    .await?
+    .is_some()
+  {
+    bail!(CONFLICT, "SSH key is already registered");
+  }
+
+  let id = Uuid::now_v7();
+  db.ssh_key()
+    .create_key(SshKey {
+      id,
+      user_id: auth.user_id,
+      name,
+      key_type: key.key_type,
+      public_key: key.public_key,
+      fingerprint: key.fingerprint.clone(),
+      created_at: Utc::now().naive_utc(),
+    })
+    .await?;
+  info!("Added ssh key {} for user {}", id, auth.user_id);
+
+  Ok(Json(AddSshKeyRes {
+    uuid: id,
+    fingerprint: key.fingerprint,
+  }))
+}
+
+#[derive(Serialize, JsonSchema)]
+struct SshKeyInfo {
+  id: Uuid,
+  name: String,
+  key_type: String,
+  public_key: String,
+  fingerprint: String,
+  created_at: DateTime<Utc>,
+}
+
+impl From<SshKey> for SshKeyInfo {
+  fn from(key: SshKey) -> Self {
+    Self {
+      id: key.id,
+      name: key.name,
+      key_type: key.key_type,
+      public_key: key.public_key,
+      fingerprint: key.fingerprint,
+      created_at: key.created_at.and_utc(),
+    }
+  }
+}
+
+async fn list_ssh_keys(auth: JwtAuth, db: Connection) -> Result<Json<Vec<SshKeyInfo>>> {
+  let keys = db.ssh_key().list_for_user(auth.user_id).await?;
+  Ok(Json(keys.into_iter().map(SshKeyInfo::from).collect()))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct DeleteSshKey {
+  uuid: Uuid,
+}
+
+async fn delete_ssh_key(
+  auth: JwtAuth,
+  db: Connection,
+  Json(data): Json<DeleteSshKey>,
+) -> Result<()> {
+  let key = db.ssh_key().find_for_user(data.uuid, auth.user_id).await?;
+
+  db.ssh_key().delete_key(key.id).await?;
+  info!("Deleted ssh key {} of user {}", key.id, auth.user_id);
+
+  Ok(())
+}
@@ -0,0 +1,15 @@
+use aide::axum::ApiRouter;
+
+mod key;
+mod lookup;
+mod management;
+
+/// Key management for the logged in user, nested under `/user`.
+pub fn router() -> ApiRouter {
+  management::router()
+}
+
+/// Endpoints wings uses to authenticate SSH sessions.
+pub fn wings_router() -> ApiRouter {
+  lookup::router()
+}
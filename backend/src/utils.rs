@@ -1,16 +1,67 @@
+use aide::OperationIo;
+use axum::{Extension, extract::FromRequestParts};
 use centaurus::{
   UpdateMessage,
   backend::{
     auth::permission::{self, Permission},
     endpoints::websocket,
   },
-  permission,
+  bail,
+  db::{init::Connection, tables::ConnectionExt},
+  error::Result,
 };
-use serde::{Deserialize, Serialize};
+use http::request::Parts;
+use serde::{Deserialize, Deserializer, Serialize};
+use tokio::{spawn, sync::broadcast::error::RecvError};
+use tracing::warn;
 use uuid::Uuid;
 
-#[allow(unused)]
-pub type Updater = websocket::state::Updater<UpdateMessage>;
+use crate::{
+  api_keys::ApiKeyScope,
+  cluster::{Bus, Cluster, Event},
+  nodes::node_scope,
+};
+
+/// Broadcasts updates to the websocket sessions of every instance. The
+/// endpoints of centaurus use its updater directly, their updates stay on the
+/// instance that handled the request.
+#[derive(Clone, FromRequestParts, OperationIo)]
+#[from_request(via(Extension))]
+pub struct Updater {
+  local: websocket::state::Updater<UpdateMessage>,
+  bus: Option<Bus>,
+}
+
+impl Updater {
+  pub fn new(local: websocket::state::Updater<UpdateMessage>, cluster: &Cluster) -> Self {
+    let bus = cluster.bus().cloned();
+    if let Some(bus) = &bus {
+      let mut events = bus.subscribe();
+      let local = local.clone();
+      spawn(async move {
+        loop {
+          match events.recv().await {
+            Ok(Event::Update { message }) => local.broadcast(message).await,
+            Ok(_) => (),
+            Err(RecvError::Lagged(missed)) => {
+              warn!("Missed {} updates of other instances", missed)
+            }
+            Err(RecvError::Closed) => break,
+          }
+        }
+      });
+    }
+
+    Self { local, bus }
+  }
+
+  pub async fn broadcast(&self, message: UpdateMessage) {
+    self.local.broadcast(message).await;
+    if let Some(bus) = &self.bus {
+      bus.publish(Event::Update { message }).await;
+    }
+  }
+}
 
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, UpdateMessage)]
 #[serde(tag = "type")]
@@ -30,13 +81,90 @@ pub enum UpdateMessage {
   Nodes {
     uuid: Uuid,
   },
+  Alerts,
 }
 
 pub fn permissions() -> Vec<&'static str> {
   let mut perms = permission::permissions();
-  perms.extend_from_slice(&[NodeViewPerm::name(), NodeEditPerm::name()]);
+  perms.extend_from_slice(&[
+    NodeViewPerm::name(),
+    NodeEditPerm::name(),
+    AlertViewPerm::name(),
+    AlertEditPerm::name(),
+    WebhookViewPerm::name(),
+    WebhookEditPerm::name(),
+  ]);
   perms
 }
 
-permission!(NodeViewPerm, "node:view");
-permission!(NodeEditPerm, "node:edit");
+/// Tells a missing field (`None`) apart from an explicit `null`
+/// (`Some(None)`), use together with `#[serde(default)]`.
+pub fn double_option<'de, T, D>(deserializer: D) -> std::result::Result<Option<Option<T>>, D::Error>
+where
+  T: Deserialize<'de>,
+  D: Deserializer<'de>,
+{
+  Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Like centaurus' `permission!`, but additionally restricted to the scope of
+/// the API key the request was authenticated with (if any).
+macro_rules! scoped_permission {
+  ($type:ident, $name:literal) => {
+    pub struct $type;
+
+    impl Permission for $type {
+      fn name() -> &'static str {
+        $name
+      }
+
+      fn check(
+        db: &Connection,
+        user: Uuid,
+        parts: &Parts,
+      ) -> impl Future<Output = Result<()>> + Send {
+        let in_scope = ApiKeyScope::allows(parts, $name);
+
+        async move {
+          if !in_scope {
+            bail!(FORBIDDEN, "api key is missing the required permission");
+          }
+          if !db.group().user_hash_permissions(user, $name).await? {
+            bail!(FORBIDDEN, "insufficient permissions");
+          }
+          Ok(())
+        }
+      }
+    }
+  };
+}
+
+/// `node:view` can also be granted for single locations, so the check passes
+/// as long as the user can see at least one of them.
+pub struct NodeViewPerm;
+
+impl Permission for NodeViewPerm {
+  fn name() -> &'static str {
+    "node:view"
+  }
+
+  fn check(db: &Connection, user: Uuid, parts: &Parts) -> impl Future<Output = Result<()>> + Send {
+    let in_scope = ApiKeyScope::allows(parts, Self::name());
+
+    async move {
+      if !in_scope {
+        bail!(FORBIDDEN, "api key is missing the required permission");
+      }
+      if node_scope(db, user).await?.is_empty() {
+        bail!(FORBIDDEN, "insufficient permissions");
+      }
+      Ok(())
+    }
+  }
+}
+
+scoped_permission!(NodeEditPerm, "node:edit");
+scoped_permission!(AlertViewPerm, "alert:view");
+scoped_permission!(AlertEditPerm, "alert:edit");
+scoped_permission!(WebhookViewPerm, "webhook:view");
+scoped_permission!(WebhookEditPerm, "webhook:edit");
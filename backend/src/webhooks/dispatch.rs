@@ -0,0 +1,303 @@
+use std::time::Duration;
+
+use aide::OperationIo;
+use axum::{Extension, extract::FromRequestParts};
+use centaurus::{db::init::Connection, error::Result};
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+use reqwest::Client;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::{
+  spawn,
+  time::{interval, sleep},
+};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::{
+  cluster::Cluster,
+  db::{
+    DBTrait,
+    webhook::{Delivery, Webhook, WebhookEvent},
+  },
+};
+
+pub const EVENT_HEADER: &str = "x-smaug-event";
+pub const DELIVERY_HEADER: &str = "x-smaug-delivery";
+pub const TIMESTAMP_HEADER: &str = "x-smaug-timestamp";
+/// Hex encoded HMAC-SHA512 of `<timestamp>.<body>` keyed with the webhook secret.
+pub const SIGNATURE_HEADER: &str = "x-smaug-signature";
+
+const MAX_ATTEMPTS: i32 = 5;
+/// Doubled after every failed attempt.
+const RETRY_DELAY: Duration = Duration::from_secs(10);
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+const DELIVERY_RETENTION: TimeDelta = TimeDelta::days(7);
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const RESUME_INTERVAL: Duration = Duration::from_secs(60);
+/// Slack on top of the retry schedule before a delivery counts as abandoned,
+/// e.g. because the instance retrying it stopped.
+const RESUME_GRACE: Duration = Duration::from_secs(30);
+
+#[derive(Serialize)]
+struct Payload<'a> {
+  id: Uuid,
+  event: WebhookEvent,
+  timestamp: DateTime<Utc>,
+  data: &'a Value,
+}
+
+/// Sends panel events to the webhooks subscribed to them.
+#[derive(Clone, FromRequestParts, OperationIo)]
+#[from_request(via(Extension))]
+pub struct Webhooks {
+  db: Connection,
+  client: Client,
+}
+
+impl Webhooks {
+  pub fn new(db: &Connection) -> Self {
+    let client = Client::builder()
+      .timeout(DELIVERY_TIMEOUT)
+      .build()
+      .expect("Failed to build webhook client");
+
+    Self {
+      db: db.clone(),
+      client,
+    }
+  }
+
+  /// Queues deliveries in the background, failures never reach the caller.
+  pub fn emit(&self, event: WebhookEvent, data: Value) {
+    let webhooks = self.clone();
+    spawn(async move {
+      if let Err(err) = webhooks.dispatch(event, data).await {
+        warn!(
+          "Failed to dispatch webhook event {}: {:?}",
+          event.as_str(),
+          err
+        );
+      }
+    });
+  }
+
+  async fn dispatch(&self, event: WebhookEvent, data: Value) -> Result<()> {
+    for hook in self.db.webhook().subscribers(event).await? {
+      self.deliver(hook, event, &data).await?;
+    }
+    Ok(())
+  }
+
+  /// Records the delivery and retries it in the background until it succeeds
+  /// or runs out of attempts.
+  pub async fn deliver(&self, hook: Webhook, event: WebhookEvent, data: &Value) -> Result<Uuid> {
+    let id = Uuid::now_v7();
+    let now = Utc::now();
+    let payload = serde_json::to_string(&Payload {
+      id,
+      event,
+      timestamp: now,
+      data,
+    })?;
+
+    let delivery = Delivery {
+      id,
+      webhook_id: hook.id,
+      event: event.as_str().to_string(),
+      payload,
+      attempts: 0,
+      status_code: None,
+      error: None,
+      delivered: false,
+      created_at: now.naive_utc(),
+      updated_at: now.naive_utc(),
+    };
+    self.db.webhook().create_delivery(delivery.clone()).await?;
+
+    spawn({
+      let webhooks = self.clone();
+      async move { webhooks.retry(hook, delivery).await }
+    });
+
+    Ok(id)
+  }
+
+  async fn retry(&self, hook: Webhook, mut delivery: Delivery) {
+    loop {
+      let (status_code, error) = self.attempt(&hook, &delivery).await;
+      delivery.attempts += 1;
+      delivery.delivered = error.is_none();
+      delivery.status_code = status_code;
+      delivery.error = error;
+      delivery.updated_at = Utc::now().naive_utc();
+
+      if let Err(err) = self.db.webhook().update_delivery(delivery.clone()).await {
+        warn!(
+          "Failed to update webhook delivery {}: {:?}",
+          delivery.id, err
+        );
+      }
+      if delivery.delivered || delivery.attempts >= MAX_ATTEMPTS {
+        break;
+      }
+
+      let delay = retry_delay(delivery.attempts);
+      debug!(
+        "Webhook delivery {} failed, retrying in {:?}",
+        delivery.id, delay
+      );
+      sleep(delay).await;
+    }
+  }
+
+  /// Picks up deliveries nobody retries anymore, so they survive restarts.
+  async fn resume(&self) -> Result<()> {
+    let now = Utc::now().naive_utc();
+    for delivery in self.db.webhook().pending_deliveries(MAX_ATTEMPTS).await? {
+      if !abandoned(&delivery, now) {
+        continue;
+      }
+      let hook = match self.db.webhook().find_by_id(delivery.webhook_id).await {
+        Ok(hook) => hook,
+        Err(err) => {
+          warn!(
+            "Failed to load webhook for delivery {}: {:?}",
+            delivery.id, err
+          );
+          continue;
+        }
+      };
+
+      debug!("Resuming webhook delivery {}", delivery.id);
+      let webhooks = self.clone();
+      spawn(async move { webhooks.retry(hook, delivery).await });
+    }
+    Ok(())
+  }
+
+  async fn attempt(&self, hook: &Webhook, delivery: &Delivery) -> (Option<i32>, Option<String>) {
+    let timestamp = Utc::now().timestamp_millis().to_string();
+    let signature = match sign(&hook.secret, &timestamp, &delivery.payload) {
+      Ok(signature) => signature,
+      Err(err) => return (None, Some(format!("{:?}", err))),
+    };
+
+    let res = self
+      .client
+      .post(&hook.url)
+      .header(http::header::CONTENT_TYPE, "application/json")
+      .header(EVENT_HEADER, &delivery.event)
+      .header(DELIVERY_HEADER, delivery.id.to_string())
+      .header(TIMESTAMP_HEADER, timestamp)
+      .header(SIGNATURE_HEADER, signature)
+      .body(delivery.payload.clone())
+      .send()
+      .await;
+
+    match res {
+      Ok(res) if res.status().is_success() => (Some(res.status().as_u16() as i32), None),
+      Ok(res) => (
+        Some(res.status().as_u16() as i32),
+        Some(format!("Endpoint responded with {}", res.status())),
+      ),
+      Err(err) => (None, Some(err.to_string())),
+    }
+  }
+}
+
+/// Wait after the given number of failed attempts, doubled every time.
+fn retry_delay(attempts: i32) -> Duration {
+  RETRY_DELAY * 2u32.pow(attempts.max(1) as u32 - 1)
+}
+
+/// The next attempt should have been recorded already, so whoever was retrying
+/// the delivery is gone.
+fn abandoned(delivery: &Delivery, now: NaiveDateTime) -> bool {
+  let next_attempt = if delivery.attempts == 0 {
+    Duration::ZERO
+  } else {
+    retry_delay(delivery.attempts)
+  };
+  let deadline = next_attempt + DELIVERY_TIMEOUT + RESUME_GRACE;
+  now - delivery.updated_at > TimeDelta::from_std(deadline).unwrap_or(TimeDelta::MAX)
+}
+
+pub fn sign(secret: &str, timestamp: &str, body: &str) -> Result<String> {
+  shared::auth::hmac(&format!("{}.{}", timestamp, body), secret)
+}
+
+/// Only the leader prunes, the deliveries are shared by all instances.
+pub fn spawn_prune(db: Connection, cluster: Cluster) {
+  spawn(async move {
+    let mut interval = interval(PRUNE_INTERVAL);
+    loop {
+      interval.tick().await;
+      if !cluster.is_leader() {
+        continue;
+      }
+      let before = (Utc::now() - DELIVERY_RETENTION).naive_utc();
+      match db.webhook().prune_deliveries(before).await {
+        Ok(pruned) if pruned > 0 => debug!("Pruned {} webhook deliveries", pruned),
+        Ok(_) => (),
+        Err(err) => warn!("Failed to prune webhook deliveries: {:?}", err),
+      }
+    }
+  });
+}
+
+/// Only the leader resumes, otherwise every instance would retry them.
+pub fn spawn_resume(webhooks: Webhooks, cluster: Cluster) {
+  spawn(async move {
+    let mut interval = interval(RESUME_INTERVAL);
+    loop {
+      interval.tick().await;
+      if !cluster.is_leader() {
+        continue;
+      }
+      if let Err(err) = webhooks.resume().await {
+        warn!("Failed to resume webhook deliveries: {:?}", err);
+      }
+    }
+  });
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn delivery(attempts: i32, updated_ago: TimeDelta, now: NaiveDateTime) -> Delivery {
+    Delivery {
+      id: Uuid::now_v7(),
+      webhook_id: Uuid::now_v7(),
+      event: WebhookEvent::NodeCreated.as_str().to_string(),
+      payload: "{}".into(),
+      attempts,
+      status_code: None,
+      error: None,
+      delivered: false,
+      created_at: now - updated_ago,
+      updated_at: now - updated_ago,
+    }
+  }
+
+  #[test]
+  fn retry_delay_doubles() {
+    assert_eq!(retry_delay(1), Duration::from_secs(10));
+    assert_eq!(retry_delay(2), Duration::from_secs(20));
+    assert_eq!(retry_delay(4), Duration::from_secs(80));
+  }
+
+  #[test]
+  fn deliveries_count_as_abandoned_after_their_next_attempt() {
+    let now = Utc::now().naive_utc();
+
+    // never attempted, the first attempt runs right after creating it
+    assert!(!abandoned(&delivery(0, TimeDelta::seconds(5), now), now));
+    assert!(abandoned(&delivery(0, TimeDelta::seconds(60), now), now));
+
+    // 40s until the fourth attempt
+    assert!(!abandoned(&delivery(3, TimeDelta::seconds(60), now), now));
+    assert!(abandoned(&delivery(3, TimeDelta::seconds(90), now), now));
+  }
+}
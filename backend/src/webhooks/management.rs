@@ -0,0 +1,271 @@
+use aide::axum::{
+  ApiRouter,
+  routing::{delete_with, get_with, post_with},
+};
+use axum::{
+  Json,
+  extract::{Path, Query},
+};
+use centaurus::{bail, db::init::Connection, error::Result};
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use url::Url;
+use uuid::Uuid;
+
+use crate::{
+  auth::jwt_auth::JwtAuth,
+  db::{
+    DBTrait,
+    webhook::{Webhook, WebhookEvent},
+  },
+  utils::{WebhookEditPerm, WebhookViewPerm},
+  webhooks::dispatch::Webhooks,
+};
+
+const DEFAULT_DELIVERY_LIMIT: u64 = 50;
+
+pub fn router() -> ApiRouter {
+  ApiRouter::new()
+    .api_route("/", post_with(create_webhook, |op| op.id("createWebhook")))
+    .api_route("/", get_with(list_webhooks, |op| op.id("listWebhooks")))
+    .api_route(
+      "/",
+      delete_with(delete_webhook, |op| op.id("deleteWebhook")),
+    )
+    .api_route(
+      "/{uuid}",
+      post_with(update_webhook, |op| op.id("updateWebhook")),
+    )
+    .api_route(
+      "/{uuid}/ping",
+      post_with(ping_webhook, |op| op.id("pingWebhook")),
+    )
+    .api_route(
+      "/{uuid}/deliveries",
+      get_with(list_deliveries, |op| op.id("listWebhookDeliveries")),
+    )
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct WebhookData {
+  name: String,
+  url: String,
+  events: Vec<WebhookEvent>,
+  #[serde(default = "enabled_default")]
+  enabled: bool,
+}
+
+fn enabled_default() -> bool {
+  true
+}
+
+/// Checks everything but the name and returns the normalized events.
+fn validate(data: &WebhookData) -> Result<Vec<WebhookEvent>> {
+  if !Url::parse(&data.url).is_ok_and(|url| matches!(url.scheme(), "http" | "https")) {
+    bail!(BAD_REQUEST, "Webhook URL must be a valid http(s) URL");
+  }
+
+  let mut events = data.events.clone();
+  events.sort();
+  events.dedup();
+  if events.is_empty() {
+    bail!(BAD_REQUEST, "Webhook must subscribe to at least one event");
+  }
+  if events.contains(&WebhookEvent::Ping) {
+    bail!(BAD_REQUEST, "Ping events are only sent on request");
+  }
+
+  Ok(events)
+}
+
+#[derive(Serialize, JsonSchema)]
+struct CreateWebhookRes {
+  uuid: Uuid,
+  /// Only returned once, used to verify the signature of deliveries.
+  secret: String,
+}
+
+async fn create_webhook(
+  _auth: JwtAuth<WebhookEditPerm>,
+  db: Connection,
+  Json(data): Json<WebhookData>,
+) -> Result<Json<CreateWebhookRes>> {
+  let name = data.name.trim().to_string();
+  if name.is_empty() {
+    bail!(BAD_REQUEST, "Webhook name must not be empty");
+  }
+  if db.webhook().find_by_name(&name).await?.is_some() {
+    bail!(CONFLICT, "Webhook with this name already exists");
+  }
+  let events = validate(&data)?;
+
+  let mut raw_secret = [0u8; 32];
+  rand::rng().fill_bytes(&mut raw_secret);
+  let secret = hex::encode(raw_secret);
+
+  let id = Uuid::now_v7();
+  db.webhook()
+    .create_webhook(Webhook {
+      id,
+      name,
+      url: data.url,
+      secret: secret.clone(),
+      enabled: data.enabled,
+      created_at: Utc::now().naive_utc(),
+    })
+    .await?;
+  db.webhook().set_events(id, events).await?;
+  info!("Created webhook with ID {}", id);
+
+  Ok(Json(CreateWebhookRes { uuid: id, secret }))
+}
+
+#[derive(Serialize, JsonSchema)]
+struct WebhookInfo {
+  uuid: Uuid,
+  name: String,
+  url: String,
+  events: Vec<WebhookEvent>,
+  enabled: bool,
+}
+
+async fn list_webhooks(
+  _auth: JwtAuth<WebhookViewPerm>,
+  db: Connection,
+) -> Result<Json<Vec<WebhookInfo>>> {
+  let hooks = db.webhook().list_webhooks().await?;
+  let mut events = db.webhook().all_events().await?;
+
+  Ok(Json(
+    hooks
+      .into_iter()
+      .map(|hook| WebhookInfo {
+        uuid: hook.id,
+        events: events.remove(&hook.id).unwrap_or_default(),
+        name: hook.name,
+        url: hook.url,
+        enabled: hook.enabled,
+      })
+      .collect(),
+  ))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct WebhookRequest {
+  uuid: Uuid,
+}
+
+async fn update_webhook(
+  _auth: JwtAuth<WebhookEditPerm>,
+  db: Connection,
+  Path(req): Path<WebhookRequest>,
+  Json(data): Json<WebhookData>,
+) -> Result<()> {
+  let hook = db.webhook().find_by_id(req.uuid).await?;
+
+  let name = data.name.trim().to_string();
+  if name.is_empty() {
+    bail!(BAD_REQUEST, "Webhook name must not be empty");
+  }
+  if let Some(other) = db.webhook().find_by_name(&name).await?
+    && other.id != hook.id
+  {
+    bail!(CONFLICT, "Webhook with this name already exists");
+  }
+  let events = validate(&data)?;
+
+  db.webhook()
+    .update_webhook(Webhook {
+      name,
+      url: data.url,
+      enabled: data.enabled,
+      ..hook
+    })
+    .await?;
+  db.webhook().set_events(req.uuid, events).await?;
+  info!("Updated webhook with ID {}", req.uuid);
+
+  Ok(())
+}
+
+async fn delete_webhook(
+  _auth: JwtAuth<WebhookEditPerm>,
+  db: Connection,
+  Json(data): Json<WebhookRequest>,
+) -> Result<()> {
+  db.webhook().find_by_id(data.uuid).await?;
+  db.webhook().delete_webhook(data.uuid).await?;
+  info!("Deleted webhook with ID {}", data.uuid);
+
+  Ok(())
+}
+
+#[derive(Serialize, JsonSchema)]
+struct PingRes {
+  delivery: Uuid,
+}
+
+/// Delivers a `ping` event regardless of the subscribed events.
+async fn ping_webhook(
+  _auth: JwtAuth<WebhookEditPerm>,
+  db: Connection,
+  webhooks: Webhooks,
+  Path(req): Path<WebhookRequest>,
+) -> Result<Json<PingRes>> {
+  let hook = db.webhook().find_by_id(req.uuid).await?;
+  let data = serde_json::json!({ "webhook": hook.id });
+  let delivery = webhooks.deliver(hook, WebhookEvent::Ping, &data).await?;
+
+  Ok(Json(PingRes { delivery }))
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ListDeliveries {
+  limit: Option<u64>,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct DeliveryInfo {
+  uuid: Uuid,
+  event: String,
+  payload: String,
+  attempts: i32,
+  status_code: Option<i32>,
+  error: Option<String>,
+  delivered: bool,
+  created_at: DateTime<Utc>,
+  updated_at: DateTime<Utc>,
+}
+
+async fn list_deliveries(
+  _auth: JwtAuth<WebhookViewPerm>,
+  db: Connection,
+  Path(req): Path<WebhookRequest>,
+  Query(query): Query<ListDeliveries>,
+) -> Result<Json<Vec<DeliveryInfo>>> {
+  db.webhook().find_by_id(req.uuid).await?;
+  let deliveries = db
+    .webhook()
+    .list_deliveries(req.uuid, query.limit.unwrap_or(DEFAULT_DELIVERY_LIMIT))
+    .await?;
+
+  Ok(Json(
+    deliveries
+      .into_iter()
+      .map(|delivery| DeliveryInfo {
+        uuid: delivery.id,
+        event: delivery.event,
+        payload: delivery.payload,
+        attempts: delivery.attempts,
+        status_code: delivery.status_code,
+        error: delivery.error,
+        delivered: delivery.delivered,
+        created_at: delivery.created_at.and_utc(),
+        updated_at: delivery.updated_at.and_utc(),
+      })
+      .collect(),
+  ))
+}
@@ -0,0 +1,23 @@
+use aide::axum::ApiRouter;
+use axum::Extension;
+use centaurus::db::init::Connection;
+
+use crate::cluster::Cluster;
+
+pub use dispatch::Webhooks;
+
+mod dispatch;
+mod management;
+
+pub fn router() -> ApiRouter {
+  management::router()
+}
+
+/// Also returns the dispatcher for events raised outside of requests.
+pub fn state(router: ApiRouter, db: &Connection, cluster: &Cluster) -> (ApiRouter, Webhooks) {
+  dispatch::spawn_prune(db.clone(), cluster.clone());
+  let webhooks = Webhooks::new(db);
+  dispatch::spawn_resume(webhooks.clone(), cluster.clone());
+
+  (router.layer(Extension(webhooks.clone())), webhooks)
+}
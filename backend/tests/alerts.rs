@@ -0,0 +1,159 @@
+mod common;
+
+use common::{TestServer, receiver::webhook_receiver, unique};
+use reqwest::StatusCode;
+use serde_json::Value;
+
+async fn create_channel(server: &TestServer, kind: &str, target: &str) -> reqwest::Response {
+  server
+    .post(
+      "/alerts/channels",
+      serde_json::json!({ "name": unique("channel"), "kind": kind, "target": target }),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn alert_channel_test_send() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let (url, received) = webhook_receiver().await;
+
+  let resp = create_channel(&server, "webhook", &url).await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let webhook: Value = resp.json().await.unwrap();
+
+  let resp = server
+    .post(
+      &format!(
+        "/alerts/channels/{}/test",
+        webhook["uuid"].as_str().unwrap()
+      ),
+      Value::Null,
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  {
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    let payload: Value = serde_json::from_str(&received[0].1).unwrap();
+    assert_eq!(payload["event"], "test");
+  }
+
+  // Without mail settings an email channel can be created but not used.
+  let resp = create_channel(&server, "email", "ops@example.com").await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let email: Value = resp.json().await.unwrap();
+  let resp = server
+    .post(
+      &format!("/alerts/channels/{}/test", email["uuid"].as_str().unwrap()),
+      Value::Null,
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+  let resp = create_channel(&server, "email", "not-an-address").await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+  let resp = create_channel(&server, "webhook", "ftp://example.com/hook").await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+  let resp = server.get("/alerts/channels").await;
+  let channels: Value = resp.json().await.unwrap();
+  assert_eq!(channels.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn alert_rule_crud_and_validation() {
+  let (server, _) = TestServer::start_with_admin().await;
+
+  let resp = create_channel(&server, "webhook", "http://127.0.0.1:1/hook").await;
+  let channel: Value = resp.json().await.unwrap();
+
+  let rule = |condition: Value, channels: Value| {
+    serde_json::json!({
+      "name": "disk",
+      "condition": condition,
+      "channels": channels,
+    })
+  };
+
+  let resp = server
+    .post(
+      "/alerts/rules",
+      rule(
+        serde_json::json!({ "type": "disk_usage", "percent": 150.0 }),
+        serde_json::json!([]),
+      ),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+  let resp = server
+    .post(
+      "/alerts/rules",
+      rule(
+        serde_json::json!({ "type": "disk_usage", "percent": 90.0 }),
+        serde_json::json!([uuid::Uuid::new_v4()]),
+      ),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+  let resp = server
+    .post(
+      "/alerts/rules",
+      rule(
+        serde_json::json!({ "type": "disk_usage", "percent": 90.0 }),
+        serde_json::json!([channel["uuid"]]),
+      ),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let created: Value = resp.json().await.unwrap();
+  let id = created["uuid"].as_str().unwrap();
+
+  // Names are unique.
+  let resp = server
+    .post(
+      "/alerts/rules",
+      rule(
+        serde_json::json!({ "type": "node_disconnected", "minutes": 5 }),
+        serde_json::json!([]),
+      ),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+  let resp = server
+    .post(
+      &format!("/alerts/rules/{id}"),
+      serde_json::json!({
+        "name": "disconnected",
+        "condition": { "type": "node_disconnected", "minutes": 5 },
+        "enabled": false,
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  let resp = server.get("/alerts/rules").await;
+  let rules: Value = resp.json().await.unwrap();
+  assert_eq!(rules.as_array().unwrap().len(), 1);
+  assert_eq!(rules[0]["name"], "disconnected");
+  assert_eq!(rules[0]["condition"]["type"], "node_disconnected");
+  assert_eq!(rules[0]["condition"]["minutes"], 5);
+  assert_eq!(rules[0]["enabled"], false);
+  assert!(rules[0]["channels"].as_array().unwrap().is_empty());
+
+  let resp = server
+    .delete("/alerts/rules", serde_json::json!({ "uuid": id }))
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let resp = server.get("/alerts/rules").await;
+  let rules: Value = resp.json().await.unwrap();
+  assert!(rules.as_array().unwrap().is_empty());
+
+  let resp = server.get("/alerts?open=true").await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let alerts: Value = resp.json().await.unwrap();
+  assert!(alerts.as_array().unwrap().is_empty());
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use common::{TestServer, unique};
+use reqwest::StatusCode;
+use serde_json::Value;
+
+async fn create_key(server: &TestServer, permissions: &[&str]) -> (String, String) {
+  let resp = server
+    .post(
+      "/api_keys",
+      serde_json::json!({
+        "name": unique("key"),
+        "permissions": permissions,
+        "expires_at": null,
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let created: Value = resp.json().await.unwrap();
+  (
+    created["uuid"].as_str().unwrap().to_string(),
+    created["key"].as_str().unwrap().to_string(),
+  )
+}
+
+#[tokio::test]
+async fn api_key_crud_flow() {
+  let (server, _) = TestServer::start_with_admin().await;
+
+  let (id, key) = create_key(&server, &["node:view"]).await;
+  assert!(key.starts_with("smaug_"));
+
+  // Only the prefix of the key is listed, never the secret itself.
+  let resp = server.get("/api_keys").await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let keys: Value = resp.json().await.unwrap();
+  let listed = &keys.as_array().unwrap()[0];
+  assert_eq!(listed["id"], id.as_str());
+  assert!(key.starts_with(listed["prefix"].as_str().unwrap()));
+  assert!(!keys.to_string().contains(&key));
+  assert_eq!(listed["permissions"], serde_json::json!(["node:view"]));
+
+  let resp = server
+    .delete("/api_keys", serde_json::json!({ "uuid": id }))
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  let resp = server.get("/api_keys").await;
+  let keys: Value = resp.json().await.unwrap();
+  assert!(keys.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn api_key_is_limited_to_its_scope() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let (id, key) = create_key(&server, &["node:view"]).await;
+
+  let resp = server.get_bearer("/nodes", &key).await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  // The owner may edit nodes, but the key was not granted `node:edit`.
+  let resp = server
+    .delete_bearer(
+      "/nodes",
+      serde_json::json!({ "uuid": uuid::Uuid::now_v7() }),
+      &key,
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+  // Keys can not be used to manage keys.
+  let resp = server.get_bearer("/api_keys", &key).await;
+  assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+  // Usage is recorded for the owner.
+  let resp = server.get(&format!("/api_keys/{id}/usage")).await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let usage: Value = resp.json().await.unwrap();
+  assert_eq!(usage.as_array().unwrap().len(), 2);
+  assert!(usage.to_string().contains("/api/nodes"));
+}
+
+#[tokio::test]
+async fn revoked_or_unknown_api_key_is_rejected() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let (id, key) = create_key(&server, &["node:view"]).await;
+
+  let resp = server
+    .delete("/api_keys", serde_json::json!({ "uuid": id }))
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  let resp = server.get_bearer("/nodes", &key).await;
+  assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+  let resp = server.get_bearer("/nodes", "smaug_unknown").await;
+  assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn api_key_can_not_exceed_owner_permissions() {
+  let (server, _) = TestServer::start_with_admin().await;
+
+  let resp = server
+    .post(
+      "/api_keys",
+      serde_json::json!({
+        "name": unique("key"),
+        "permissions": ["does:not-exist"],
+        "expires_at": null,
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+  let resp = server
+    .post(
+      "/api_keys",
+      serde_json::json!({
+        "name": unique("key"),
+        "permissions": ["node:view"],
+        "expires_at": "2000-01-01T00:00:00Z",
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{
+  CONNECT_TIMEOUT, TestServer,
+  cluster::{Instance, LEASE_TTL_SECS, SharedDb},
+  wings::FakeWings,
+};
+use reqwest::StatusCode;
+use serde_json::Value;
+use tokio::time::sleep;
+
+const EMAIL: &str = "admin@example.com";
+const PASSWORD: &str = "hunter2pass";
+
+async fn start() -> (SharedDb, TestServer) {
+  let db = SharedDb::new();
+  let server = TestServer::start_shared(&db).await;
+  server.setup_admin("admin", EMAIL, PASSWORD).await;
+  (db, server)
+}
+
+async fn start_instance(db: &SharedDb) -> (Instance, TestServer) {
+  let (instance, server) = Instance::start(db).await;
+  assert_eq!(server.login(EMAIL, PASSWORD).await.status(), StatusCode::OK);
+  (instance, server)
+}
+
+/// Long enough for the other instances to renew their leases a few times.
+async fn lease_rounds() {
+  sleep(Duration::from_secs(LEASE_TTL_SECS * 2)).await;
+}
+
+#[tokio::test]
+async fn only_the_owner_connects_to_a_node() {
+  let (db, server) = start().await;
+  let wings = FakeWings::start().await;
+  let id = wings.attach(&server).await;
+  server.wait_for_connected(&id, true, CONNECT_TIMEOUT).await;
+
+  let (_instance, other) = start_instance(&db).await;
+  // the other instance reads the connection state from the database
+  other.wait_for_connected(&id, true, CONNECT_TIMEOUT).await;
+
+  lease_rounds().await;
+  assert_eq!(wings.connections(), 1);
+  assert!(wings.is_open());
+}
+
+#[tokio::test]
+async fn nodes_move_when_their_instance_stops() {
+  let (db, server) = start().await;
+  let (mut instance, other) = start_instance(&db).await;
+  let wings = FakeWings::start().await;
+  let id = wings.attach(&other).await;
+  other.wait_for_connected(&id, true, CONNECT_TIMEOUT).await;
+  assert_eq!(wings.connections(), 1);
+
+  instance.stop();
+  wings.wait_for_connections(2).await;
+  // the lease of the stopped owner has to run out first
+  server
+    .wait_for_connected(&id, true, Duration::from_secs(10))
+    .await;
+  let node: Value = server
+    .get(&format!("/nodes/{id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  assert_eq!(node["lifecycle"], "connected");
+}
+
+#[tokio::test]
+async fn nodes_deleted_on_another_instance_are_disconnected() {
+  let (db, server) = start().await;
+  let wings = FakeWings::start().await;
+  let id = wings.attach(&server).await;
+  server.wait_for_connected(&id, true, CONNECT_TIMEOUT).await;
+
+  let (_instance, other) = start_instance(&db).await;
+  let resp = other
+    .delete("/nodes", serde_json::json!({ "uuid": id }))
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  wings.wait_for_closed().await;
+  lease_rounds().await;
+  assert_eq!(wings.connections(), 1);
+}
@@ -0,0 +1,88 @@
+//! Further instances of the panel for the cluster tests.
+//!
+//! The metrics recorder of centaurus is process-global, so only one server can
+//! run inside the test process. Every further instance is the `backend` binary
+//! in a process of its own, sharing an SQLite file with the test server. There
+//! is no Postgres, so the instances coordinate through leases only.
+
+use std::{
+  net::TcpListener,
+  path::PathBuf,
+  process::{Child, Command, Stdio},
+};
+
+use uuid::Uuid;
+
+use super::TestServer;
+
+/// Short enough for the tests to wait for a lease to run out.
+pub const LEASE_TTL_SECS: u64 = 3;
+
+/// A database file, removed once the test is done.
+pub struct SharedDb {
+  path: PathBuf,
+}
+
+impl SharedDb {
+  pub fn new() -> SharedDb {
+    let path = std::env::temp_dir().join(format!("smaug-cluster-{}.db", Uuid::new_v4()));
+    SharedDb { path }
+  }
+
+  pub fn url(&self) -> String {
+    format!("sqlite://{}?mode=rwc", self.path.display())
+  }
+}
+
+impl Drop for SharedDb {
+  fn drop(&mut self) {
+    for suffix in ["", "-wal", "-shm"] {
+      let mut path = self.path.clone().into_os_string();
+      path.push(suffix);
+      std::fs::remove_file(path).ok();
+    }
+  }
+}
+
+/// The backend binary running on the shared database, killed on drop.
+pub struct Instance {
+  child: Child,
+}
+
+impl Instance {
+  /// Start an instance and wait until it serves requests. The first instance
+  /// must have created the database already.
+  pub async fn start(db: &SharedDb) -> (Instance, TestServer) {
+    // the port is released again for the instance to bind it
+    let port = TcpListener::bind("127.0.0.1:0")
+      .and_then(|listener| listener.local_addr())
+      .expect("find free port")
+      .port();
+
+    let child = Command::new(env!("CARGO_BIN_EXE_backend"))
+      .env("PORT", port.to_string())
+      .env("DB_URL", db.url())
+      .env("SITE_URL", "http://localhost/")
+      .env("LOG_LEVEL", "off")
+      .env("LEASE_TTL_SECS", LEASE_TTL_SECS.to_string())
+      .stdout(Stdio::null())
+      .stderr(Stdio::null())
+      .spawn()
+      .expect("spawn backend instance");
+
+    let instance = Instance { child };
+    let server = TestServer::attach(port).await;
+    (instance, server)
+  }
+
+  pub fn stop(&mut self) {
+    self.child.kill().ok();
+    self.child.wait().ok();
+  }
+}
+
+impl Drop for Instance {
+  fn drop(&mut self) {
+    self.stop();
+  }
+}
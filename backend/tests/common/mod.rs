@@ -20,8 +20,16 @@
 //! ```text
 //! cargo nextest run --features test
 //! ```
+//!
+//! Nodes the panel dials can be backed by a [`wings::FakeWings`]. Further
+//! instances of the panel sharing a database run as [`cluster::Instance`]s.
+//! Webhook deliveries are captured by a [`receiver::webhook_receiver`].
 #![allow(dead_code)]
 
+pub mod cluster;
+pub mod receiver;
+pub mod wings;
+
 use std::{
   collections::HashMap,
   sync::{
@@ -36,12 +44,18 @@ use base64::{Engine, prelude::BASE64_STANDARD};
 use reqwest::{Client, RequestBuilder, Response, StatusCode};
 use rsa::{Pkcs1v15Encrypt, RsaPublicKey, pkcs1::DecodeRsaPublicKey, rand_core::OsRng};
 use serde_json::Value;
-use tokio::{spawn, time::sleep};
+use tokio::{
+  spawn,
+  time::{Instant, sleep},
+};
 use uuid::Uuid;
 
 /// The auth cookie centaurus sets on a successful login/setup.
 pub const JWT_COOKIE_NAME: &str = "centaurus_jwt";
 
+/// How long a node usually takes to (dis)connect in the tests.
+pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Configure the process environment for a throwaway in-memory server.
 ///
 /// Safe to call repeatedly; nextest gives every test its own process so this
@@ -68,11 +82,29 @@ impl TestServer {
   /// become ready.
   pub async fn start() -> TestServer {
     prepare_env();
+    TestServer::boot().await
+  }
+
+  /// Boot a server on a database that [`cluster::Instance`]s can share.
+  pub async fn start_shared(db: &cluster::SharedDb) -> TestServer {
+    prepare_env();
+    unsafe {
+      std::env::set_var("DB_URL", db.url());
+      std::env::set_var("LEASE_TTL_SECS", cluster::LEASE_TTL_SECS.to_string());
+    }
+    TestServer::boot().await
+  }
 
+  async fn boot() -> TestServer {
     let app = App::new().await;
     let port = app.port();
     spawn(app.run());
 
+    TestServer::attach(port).await
+  }
+
+  /// Wait for a server that is already running, e.g. in another process.
+  pub async fn attach(port: u16) -> TestServer {
     let client = Client::builder()
       .timeout(Duration::from_secs(30))
       .connect_timeout(Duration::from_secs(30))
@@ -192,6 +224,24 @@ impl TestServer {
       .await
   }
 
+  /// Send a request authenticated only by `Authorization: Bearer <token>`,
+  /// bypassing the cookie jar (used for API keys).
+  pub async fn send_bearer(&self, req: RequestBuilder, token: &str) -> Response {
+    req.bearer_auth(token).send().await.expect("request failed")
+  }
+
+  pub async fn get_bearer(&self, path: &str, token: &str) -> Response {
+    self
+      .send_bearer(self.client.get(self.url(path)), token)
+      .await
+  }
+
+  pub async fn delete_bearer(&self, path: &str, body: Value, token: &str) -> Response {
+    self
+      .send_bearer(self.client.delete(self.url(path)).json(&body), token)
+      .await
+  }
+
   /// PUT a raw byte body (used by the note-edit endpoint, which reads `Bytes`).
   pub async fn put_bytes(&self, path: &str, body: Vec<u8>) -> Response {
     self.send(self.client.put(self.url(path)).body(body)).await
@@ -258,6 +308,40 @@ impl TestServer {
   pub fn clear_cookies(&self) {
     self.cookies.lock().unwrap().clear();
   }
+
+  /// Poll the node until its `connected` flag matches, [`CONNECT_TIMEOUT`]
+  /// is enough unless a lease has to run out first.
+  pub async fn wait_for_connected(&self, id: &str, connected: bool, timeout: Duration) {
+    let deadline = Instant::now() + timeout;
+    while Instant::now() < deadline {
+      if self.node(id).await["connected"] == connected {
+        return;
+      }
+      sleep(Duration::from_millis(50)).await;
+    }
+    panic!("node connected did not become {connected} within {timeout:?}");
+  }
+
+  /// Poll the node until wings reported the sync or it was requested again.
+  pub async fn wait_for_sync_status(&self, id: &str, status: &str) -> Value {
+    for _ in 0..100 {
+      let node = self.node(id).await;
+      if node["sync"]["status"] == status {
+        return node;
+      }
+      sleep(Duration::from_millis(50)).await;
+    }
+    panic!("node sync status did not become {status}");
+  }
+
+  pub async fn node(&self, id: &str) -> Value {
+    self
+      .get(&format!("/nodes/{id}"))
+      .await
+      .json()
+      .await
+      .unwrap()
+  }
 }
 
 /// Pull the `centaurus_jwt` value out of a response's `Set-Cookie` headers.
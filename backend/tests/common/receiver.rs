@@ -0,0 +1,30 @@
+//! Target for webhooks and webhook alert channels.
+
+use std::sync::{Arc, Mutex};
+
+use axum::{Router, extract::State, http::HeaderMap, routing::post};
+use tokio::{net::TcpListener, spawn};
+
+/// Headers and raw body of every request, in the order they arrived.
+pub type Received = Arc<Mutex<Vec<(HeaderMap, String)>>>;
+
+/// Starts a webhook receiver, returns the url to configure and what it received.
+pub async fn webhook_receiver() -> (String, Received) {
+  let received = Received::default();
+  let app = Router::new()
+    .route(
+      "/hook",
+      post(
+        |State(received): State<Received>, headers: HeaderMap, body: String| async move {
+          received.lock().unwrap().push((headers, body));
+        },
+      ),
+    )
+    .with_state(received.clone());
+
+  let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let url = format!("http://{}/hook", listener.local_addr().unwrap());
+  spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+  (url, received)
+}
@@ -0,0 +1,322 @@
+//! In-process stand-in for wings, for nodes the panel dials itself.
+//!
+//! It serves the websocket at `/api` like the real daemon, with the same
+//! `SignData` handshake and `WingsMessage` protocol. The node token only
+//! exists once the panel created the node, so upgrade requests are held until
+//! [`FakeWings::attach`] hands the token over instead of being rejected. That
+//! way the first connect attempt succeeds and tests do not wait for the
+//! reconnect delay.
+//!
+//! Every message from the panel is recorded and answered by a replaceable
+//! responder, which by default behaves like wings: `Hello` gets a `World` and
+//! `SyncConfig` an empty `SyncReport`. Handshakes can be made to fail and the
+//! current websocket can be dropped to exercise reconnects.
+
+use std::{
+  sync::{
+    Arc, Mutex,
+    atomic::{AtomicUsize, Ordering},
+  },
+  time::Duration,
+};
+
+use axum::{
+  Router,
+  extract::{
+    State, WebSocketUpgrade,
+    ws::{Message, WebSocket},
+  },
+  http::{HeaderMap, StatusCode},
+  response::{IntoResponse, Response},
+  routing::any,
+};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::Value;
+use shared::{
+  auth::SignData,
+  msg::{SyncReport, WingsMessage},
+};
+use tokio::{
+  net::TcpListener,
+  spawn,
+  sync::{mpsc, watch},
+  time::sleep,
+};
+
+use super::{TestServer, unique};
+
+/// How the next upgrade requests are answered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Handshake {
+  Accept,
+  /// Fails before the upgrade, like a wings with a different token.
+  Reject,
+  /// Upgrades but signs the response with the wrong token, so the panel has
+  /// to drop the websocket.
+  BadSignature,
+}
+
+type Responder = Box<dyn Fn(&WingsMessage) -> Vec<WingsMessage> + Send + Sync>;
+
+enum Command {
+  Send(WingsMessage),
+  Close,
+}
+
+#[derive(Clone)]
+pub struct FakeWings {
+  inner: Arc<Inner>,
+}
+
+struct Inner {
+  port: u16,
+  token: watch::Sender<Option<String>>,
+  handshake: Mutex<Handshake>,
+  responder: Mutex<Responder>,
+  received: Mutex<Vec<WingsMessage>>,
+  current: Mutex<Option<mpsc::UnboundedSender<Command>>>,
+  attempts: AtomicUsize,
+  connections: AtomicUsize,
+}
+
+impl FakeWings {
+  pub async fn start() -> FakeWings {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let wings = FakeWings {
+      inner: Arc::new(Inner {
+        port,
+        token: watch::Sender::new(None),
+        handshake: Mutex::new(Handshake::Accept),
+        responder: Mutex::new(Box::new(default_responder)),
+        received: Mutex::default(),
+        current: Mutex::default(),
+        attempts: AtomicUsize::new(0),
+        connections: AtomicUsize::new(0),
+      }),
+    };
+
+    let app = Router::new()
+      .route("/api", any(upgrade))
+      .with_state(wings.clone());
+    spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    wings
+  }
+
+  /// Address to create the node with.
+  pub fn address(&self) -> String {
+    format!("127.0.0.1:{}", self.inner.port)
+  }
+
+  /// Creates a node pointing at this wings and hands its token over, which
+  /// releases the held connect attempt of the panel.
+  pub async fn attach(&self, server: &TestServer) -> String {
+    let resp = server
+      .post(
+        "/nodes",
+        serde_json::json!({
+          "name": unique("node"),
+          "address": self.address(),
+          "secure": false,
+          "disk_limit_mb": null,
+          "memory_limit_mb": null,
+          "cpu_limit": null,
+        }),
+      )
+      .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let created: Value = resp.json().await.unwrap();
+    let id = created["uuid"].as_str().unwrap().to_string();
+
+    let node: Value = server
+      .get(&format!("/nodes/{id}"))
+      .await
+      .json()
+      .await
+      .unwrap();
+    self.set_token(node["token"].as_str().unwrap());
+
+    id
+  }
+
+  pub fn set_token(&self, token: &str) {
+    self.inner.token.send_replace(Some(token.to_string()));
+  }
+
+  pub fn set_handshake(&self, handshake: Handshake) {
+    *self.inner.handshake.lock().unwrap() = handshake;
+  }
+
+  /// Replaces how messages from the panel are answered.
+  pub fn respond_with(
+    &self,
+    responder: impl Fn(&WingsMessage) -> Vec<WingsMessage> + Send + Sync + 'static,
+  ) {
+    *self.inner.responder.lock().unwrap() = Box::new(responder);
+  }
+
+  /// Upgrade requests received, including failed handshakes.
+  pub fn attempts(&self) -> usize {
+    self.inner.attempts.load(Ordering::SeqCst)
+  }
+
+  /// Websockets that were accepted.
+  pub fn connections(&self) -> usize {
+    self.inner.connections.load(Ordering::SeqCst)
+  }
+
+  pub fn is_open(&self) -> bool {
+    self.inner.current.lock().unwrap().is_some()
+  }
+
+  pub fn received(&self) -> Vec<WingsMessage> {
+    self.inner.received.lock().unwrap().clone()
+  }
+
+  /// Sends a message to the panel over the current websocket.
+  pub fn send(&self, msg: WingsMessage) {
+    let current = self.inner.current.lock().unwrap();
+    let current = current.as_ref().expect("wings is not connected");
+    current.send(Command::Send(msg)).unwrap();
+  }
+
+  /// Closes the current websocket, like a restarting wings.
+  pub fn drop_connection(&self) {
+    if let Some(current) = self.inner.current.lock().unwrap().take() {
+      current.send(Command::Close).ok();
+    }
+  }
+
+  /// Waits for a message from the panel matching `predicate`, including ones
+  /// received before the call.
+  pub async fn wait_for_message(&self, predicate: impl Fn(&WingsMessage) -> bool) -> WingsMessage {
+    self
+      .wait_until(|| self.received().into_iter().find(|msg| predicate(msg)))
+      .await
+  }
+
+  /// Waits until `predicate` holds for everything received so far.
+  pub async fn wait_until_received(&self, predicate: impl Fn(&[WingsMessage]) -> bool) {
+    self
+      .wait_until(|| predicate(&self.received()).then_some(()))
+      .await
+  }
+
+  pub async fn wait_for_attempts(&self, attempts: usize) {
+    self
+      .wait_until(|| (self.attempts() >= attempts).then_some(()))
+      .await
+  }
+
+  pub async fn wait_for_connections(&self, connections: usize) {
+    self
+      .wait_until(|| (self.connections() >= connections).then_some(()))
+      .await
+  }
+
+  pub async fn wait_for_closed(&self) {
+    self.wait_until(|| (!self.is_open()).then_some(())).await
+  }
+
+  async fn wait_until<T>(&self, check: impl Fn() -> Option<T>) -> T {
+    for _ in 0..400 {
+      if let Some(res) = check() {
+        return res;
+      }
+      sleep(Duration::from_millis(25)).await;
+    }
+    panic!("fake wings did not reach the expected state in time");
+  }
+}
+
+fn default_responder(msg: &WingsMessage) -> Vec<WingsMessage> {
+  match msg {
+    WingsMessage::Hello => vec![WingsMessage::World],
+    WingsMessage::SyncConfig(_) => vec![WingsMessage::SyncReport(SyncReport::default())],
+    _ => Vec::new(),
+  }
+}
+
+async fn upgrade(
+  State(wings): State<FakeWings>,
+  headers: HeaderMap,
+  ws: WebSocketUpgrade,
+) -> Response {
+  wings.inner.attempts.fetch_add(1, Ordering::SeqCst);
+
+  let mut token = wings.inner.token.subscribe();
+  let Ok(token) = token.wait_for(Option::is_some).await else {
+    return StatusCode::SERVICE_UNAVAILABLE.into_response();
+  };
+  let token = token.clone().unwrap();
+
+  let handshake = *wings.inner.handshake.lock().unwrap();
+  if handshake == Handshake::Reject {
+    return StatusCode::UNAUTHORIZED.into_response();
+  }
+  let Ok(timestamp) = SignData::validate_header_map(&headers, &token, None) else {
+    return StatusCode::UNAUTHORIZED.into_response();
+  };
+
+  let key = match handshake {
+    Handshake::BadSignature => "not-the-token".to_string(),
+    _ => token,
+  };
+  let headers = SignData::from_timestamp(timestamp)
+    .to_header_map(&key)
+    .unwrap();
+
+  (headers, ws.on_upgrade(move |socket| serve(wings, socket))).into_response()
+}
+
+async fn serve(wings: FakeWings, socket: WebSocket) {
+  let (commands, mut pending) = mpsc::unbounded_channel();
+  *wings.inner.current.lock().unwrap() = Some(commands.clone());
+  wings.inner.connections.fetch_add(1, Ordering::SeqCst);
+
+  let (mut sender, mut receiver) = socket.split();
+  loop {
+    tokio::select! {
+      command = pending.recv() => match command {
+        Some(Command::Send(msg)) => {
+          let raw = serde_json::to_vec(&msg).unwrap();
+          if sender.send(Message::Binary(raw.into())).await.is_err() {
+            break;
+          }
+        }
+        Some(Command::Close) | None => {
+          sender.send(Message::Close(None)).await.ok();
+          break;
+        }
+      },
+      msg = receiver.next() => {
+        let Some(Ok(msg)) = msg else {
+          break;
+        };
+        let Message::Binary(raw) = msg else {
+          continue;
+        };
+        let Ok(msg) = serde_json::from_slice::<WingsMessage>(&raw) else {
+          continue;
+        };
+
+        let replies = (wings.inner.responder.lock().unwrap())(&msg);
+        wings.inner.received.lock().unwrap().push(msg);
+        for reply in replies {
+          commands.send(Command::Send(reply)).ok();
+        }
+      }
+    }
+  }
+
+  // a newer websocket may already have replaced this one
+  let mut current = wings.inner.current.lock().unwrap();
+  if current
+    .as_ref()
+    .is_some_and(|current| current.same_channel(&commands))
+  {
+    *current = None;
+  }
+}
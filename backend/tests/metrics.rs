@@ -0,0 +1,84 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{TestServer, unique, wings::FakeWings};
+use reqwest::StatusCode;
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn node_metrics_are_exported() {
+  // nextest runs every test in its own process, so this does not leak.
+  unsafe {
+    std::env::set_var("METRICS_ENABLED", "true");
+  }
+  let (server, _) = TestServer::start_with_admin().await;
+
+  let resp = server
+    .post(
+      "/nodes",
+      serde_json::json!({
+        "name": unique("node"),
+        "address": "127.0.0.1:1",
+        "secure": false,
+        "disk_limit_mb": null,
+        "memory_limit_mb": null,
+        "cpu_limit": null,
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let created: serde_json::Value = resp.json().await.unwrap();
+  let id = created["uuid"].as_str().unwrap().to_string();
+
+  let resp = server.get("/metrics").await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let body = resp.text().await.unwrap();
+  assert!(
+    body.contains("smaug_nodes{service_name=\"smaug\"} 1"),
+    "{body}"
+  );
+  assert!(
+    body.contains("smaug_wings_disconnected{service_name=\"smaug\"} 1"),
+    "{body}"
+  );
+
+  let resp = server
+    .delete("/nodes", serde_json::json!({ "uuid": id }))
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  let body = server.get("/metrics").await.text().await.unwrap();
+  assert!(
+    body.contains("smaug_nodes{service_name=\"smaug\"} 0"),
+    "{body}"
+  );
+  assert!(
+    body.contains("smaug_wings_disconnected{service_name=\"smaug\"} 0"),
+    "{body}"
+  );
+  // the connection state is only exported in aggregate, so deleted nodes do
+  // not show up as disconnected
+  assert!(!body.contains("wings_node_connected"), "{body}");
+}
+
+#[tokio::test]
+async fn sync_round_trip_is_recorded() {
+  unsafe {
+    std::env::set_var("METRICS_ENABLED", "true");
+  }
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+  let id = wings.attach(&server).await;
+
+  // the sync on connect is answered by the fake wings in the background
+  for _ in 0..100 {
+    let body = server.get("/metrics").await.text().await.unwrap();
+    if body.contains("smaug_wings_rpc_duration") {
+      assert!(body.contains(&format!("node=\"{id}\"")), "{body}");
+      return;
+    }
+    sleep(Duration::from_millis(50)).await;
+  }
+  panic!("sync round trip of {id} was not recorded");
+}
@@ -0,0 +1,718 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{CONNECT_TIMEOUT, TestServer, unique};
+use futures_util::SinkExt;
+use reqwest::StatusCode;
+use serde_json::Value;
+use shared::{
+  auth::{NODE_HEADER, SignData},
+  msg::{NodeStats, WingsMessage},
+};
+use tokio::{net::TcpStream, time::sleep};
+use tokio_tungstenite::{
+  MaybeTlsStream, WebSocketStream, connect_async,
+  tungstenite::{self, client::IntoClientRequest},
+};
+use uuid::Uuid;
+
+async fn create_node(server: &TestServer) -> String {
+  create_node_in(server, Value::Null, &[]).await
+}
+
+async fn create_node_in(server: &TestServer, location: Value, tags: &[&str]) -> String {
+  let resp = server
+    .post(
+      "/nodes",
+      serde_json::json!({
+        "name": unique("node"),
+        "address": "127.0.0.1:1",
+        "secure": false,
+        "disk_limit_mb": null,
+        "memory_limit_mb": null,
+        "cpu_limit": null,
+        "location": location,
+        "tags": tags,
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let created: Value = resp.json().await.unwrap();
+  created["uuid"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn node_maintenance_toggle() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let id = create_node(&server).await;
+
+  let resp = server.get(&format!("/nodes/{id}")).await;
+  let node: Value = resp.json().await.unwrap();
+  assert_eq!(node["maintenance"], false);
+
+  let resp = server
+    .post(
+      &format!("/nodes/{id}/maintenance"),
+      serde_json::json!({ "enabled": true, "message": "Kernel upgrade" }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  let resp = server.get(&format!("/nodes/{id}")).await;
+  let node: Value = resp.json().await.unwrap();
+  assert_eq!(node["maintenance"], true);
+  assert_eq!(node["maintenance_message"], "Kernel upgrade");
+
+  // Leaving maintenance clears the message.
+  let resp = server
+    .post(
+      &format!("/nodes/{id}/maintenance"),
+      serde_json::json!({ "enabled": false, "message": "Kernel upgrade" }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  let resp = server.get(&format!("/nodes/{id}")).await;
+  let node: Value = resp.json().await.unwrap();
+  assert_eq!(node["maintenance"], false);
+  assert!(node["maintenance_message"].is_null());
+}
+
+#[tokio::test]
+async fn node_maintenance_unknown_node() {
+  let (server, _) = TestServer::start_with_admin().await;
+
+  let resp = server
+    .post(
+      &format!("/nodes/{}/maintenance", Uuid::now_v7()),
+      serde_json::json!({ "enabled": true, "message": null }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+async fn create_location(server: &TestServer) -> String {
+  let resp = server
+    .post(
+      "/nodes/locations",
+      serde_json::json!({ "name": unique("location"), "description": null }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let created: Value = resp.json().await.unwrap();
+  created["uuid"].as_str().unwrap().to_string()
+}
+
+fn node_ids(nodes: &Value) -> Vec<&str> {
+  nodes
+    .as_array()
+    .unwrap()
+    .iter()
+    .map(|n| n["id"].as_str().unwrap())
+    .collect()
+}
+
+#[tokio::test]
+async fn list_nodes_filters_and_paginates() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let location = create_location(&server).await;
+  let tagged = create_node_in(
+    &server,
+    Value::from(location.clone()),
+    &[" ssd ", "eu", "ssd"],
+  )
+  .await;
+  let other = create_node(&server).await;
+
+  let resp = server.get(&format!("/nodes/{tagged}")).await;
+  let node: Value = resp.json().await.unwrap();
+  assert_eq!(node["location"], location.as_str());
+  assert_eq!(node["tags"], serde_json::json!(["eu", "ssd"]));
+
+  let resp = server.get("/nodes?tag=ssd").await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  assert_eq!(node_ids(&resp.json().await.unwrap()), vec![tagged.as_str()]);
+
+  let resp = server.get(&format!("/nodes?location={location}")).await;
+  assert_eq!(node_ids(&resp.json().await.unwrap()), vec![tagged.as_str()]);
+
+  let resp = server.get("/nodes?sort=name&order=desc&limit=1").await;
+  let nodes: Value = resp.json().await.unwrap();
+  assert_eq!(nodes.as_array().unwrap().len(), 1);
+  let resp = server.get("/nodes?sort=name&order=desc&offset=1").await;
+  let rest: Value = resp.json().await.unwrap();
+  assert_eq!(rest.as_array().unwrap().len(), 1);
+  assert_ne!(nodes[0]["id"], rest[0]["id"]);
+  assert!([tagged.as_str(), other.as_str()].contains(&rest[0]["id"].as_str().unwrap()));
+}
+
+#[tokio::test]
+async fn location_grant_scopes_node_view() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let location = create_location(&server).await;
+  let visible = create_node_in(&server, Value::from(location.clone()), &[]).await;
+  let hidden = create_node(&server).await;
+
+  let email = format!("{}@example.com", unique("viewer"));
+  let password = server.encrypt_password("viewerpass1").await;
+  let resp = server
+    .post(
+      "/user/management",
+      serde_json::json!({ "name": "Viewer", "email": email, "password": password }),
+    )
+    .await;
+  let created: Value = resp.json().await.unwrap();
+  let user_id = created["uuid"].as_str().unwrap().to_string();
+
+  let group_name = unique("viewers");
+  let resp = server
+    .post("/group", serde_json::json!({ "name": group_name }))
+    .await;
+  let created: Value = resp.json().await.unwrap();
+  let group_id = created["uuid"].as_str().unwrap().to_string();
+  let resp = server
+    .put(
+      "/group",
+      serde_json::json!({
+        "uuid": group_id,
+        "name": group_name,
+        "permissions": [],
+        "users": [user_id],
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  server.clear_cookies();
+  assert_eq!(
+    server.login(&email, "viewerpass1").await.status(),
+    StatusCode::OK
+  );
+  // Without a grant the user may not view nodes at all.
+  assert_eq!(server.get("/nodes").await.status(), StatusCode::FORBIDDEN);
+
+  server.clear_cookies();
+  server.login("admin@example.com", "hunter2pass").await;
+  let resp = server
+    .post(
+      &format!("/nodes/locations/{location}/groups"),
+      serde_json::json!({ "groups": [group_id] }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  server.clear_cookies();
+  server.login(&email, "viewerpass1").await;
+  let resp = server.get("/nodes").await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let nodes: Value = resp.json().await.unwrap();
+  assert_eq!(node_ids(&nodes), vec![visible.as_str()]);
+  // the token is the secret of the wings channel, viewers must not see it
+  assert!(nodes[0]["token"].is_null());
+  assert_eq!(
+    server.get(&format!("/nodes/{hidden}")).await.status(),
+    StatusCode::NOT_FOUND
+  );
+  // diagnosing signs with the token, so viewing is not enough
+  assert_eq!(
+    server
+      .post(&format!("/nodes/{visible}/diagnose"), Value::Null)
+      .await
+      .status(),
+    StatusCode::FORBIDDEN
+  );
+}
+
+#[tokio::test]
+async fn update_without_optional_fields_keeps_them() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let location = create_location(&server).await;
+  let id = create_node_in(&server, Value::from(location.clone()), &["gpu"]).await;
+  let node: Value = server
+    .get(&format!("/nodes/{id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  let resp = server
+    .post(
+      &format!("/nodes/{id}"),
+      serde_json::json!({
+        "name": node["name"],
+        "address": "127.0.0.1:1",
+        "secure": false,
+        "disk_limit_mb": null,
+        "memory_limit_mb": null,
+        "cpu_limit": null,
+        "reverse_connect": true,
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  // the body the settings page sends
+  let resp = server
+    .post(
+      &format!("/nodes/{id}"),
+      serde_json::json!({
+        "name": unique("renamed"),
+        "address": "127.0.0.1:1",
+        "secure": false,
+        "disk_limit_mb": null,
+        "memory_limit_mb": 1024.0,
+        "cpu_limit": null,
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  let node: Value = server
+    .get(&format!("/nodes/{id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  assert_eq!(node["memory_limit_mb"], 1024.0);
+  assert_eq!(node["location"], location.as_str());
+  assert_eq!(node["tags"], serde_json::json!(["gpu"]));
+  assert_eq!(node["reverse_connect"], true);
+
+  // an explicit null still clears the location
+  let resp = server
+    .post(
+      &format!("/nodes/{id}"),
+      serde_json::json!({
+        "name": node["name"],
+        "address": "127.0.0.1:1",
+        "secure": false,
+        "disk_limit_mb": null,
+        "memory_limit_mb": null,
+        "cpu_limit": null,
+        "location": null,
+        "tags": [],
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  let node: Value = server
+    .get(&format!("/nodes/{id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  assert!(node["location"].is_null());
+  assert_eq!(node["tags"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn node_history_defaults_to_raw_samples() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let id = create_node(&server).await;
+
+  let resp = server.get(&format!("/nodes/{id}/history")).await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let history: Value = resp.json().await.unwrap();
+  assert_eq!(history["resolution"], "raw");
+  assert!(history["samples"].as_array().unwrap().is_empty());
+
+  let resp = server
+    .get(&format!(
+      "/nodes/{id}/history?from=2026-01-01T00:00:00Z&to=2025-01-01T00:00:00Z"
+    ))
+    .await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+  let resp = server
+    .get(&format!("/nodes/{}/history", Uuid::now_v7()))
+    .await;
+  assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn retention_settings_are_validated() {
+  let (server, _) = TestServer::start_with_admin().await;
+
+  let resp = server.get("/settings/retention").await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let settings: Value = resp.json().await.unwrap();
+  assert_eq!(settings["raw_minutes"], 60);
+
+  // Raw samples may not outlive the minute averages built from them.
+  let resp = server
+    .post(
+      "/settings/retention",
+      serde_json::json!({ "raw_minutes": 120, "minute_hours": 1, "quarter_hour_days": 30 }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+  let resp = server
+    .post(
+      "/settings/retention",
+      serde_json::json!({ "raw_minutes": 30, "minute_hours": 12, "quarter_hour_days": 7 }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  let resp = server.get("/settings/retention").await;
+  let settings: Value = resp.json().await.unwrap();
+  assert_eq!(settings["quarter_hour_days"], 7);
+}
+
+#[tokio::test]
+async fn node_enrollment_code_is_single_use() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let node_id = create_node(&server).await;
+  let node: Value = server
+    .get(&format!("/nodes/{node_id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  assert_eq!(node["lifecycle"], "pending_setup");
+
+  let resp = server
+    .post(&format!("/nodes/{node_id}/enrollment"), Value::Null)
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let enrollment: Value = resp.json().await.unwrap();
+  let code = enrollment["code"].as_str().unwrap().to_string();
+
+  let enroll = |code: String, key: &str| {
+    let headers = SignData::new().to_header_map(key).unwrap();
+    reqwest::Client::new()
+      .post(server.url("/wings/enroll"))
+      .headers(headers)
+      .json(&serde_json::json!({ "code": code }))
+      .send()
+  };
+
+  // A request not signed with the code must not consume it.
+  let resp = enroll(code.clone(), "wrong-code").await.unwrap();
+  assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+  let resp = enroll(code.clone(), &code).await.unwrap();
+  assert_eq!(resp.status(), StatusCode::OK);
+  SignData::validate_header_map(resp.headers(), &code, None).unwrap();
+  let enrolled: Value = resp.json().await.unwrap();
+  assert_eq!(enrolled["node"], node_id);
+
+  let node: Value = server
+    .get(&format!("/nodes/{node_id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  assert_eq!(enrolled["token"], node["token"]);
+  assert_eq!(node["lifecycle"], "enrolled");
+  assert!(node["first_connected_at"].is_null());
+
+  let resp = enroll(code.clone(), &code).await.unwrap();
+  assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+  // Issuing a new code invalidates the pending one.
+  let first: Value = server
+    .post(&format!("/nodes/{node_id}/enrollment"), Value::Null)
+    .await
+    .json()
+    .await
+    .unwrap();
+  let first = first["code"].as_str().unwrap().to_string();
+  let resp = server
+    .post(&format!("/nodes/{node_id}/enrollment"), Value::Null)
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let resp = enroll(first.clone(), &first).await.unwrap();
+  assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+  let resp = server
+    .post(
+      &format!("/nodes/{}/enrollment", Uuid::now_v7()),
+      Value::Null,
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+/// Opens the websocket to the panel the way wings does in reverse connect mode.
+async fn dial_panel(
+  server: &TestServer,
+  id: &str,
+) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, tungstenite::Error> {
+  let node: Value = server
+    .get(&format!("/nodes/{id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  let token = node["token"].as_str().unwrap();
+
+  let data = SignData::new();
+  let (stream, res) = dial_panel_with(server, id, data.to_header_map(token).unwrap()).await?;
+  SignData::validate_header_map(res.headers(), token, Some(data)).unwrap();
+  Ok(stream)
+}
+
+async fn dial_panel_with(
+  server: &TestServer,
+  id: &str,
+  mut headers: http::HeaderMap,
+) -> Result<
+  (
+    WebSocketStream<MaybeTlsStream<TcpStream>>,
+    tungstenite::handshake::client::Response,
+  ),
+  tungstenite::Error,
+> {
+  let mut request = server
+    .url("/wings/connect")
+    .replacen("http", "ws", 1)
+    .into_client_request()
+    .unwrap();
+  headers.insert(NODE_HEADER, id.parse().unwrap());
+  request.headers_mut().extend(headers);
+
+  connect_async(request).await
+}
+
+#[tokio::test]
+async fn reverse_connect_node_accepts_wings_websocket() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let direct = create_node(&server).await;
+  let resp = server
+    .post(
+      "/nodes",
+      serde_json::json!({
+        "name": unique("node"),
+        "address": "127.0.0.1:1",
+        "secure": false,
+        "disk_limit_mb": null,
+        "memory_limit_mb": null,
+        "cpu_limit": null,
+        "reverse_connect": true,
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let created: Value = resp.json().await.unwrap();
+  let id = created["uuid"].as_str().unwrap().to_string();
+
+  // The panel dials this node itself, so it may not open a websocket.
+  assert!(dial_panel(&server, &direct).await.is_err());
+
+  let mut stream = dial_panel(&server, &id).await.unwrap();
+  server.wait_for_connected(&id, true, CONNECT_TIMEOUT).await;
+
+  let stats = WingsMessage::NodeStats(NodeStats {
+    cpu_percent: 12.5,
+    memory_used_mb: 512.0,
+    memory_total_mb: 1024.0,
+    disk_used_mb: 10.0,
+    disk_total_mb: 100.0,
+  });
+  stream
+    .send(tungstenite::Message::Binary(
+      serde_json::to_vec(&stats).unwrap().into(),
+    ))
+    .await
+    .unwrap();
+
+  let mut samples = Vec::new();
+  for _ in 0..100 {
+    let history: Value = server
+      .get(&format!("/nodes/{id}/history"))
+      .await
+      .json()
+      .await
+      .unwrap();
+    samples = history["samples"].as_array().unwrap().clone();
+    if !samples.is_empty() {
+      break;
+    }
+    sleep(Duration::from_millis(50)).await;
+  }
+  assert_eq!(samples.len(), 1);
+
+  stream.close(None).await.unwrap();
+  server.wait_for_connected(&id, false, CONNECT_TIMEOUT).await;
+}
+
+fn assert_unauthorized<T>(res: Result<T, tungstenite::Error>) {
+  match res {
+    Err(tungstenite::Error::Http(resp)) => assert_eq!(resp.status(), StatusCode::UNAUTHORIZED),
+    Err(err) => panic!("unexpected error: {err}"),
+    Ok(_) => panic!("websocket was accepted"),
+  }
+}
+
+#[tokio::test]
+async fn reverse_connect_rejects_replayed_and_stale_signatures() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let resp = server
+    .post(
+      "/nodes",
+      serde_json::json!({
+        "name": unique("node"),
+        "address": "127.0.0.1:1",
+        "secure": false,
+        "disk_limit_mb": null,
+        "memory_limit_mb": null,
+        "cpu_limit": null,
+        "reverse_connect": true,
+      }),
+    )
+    .await;
+  let created: Value = resp.json().await.unwrap();
+  let id = created["uuid"].as_str().unwrap().to_string();
+  let node: Value = server
+    .get(&format!("/nodes/{id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  let token = node["token"].as_str().unwrap();
+
+  let headers = SignData::new().to_header_map(token).unwrap();
+  let (stream, _) = dial_panel_with(&server, &id, headers.clone())
+    .await
+    .unwrap();
+  drop(stream);
+
+  // captured headers must not open the websocket a second time
+  assert_unauthorized(dial_panel_with(&server, &id, headers).await);
+
+  let stale = (chrono::Utc::now() - chrono::TimeDelta::minutes(5))
+    .timestamp_millis()
+    .to_string();
+  let headers = SignData::from_timestamp(stale)
+    .to_header_map(token)
+    .unwrap();
+  assert_unauthorized(dial_panel_with(&server, &id, headers).await);
+}
+
+#[tokio::test]
+async fn node_port_above_smallint_range_round_trips() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let resp = server
+    .post(
+      "/nodes",
+      serde_json::json!({
+        "name": unique("node"),
+        "address": "127.0.0.1:40000",
+        "secure": false,
+        "disk_limit_mb": null,
+        "memory_limit_mb": null,
+        "cpu_limit": null,
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let created: Value = resp.json().await.unwrap();
+  let id = created["uuid"].as_str().unwrap();
+
+  let node: Value = server
+    .get(&format!("/nodes/{id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  assert_eq!(node["port"], 40000);
+
+  let nodes: Value = server.get("/nodes").await.json().await.unwrap();
+  let listed = nodes
+    .as_array()
+    .unwrap()
+    .iter()
+    .find(|node| node["id"] == id)
+    .unwrap();
+  assert_eq!(listed["port"], 40000);
+}
+
+#[tokio::test]
+async fn node_tls_pin_is_validated_and_normalized() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let create = |pin: Value, ca: Value| {
+    server.post(
+      "/nodes",
+      serde_json::json!({
+        "name": unique("node"),
+        "address": "127.0.0.1:1",
+        "secure": true,
+        "disk_limit_mb": null,
+        "memory_limit_mb": null,
+        "cpu_limit": null,
+        "tls_ca": ca,
+        "tls_pin": pin,
+      }),
+    )
+  };
+
+  let resp = create(
+    serde_json::json!({ "type": "certificate", "sha256": "abcd" }),
+    Value::Null,
+  )
+  .await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+  let resp = create(Value::Null, serde_json::json!("not a certificate")).await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+  let fingerprint = ["AB"; 32].join(":");
+  let resp = create(
+    serde_json::json!({ "type": "spki", "sha256": fingerprint }),
+    Value::Null,
+  )
+  .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let created: Value = resp.json().await.unwrap();
+  let id = created["uuid"].as_str().unwrap();
+
+  let info: Value = server
+    .get(&format!("/nodes/{id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  assert_eq!(info["tls_pin"]["type"], "spki");
+  assert_eq!(info["tls_pin"]["sha256"], "ab".repeat(32));
+  assert!(info["tls_ca"].is_null());
+
+  // saving the settings page must not drop the pin
+  let path = format!("/nodes/{id}");
+  let update = |extra: Value| {
+    let mut body = serde_json::json!({
+      "name": info["name"],
+      "address": "127.0.0.1:1",
+      "secure": true,
+      "disk_limit_mb": null,
+      "memory_limit_mb": null,
+      "cpu_limit": null,
+    });
+    body
+      .as_object_mut()
+      .unwrap()
+      .extend(extra.as_object().unwrap().clone());
+    server.post(&path, body)
+  };
+  let resp = update(serde_json::json!({})).await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let node: Value = server
+    .get(&format!("/nodes/{id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  assert_eq!(node["tls_pin"], info["tls_pin"]);
+
+  let resp = update(serde_json::json!({ "tls_pin": null })).await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let node: Value = server
+    .get(&format!("/nodes/{id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  assert!(node["tls_pin"].is_null());
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use common::{TestServer, unique};
+use reqwest::StatusCode;
+use serde_json::Value;
+use shared::auth::{NODE_HEADER, SignData};
+
+const KEY: &str =
+  "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIC1VNbd43EwaRSgH4ti0Y8sgR4H4qwEnr5WW7nx4mPyB me@host";
+const FINGERPRINT: &str = "SHA256:CpL150WkK1ZbuAIzyrz6YxkYhBsIe7jONkBKnct5rhM";
+
+#[tokio::test]
+async fn ssh_key_add_list_delete() {
+  let (server, _) = TestServer::start_with_admin().await;
+
+  let resp = server
+    .post("/user/ssh_keys", serde_json::json!({ "key": KEY }))
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let created: Value = resp.json().await.unwrap();
+  assert_eq!(created["fingerprint"], FINGERPRINT);
+
+  // The same key can only be registered once.
+  let resp = server
+    .post(
+      "/user/ssh_keys",
+      serde_json::json!({ "name": "laptop", "key": KEY }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+  let resp = server.get("/user/ssh_keys").await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let keys: Value = resp.json().await.unwrap();
+  assert_eq!(keys.as_array().unwrap().len(), 1);
+  // Without a name the comment of the key is used.
+  assert_eq!(keys[0]["name"], "me@host");
+  assert_eq!(keys[0]["key_type"], "ssh-ed25519");
+
+  let resp = server
+    .delete(
+      "/user/ssh_keys",
+      serde_json::json!({ "uuid": created["uuid"] }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  let resp = server.get("/user/ssh_keys").await;
+  let keys: Value = resp.json().await.unwrap();
+  assert!(keys.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn ssh_key_rejects_unsupported_keys() {
+  let (server, _) = TestServer::start_with_admin().await;
+
+  let resp = server
+    .post(
+      "/user/ssh_keys",
+      serde_json::json!({ "name": "old", "key": "ssh-dss AAAAB3NzaC1kc3M=" }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn wings_lookup_requires_node_signature() {
+  let (server, admin_id) = TestServer::start_with_admin().await;
+  server
+    .post("/user/ssh_keys", serde_json::json!({ "key": KEY }))
+    .await;
+
+  let resp = server
+    .post(
+      "/nodes",
+      serde_json::json!({
+        "name": unique("node"),
+        "address": "127.0.0.1:1",
+        "secure": false,
+        "disk_limit_mb": null,
+        "memory_limit_mb": null,
+        "cpu_limit": null,
+      }),
+    )
+    .await;
+  let created: Value = resp.json().await.unwrap();
+  let node_id = created["uuid"].as_str().unwrap().to_string();
+  let node: Value = server
+    .get(&format!("/nodes/{node_id}"))
+    .await
+    .json()
+    .await
+    .unwrap();
+  let token = node["token"].as_str().unwrap();
+
+  let body = serde_json::json!({ "fingerprint": FINGERPRINT }).to_string();
+  let lookup = |token: &str, signed: &str, body: &str| {
+    let mut headers = SignData::new()
+      .with_body(signed.as_bytes())
+      .to_header_map(token)
+      .unwrap();
+    headers.insert(NODE_HEADER, node_id.parse().unwrap());
+    reqwest::Client::new()
+      .post(server.url("/wings/ssh_keys/lookup"))
+      .headers(headers)
+      .header(http::header::CONTENT_TYPE, "application/json")
+      .body(body.to_string())
+      .send()
+  };
+
+  let resp = lookup("wrong-token", &body, &body).await.unwrap();
+  assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+  // the signature covers the body, so it can not be swapped
+  let other = serde_json::json!({ "fingerprint": "SHA256:other" }).to_string();
+  let resp = lookup(token, &other, &body).await.unwrap();
+  assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+  let resp = lookup(token, &body, &body).await.unwrap();
+  assert_eq!(resp.status(), StatusCode::OK);
+  SignData::validate_header_map(resp.headers(), token, None).unwrap();
+  let owner: Value = resp.json().await.unwrap();
+  assert_eq!(owner["user"], admin_id.to_string());
+  assert_eq!(owner["user_name"], "admin");
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{TestServer, receiver::webhook_receiver, unique};
+use reqwest::StatusCode;
+use serde_json::Value;
+use tokio::time::sleep;
+
+async fn create_webhook(server: &TestServer, url: &str, events: Value) -> reqwest::Response {
+  server
+    .post(
+      "/webhooks",
+      serde_json::json!({ "name": unique("hook"), "url": url, "events": events }),
+    )
+    .await
+}
+
+async fn wait_for_deliveries(server: &TestServer, hook: &str) -> Value {
+  for _ in 0..100 {
+    let resp = server.get(&format!("/webhooks/{hook}/deliveries")).await;
+    let deliveries: Value = resp.json().await.unwrap();
+    if deliveries
+      .as_array()
+      .unwrap()
+      .iter()
+      .any(|d| d["attempts"].as_i64().unwrap() > 0)
+    {
+      return deliveries;
+    }
+    sleep(Duration::from_millis(50)).await;
+  }
+  panic!("webhook was not delivered in time");
+}
+
+#[tokio::test]
+async fn webhook_receives_signed_node_events() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let (url, received) = webhook_receiver().await;
+
+  let resp = create_webhook(&server, &url, serde_json::json!(["node.created"])).await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let created: Value = resp.json().await.unwrap();
+  let hook = created["uuid"].as_str().unwrap();
+  let secret = created["secret"].as_str().unwrap();
+
+  let resp = server
+    .post(
+      "/nodes",
+      serde_json::json!({
+        "name": "node",
+        "address": "127.0.0.1:1",
+        "secure": false,
+        "disk_limit_mb": null,
+        "memory_limit_mb": null,
+        "cpu_limit": null,
+      }),
+    )
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let node: Value = resp.json().await.unwrap();
+
+  let deliveries = wait_for_deliveries(&server, hook).await;
+  assert_eq!(deliveries.as_array().unwrap().len(), 1);
+  assert_eq!(deliveries[0]["event"], "node.created");
+  assert_eq!(deliveries[0]["delivered"], true);
+  assert_eq!(deliveries[0]["status_code"], 200);
+
+  let received = received.lock().unwrap();
+  assert_eq!(received.len(), 1);
+  let (headers, body) = &received[0];
+  assert_eq!(headers["x-smaug-event"], "node.created");
+
+  let timestamp = headers["x-smaug-timestamp"].to_str().unwrap();
+  let signature = shared::auth::hmac(&format!("{timestamp}.{body}"), secret).unwrap();
+  assert_eq!(headers["x-smaug-signature"], signature.as_str());
+
+  let payload: Value = serde_json::from_str(body).unwrap();
+  assert_eq!(payload["event"], "node.created");
+  assert_eq!(payload["data"]["node"], node["uuid"]);
+}
+
+#[tokio::test]
+async fn webhook_validation_and_failed_ping() {
+  let (server, _) = TestServer::start_with_admin().await;
+
+  let resp = create_webhook(
+    &server,
+    "ftp://example.com/hook",
+    serde_json::json!(["node.created"]),
+  )
+  .await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+  let resp = create_webhook(&server, "http://127.0.0.1:1/hook", serde_json::json!([])).await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+  let resp = create_webhook(
+    &server,
+    "http://127.0.0.1:1/hook",
+    serde_json::json!(["ping"]),
+  )
+  .await;
+  assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+  let resp = create_webhook(
+    &server,
+    "http://127.0.0.1:1/hook",
+    serde_json::json!(["node.deleted", "node.created", "node.deleted"]),
+  )
+  .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let created: Value = resp.json().await.unwrap();
+  let hook = created["uuid"].as_str().unwrap();
+
+  let resp = server.get("/webhooks").await;
+  let hooks: Value = resp.json().await.unwrap();
+  assert_eq!(
+    hooks[0]["events"],
+    serde_json::json!(["node.created", "node.deleted"])
+  );
+  assert!(hooks[0].get("secret").is_none());
+
+  // Nothing listens on the port, so the first attempt fails and is logged.
+  let resp = server
+    .post(&format!("/webhooks/{hook}/ping"), Value::Null)
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  let deliveries = wait_for_deliveries(&server, hook).await;
+  assert_eq!(deliveries[0]["event"], "ping");
+  assert_eq!(deliveries[0]["delivered"], false);
+  assert_eq!(deliveries[0]["attempts"], 1);
+  assert!(deliveries[0]["error"].is_string());
+}
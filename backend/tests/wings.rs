@@ -0,0 +1,449 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{
+  CONNECT_TIMEOUT, TestServer, unique,
+  wings::{FakeWings, Handshake},
+};
+use reqwest::StatusCode;
+use serde_json::Value;
+use shared::{
+  auth::SignData,
+  msg::{Drift, NodeConfig, NodeStats, SyncReport, WingsMessage},
+};
+use tokio::time::sleep;
+use uuid::Uuid;
+
+async fn wait_for_lifecycle(server: &TestServer, id: &str, lifecycle: &str) -> Value {
+  for _ in 0..200 {
+    let node = server.node(id).await;
+    if node["lifecycle"] == lifecycle {
+      return node;
+    }
+    sleep(Duration::from_millis(50)).await;
+  }
+  panic!("node lifecycle did not become {lifecycle}");
+}
+
+/// Redeems an enrollment code like wings does on its first start.
+async fn enroll(server: &TestServer, id: &str) {
+  let enrollment: Value = server
+    .post(&format!("/nodes/{id}/enrollment"), Value::Null)
+    .await
+    .json()
+    .await
+    .unwrap();
+  let code = enrollment["code"].as_str().unwrap();
+
+  let resp = reqwest::Client::new()
+    .post(server.url("/wings/enroll"))
+    .headers(SignData::new().to_header_map(code).unwrap())
+    .json(&serde_json::json!({ "code": code }))
+    .send()
+    .await
+    .unwrap();
+  assert_eq!(resp.status(), StatusCode::OK);
+}
+
+fn is_sync_config(msg: &WingsMessage) -> bool {
+  matches!(msg, WingsMessage::SyncConfig(_))
+}
+
+#[tokio::test]
+async fn panel_connects_to_wings_and_syncs_config() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+
+  let id = wings.attach(&server).await;
+  server.wait_for_connected(&id, true, CONNECT_TIMEOUT).await;
+  assert_eq!(wings.connections(), 1);
+
+  let msg = wings.wait_for_message(is_sync_config).await;
+  assert_eq!(msg, WingsMessage::SyncConfig(NodeConfig::default()));
+  let node = server.wait_for_sync_status(&id, "synced").await;
+  assert!(node["sync"]["drift"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn drift_reported_by_wings_is_recorded() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+  // answered by hand, so the sync stays pending until then
+  wings.respond_with(|_| Vec::new());
+
+  let id = wings.attach(&server).await;
+  let WingsMessage::SyncConfig(desired) = wings.wait_for_message(is_sync_config).await else {
+    unreachable!();
+  };
+  server.wait_for_sync_status(&id, "pending").await;
+
+  let actual = NodeConfig {
+    cpu_limit: Some(2),
+    ..desired.clone()
+  };
+  wings.send(WingsMessage::SyncReport(SyncReport {
+    drift: actual.drift(&desired),
+  }));
+  let node = server.wait_for_sync_status(&id, "synced").await;
+  let drift: Vec<Drift> = serde_json::from_value(node["sync"]["drift"].clone()).unwrap();
+  assert_eq!(
+    drift,
+    vec![Drift {
+      field: "cpu_limit".to_string(),
+      desired: None,
+      actual: Some(2.0),
+    }]
+  );
+}
+
+#[tokio::test]
+async fn stats_from_wings_are_stored() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+  let id = wings.attach(&server).await;
+  let connected = wait_for_lifecycle(&server, &id, "connected").await;
+
+  wings.send(WingsMessage::NodeStats(NodeStats {
+    cpu_percent: 50.0,
+    memory_used_mb: 256.0,
+    memory_total_mb: 1024.0,
+    disk_used_mb: 10.0,
+    disk_total_mb: 100.0,
+  }));
+
+  for _ in 0..100 {
+    let history: Value = server
+      .get(&format!("/nodes/{id}/history"))
+      .await
+      .json()
+      .await
+      .unwrap();
+    let samples = history["samples"].as_array().unwrap();
+    if !samples.is_empty() {
+      assert_eq!(samples.len(), 1);
+      // seen on connect just now, too recent to be written again
+      let node: Value = server
+        .get(&format!("/nodes/{id}"))
+        .await
+        .json()
+        .await
+        .unwrap();
+      assert_eq!(node["last_seen_at"], connected["last_seen_at"]);
+      return;
+    }
+    sleep(Duration::from_millis(50)).await;
+  }
+  panic!("stats were not stored");
+}
+
+#[tokio::test]
+async fn limit_changes_are_pushed_over_the_open_connection() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+  let id = wings.attach(&server).await;
+  wings.wait_for_message(is_sync_config).await;
+
+  let update = serde_json::json!({
+    "name": unique("node"),
+    "address": wings.address(),
+    "secure": false,
+    "disk_limit_mb": null,
+    "memory_limit_mb": 2048.0,
+    "cpu_limit": null,
+  });
+  let resp = server.post(&format!("/nodes/{id}"), update).await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  wings
+    .wait_for_message(|msg| {
+      *msg
+        == WingsMessage::SyncConfig(NodeConfig {
+          memory_limit_mb: Some(2048.0),
+          ..Default::default()
+        })
+    })
+    .await;
+  assert_eq!(wings.connections(), 1);
+  assert!(wings.is_open());
+}
+
+#[tokio::test]
+async fn address_change_moves_the_connection() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let old = FakeWings::start().await;
+  let id = old.attach(&server).await;
+  old.wait_for_connections(1).await;
+
+  let new = FakeWings::start().await;
+  new.set_token(server.node(&id).await["token"].as_str().unwrap());
+
+  let update = serde_json::json!({
+    "name": unique("node"),
+    "address": new.address(),
+    "secure": false,
+    "disk_limit_mb": null,
+    "memory_limit_mb": null,
+    "cpu_limit": null,
+  });
+  let resp = server.post(&format!("/nodes/{id}"), update).await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  new.wait_for_connections(1).await;
+  old.wait_for_closed().await;
+  server.wait_for_connected(&id, true, CONNECT_TIMEOUT).await;
+}
+
+#[tokio::test]
+async fn deleting_node_closes_wings_connection() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+  let id = wings.attach(&server).await;
+  wings.wait_for_connections(1).await;
+
+  let resp = server
+    .delete("/nodes", serde_json::json!({ "uuid": id }))
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+
+  wings.wait_for_closed().await;
+  let resp = server.get(&format!("/nodes/{id}")).await;
+  assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn panel_reconnects_after_wings_drops_the_connection() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+  let id = wings.attach(&server).await;
+  server.wait_for_connected(&id, true, CONNECT_TIMEOUT).await;
+
+  // the panel redials right away, so the node is hardly ever seen offline
+  wings.drop_connection();
+  wings.wait_for_connections(2).await;
+  server.wait_for_connected(&id, true, CONNECT_TIMEOUT).await;
+  // every connect pushes the configuration again
+  wings
+    .wait_until_received(|received| received.iter().filter(|msg| is_sync_config(msg)).count() >= 2)
+    .await;
+}
+
+#[tokio::test]
+async fn failed_handshakes_keep_node_disconnected() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+  wings.set_handshake(Handshake::BadSignature);
+  let id = wings.attach(&server).await;
+
+  // the websocket is upgraded, but the panel rejects the signature and drops it
+  wings.wait_for_connections(1).await;
+  wings.wait_for_closed().await;
+  assert_eq!(server.node(&id).await["connected"], false);
+  assert!(!wings.received().iter().any(is_sync_config));
+
+  // pending nodes are retried slowly, enrolling dials right away
+  wings.set_handshake(Handshake::Accept);
+  enroll(&server, &id).await;
+  wings.wait_for_connections(2).await;
+  server.wait_for_connected(&id, true, CONNECT_TIMEOUT).await;
+}
+
+#[tokio::test]
+async fn rejected_handshakes_are_retried_once_enrolled() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+  wings.set_handshake(Handshake::Reject);
+  let id = wings.attach(&server).await;
+
+  wings.wait_for_attempts(1).await;
+  assert_eq!(wings.connections(), 0);
+  assert_eq!(server.node(&id).await["connected"], false);
+
+  wings.set_handshake(Handshake::Accept);
+  enroll(&server, &id).await;
+  wings.wait_for_connections(1).await;
+  assert!(wings.attempts() >= 2);
+  server.wait_for_connected(&id, true, CONNECT_TIMEOUT).await;
+}
+
+async fn test_connection(server: &TestServer, body: Value) -> Value {
+  let resp = server.post("/nodes/test-connection", body).await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  resp.json().await.unwrap()
+}
+
+fn statuses(diagnosis: &Value) -> Vec<(String, String)> {
+  diagnosis["steps"]
+    .as_array()
+    .unwrap()
+    .iter()
+    .map(|step| {
+      (
+        step["step"].as_str().unwrap().to_string(),
+        step["status"].as_str().unwrap().to_string(),
+      )
+    })
+    .collect()
+}
+
+fn expected(steps: &[(&str, &str)]) -> Vec<(String, String)> {
+  steps
+    .iter()
+    .map(|(step, status)| (step.to_string(), status.to_string()))
+    .collect()
+}
+
+#[tokio::test]
+async fn test_connection_runs_every_step() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+  wings.set_token("secret");
+
+  let diagnosis = test_connection(
+    &server,
+    serde_json::json!({ "address": wings.address(), "secure": false, "token": "secret" }),
+  )
+  .await;
+  assert_eq!(diagnosis["ok"], true);
+  assert!(diagnosis["failed_step"].is_null());
+  assert_eq!(
+    statuses(&diagnosis),
+    expected(&[
+      ("dns", "ok"),
+      ("tcp", "ok"),
+      ("tls", "skipped"),
+      ("upgrade", "ok"),
+      ("signature", "ok"),
+    ])
+  );
+
+  // nothing is stored
+  let nodes: Value = server.get("/nodes").await.json().await.unwrap();
+  assert!(nodes.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_connection_reports_the_failed_step() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+  wings.set_token("secret");
+
+  let diagnosis = test_connection(
+    &server,
+    serde_json::json!({ "address": wings.address(), "secure": false, "token": "other" }),
+  )
+  .await;
+  assert_eq!(diagnosis["ok"], false);
+  assert_eq!(diagnosis["failed_step"], "upgrade");
+  assert!(
+    diagnosis["steps"][3]["error"]
+      .as_str()
+      .unwrap()
+      .contains("401")
+  );
+  assert_eq!(diagnosis["steps"][4]["status"], "skipped");
+
+  wings.set_handshake(Handshake::BadSignature);
+  let diagnosis = test_connection(
+    &server,
+    serde_json::json!({ "address": wings.address(), "secure": false, "token": "secret" }),
+  )
+  .await;
+  assert_eq!(diagnosis["failed_step"], "signature");
+
+  // wings does not speak TLS
+  let diagnosis = test_connection(
+    &server,
+    serde_json::json!({ "address": wings.address(), "secure": true, "token": "secret" }),
+  )
+  .await;
+  assert_eq!(diagnosis["failed_step"], "tls");
+
+  let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+  let address = closed.local_addr().unwrap().to_string();
+  drop(closed);
+  let diagnosis = test_connection(
+    &server,
+    serde_json::json!({ "address": address, "secure": false }),
+  )
+  .await;
+  assert_eq!(diagnosis["failed_step"], "tcp");
+  assert!(diagnosis["steps"][1]["error"].is_string());
+
+  let diagnosis = test_connection(
+    &server,
+    serde_json::json!({ "address": "node.invalid:8000", "secure": false }),
+  )
+  .await;
+  assert_eq!(diagnosis["failed_step"], "dns");
+  assert_eq!(
+    statuses(&diagnosis),
+    expected(&[
+      ("dns", "failed"),
+      ("tcp", "skipped"),
+      ("tls", "skipped"),
+      ("upgrade", "skipped"),
+      ("signature", "skipped"),
+    ])
+  );
+}
+
+#[tokio::test]
+async fn test_connection_without_token_checks_the_network_only() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+
+  let diagnosis = test_connection(
+    &server,
+    serde_json::json!({ "address": wings.address(), "secure": false }),
+  )
+  .await;
+  assert_eq!(diagnosis["ok"], true);
+  assert_eq!(diagnosis["steps"][3]["status"], "skipped");
+  assert_eq!(wings.attempts(), 0);
+}
+
+#[tokio::test]
+async fn diagnose_dials_an_existing_node() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+  let id = wings.attach(&server).await;
+  wings.wait_for_connections(1).await;
+
+  let resp = server
+    .post(&format!("/nodes/{id}/diagnose"), Value::Null)
+    .await;
+  assert_eq!(resp.status(), StatusCode::OK);
+  let diagnosis: Value = resp.json().await.unwrap();
+  assert_eq!(diagnosis["ok"], true);
+  // on a connection of its own
+  wings.wait_for_connections(2).await;
+  server.wait_for_connected(&id, true, CONNECT_TIMEOUT).await;
+
+  let resp = server
+    .post(&format!("/nodes/{}/diagnose", Uuid::now_v7()), Value::Null)
+    .await;
+  assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn lifecycle_follows_the_connection() {
+  let (server, _) = TestServer::start_with_admin().await;
+  let wings = FakeWings::start().await;
+  let id = wings.attach(&server).await;
+
+  let connected = wait_for_lifecycle(&server, &id, "connected").await;
+  let first_connected_at = connected["first_connected_at"].clone();
+  assert!(first_connected_at.is_string());
+  assert!(connected["last_seen_at"].is_string());
+
+  // keeps the node offline until wings accepts again
+  wings.set_handshake(Handshake::Reject);
+  wings.drop_connection();
+  wait_for_lifecycle(&server, &id, "lost").await;
+  server.wait_for_connected(&id, false, CONNECT_TIMEOUT).await;
+
+  wings.set_handshake(Handshake::Accept);
+  let reconnected = wait_for_lifecycle(&server, &id, "connected").await;
+  assert_eq!(reconnected["first_connected_at"], first_connected_at);
+}
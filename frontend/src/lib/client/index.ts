@@ -1,6 +1,8 @@
 // This file is auto-generated by @hey-api/openapi-ts
 
 export {
+  addSshKey,
+  apiKeyUsage,
   authConfig,
   authenticate,
   avatarById,
@@ -8,49 +10,98 @@ export {
   completeSetup,
   confirmEmailChange,
   convertOidcUser,
+  createAlertChannel,
+  createAlertRule,
+  createApiKey,
   createGroup,
+  createLocation,
   createNode,
+  createNodeEnrollment,
   createUser,
+  createWebhook,
+  deleteAlertChannel,
+  deleteAlertRule,
   deleteGroup,
+  deleteLocation,
   deleteNode,
+  deleteSshKey,
   deleteUser,
+  deleteWebhook,
+  diagnoseNode,
+  dryRunPlacement,
   editGroup,
   editUser,
   getGeneralSettings,
   getMailSettings,
   getOidcSettings,
+  getRetentionSettings,
   getUserSettings,
   groupInfo,
   info,
   initOidc,
   isSetup,
   key,
+  listAlertChannels,
+  listAlertRules,
+  listAlerts,
+  listApiKeys,
   listGroups,
   listGroupsSimple,
+  listLocations,
   listNodes,
+  listSshKeys,
   listUsers,
   listUsersSimple,
+  listWebhookDeliveries,
+  listWebhooks,
   logout,
   mailActive,
+  nodeHistory,
   nodeInfo,
   type Options,
+  pingWebhook,
   resetPassword,
   resetUserAvatar,
   resetUserPassword,
+  revokeApiKey,
   saveMailSettings,
+  saveRetentionSettings,
   saveUserSettings,
   sendResetLink,
+  setLocationGroups,
+  setNodeMaintenance,
   startEmailChange,
+  testAlertChannel,
   testMail,
+  testNodeConnection,
   testToken,
   updateAccount,
+  updateAlertRule,
   updateAvatar,
+  updateLocation,
   updateNode,
   updatePassword,
+  updateWebhook,
   userInfo
 } from './sdk.gen';
 export {
   type AccountUpdate,
+  type AddSshKey,
+  type AddSshKeyData,
+  type AddSshKeyError,
+  type AddSshKeyErrors,
+  type AddSshKeyRes,
+  type AddSshKeyResponse,
+  type AddSshKeyResponses,
+  type AlertCondition,
+  type AlertInfo,
+  type ApiKeyInfo,
+  type ApiKeyRequest,
+  type ApiKeyUsage,
+  type ApiKeyUsageData,
+  type ApiKeyUsageErrors,
+  type ApiKeyUsageResponse,
+  type ApiKeyUsageResponses,
   type AuthConfig,
   type AuthConfigData,
   type AuthConfigErrors,
@@ -70,6 +121,8 @@ export {
   type ChangeUserEmailError,
   type ChangeUserEmailErrors,
   type ChangeUserEmailResponses,
+  type ChannelInfo,
+  type ChannelKind,
   type ClientOptions,
   type CompleteSetupData,
   type CompleteSetupError,
@@ -82,19 +135,51 @@ export {
   type ConvertOidcUserError,
   type ConvertOidcUserErrors,
   type ConvertOidcUserResponses,
+  type CreateAlertChannelData,
+  type CreateAlertChannelError,
+  type CreateAlertChannelErrors,
+  type CreateAlertChannelResponse,
+  type CreateAlertChannelResponses,
+  type CreateAlertRuleData,
+  type CreateAlertRuleError,
+  type CreateAlertRuleErrors,
+  type CreateAlertRuleResponse,
+  type CreateAlertRuleResponses,
+  type CreateApiKey,
+  type CreateApiKeyData,
+  type CreateApiKeyError,
+  type CreateApiKeyErrors,
+  type CreateApiKeyRes,
+  type CreateApiKeyResponse,
+  type CreateApiKeyResponses,
+  type CreateChannel,
+  type CreateChannelRes,
+  type CreateEnrollmentRes,
   type CreateGroupData,
   type CreateGroupError,
   type CreateGroupErrors,
   type CreateGroupRequest,
   type CreateGroupResponse,
   type CreateGroupResponses,
+  type CreateLocation,
+  type CreateLocationData,
+  type CreateLocationError,
+  type CreateLocationErrors,
+  type CreateLocationRes,
+  type CreateLocationResponse,
+  type CreateLocationResponses,
   type CreateNode,
   type CreateNodeData,
+  type CreateNodeEnrollmentData,
+  type CreateNodeEnrollmentErrors,
+  type CreateNodeEnrollmentResponse,
+  type CreateNodeEnrollmentResponses,
   type CreateNodeError,
   type CreateNodeErrors,
   type CreateNodeRes,
   type CreateNodeResponse,
   type CreateNodeResponses,
+  type CreateRuleRes,
   type CreateUser,
   type CreateUserData,
   type CreateUserError,
@@ -102,22 +187,62 @@ export {
   type CreateUserResponse,
   type CreateUserResponse2,
   type CreateUserResponses,
+  type CreateWebhookData,
+  type CreateWebhookError,
+  type CreateWebhookErrors,
+  type CreateWebhookRes,
+  type CreateWebhookResponse,
+  type CreateWebhookResponses,
+  type DeleteAlertChannelData,
+  type DeleteAlertChannelError,
+  type DeleteAlertChannelErrors,
+  type DeleteAlertChannelResponses,
+  type DeleteAlertRuleData,
+  type DeleteAlertRuleError,
+  type DeleteAlertRuleErrors,
+  type DeleteAlertRuleResponses,
+  type DeleteChannel,
   type DeleteGroupData,
   type DeleteGroupError,
   type DeleteGroupErrors,
   type DeleteGroupRequest,
   type DeleteGroupResponses,
+  type DeleteLocationData,
+  type DeleteLocationError,
+  type DeleteLocationErrors,
+  type DeleteLocationResponses,
   type DeleteNode,
   type DeleteNodeData,
   type DeleteNodeError,
   type DeleteNodeErrors,
   type DeleteNodeResponses,
+  type DeleteSshKey,
+  type DeleteSshKeyData,
+  type DeleteSshKeyError,
+  type DeleteSshKeyErrors,
+  type DeleteSshKeyResponses,
   type DeleteUserData,
   type DeleteUserError,
   type DeleteUserErrors,
   type DeleteUserRequest,
   type DeleteUserResponses,
+  type DeleteWebhookData,
+  type DeleteWebhookError,
+  type DeleteWebhookErrors,
+  type DeleteWebhookResponses,
+  type DeliveryInfo,
   type DetailUserInfo,
+  type DiagnoseNodeData,
+  type DiagnoseNodeErrors,
+  type DiagnoseNodeResponse,
+  type DiagnoseNodeResponses,
+  type DiagnoseRequest,
+  type Diagnosis,
+  type DryRunPlacementData,
+  type DryRunPlacementError,
+  type DryRunPlacementErrors,
+  type DryRunPlacementResponse,
+  type DryRunPlacementResponses,
   type EditGroupData,
   type EditGroupError,
   type EditGroupErrors,
@@ -129,6 +254,7 @@ export {
   type EditUserResponses,
   type EmailChange,
   type EmailChangeConfirm,
+  type EnrollmentRequest,
   type GeneralSettings,
   type GetGeneralSettingsData,
   type GetGeneralSettingsErrors,
@@ -142,6 +268,10 @@ export {
   type GetOidcSettingsErrors,
   type GetOidcSettingsResponse,
   type GetOidcSettingsResponses,
+  type GetRetentionSettingsData,
+  type GetRetentionSettingsErrors,
+  type GetRetentionSettingsResponse,
+  type GetRetentionSettingsResponses,
   type GetUserSettingsData,
   type GetUserSettingsErrors,
   type GetUserSettingsResponse,
@@ -155,6 +285,7 @@ export {
   type GroupInfoResponse,
   type GroupInfoResponses,
   type GroupViewPath,
+  type HistorySample,
   type InfoData,
   type InfoErrors,
   type InfoResponse,
@@ -172,6 +303,24 @@ export {
   type KeyRes,
   type KeyResponse,
   type KeyResponses,
+  type ListAlertChannelsData,
+  type ListAlertChannelsErrors,
+  type ListAlertChannelsResponse,
+  type ListAlertChannelsResponses,
+  type ListAlertRulesData,
+  type ListAlertRulesErrors,
+  type ListAlertRulesResponse,
+  type ListAlertRulesResponses,
+  type ListAlerts,
+  type ListAlertsData,
+  type ListAlertsErrors,
+  type ListAlertsResponse,
+  type ListAlertsResponses,
+  type ListApiKeysData,
+  type ListApiKeysErrors,
+  type ListApiKeysResponse,
+  type ListApiKeysResponses,
+  type ListDeliveries,
   type ListGroupResponse,
   type ListGroupsData,
   type ListGroupsErrors,
@@ -181,10 +330,19 @@ export {
   type ListGroupsSimpleErrors,
   type ListGroupsSimpleResponse,
   type ListGroupsSimpleResponses,
+  type ListLocationsData,
+  type ListLocationsErrors,
+  type ListLocationsResponse,
+  type ListLocationsResponses,
+  type ListNodes,
   type ListNodesData,
   type ListNodesErrors,
   type ListNodesResponse,
   type ListNodesResponses,
+  type ListSshKeysData,
+  type ListSshKeysErrors,
+  type ListSshKeysResponse,
+  type ListSshKeysResponses,
   type ListUsersData,
   type ListUsersErrors,
   type ListUsersResponse,
@@ -193,6 +351,16 @@ export {
   type ListUsersSimpleErrors,
   type ListUsersSimpleResponse,
   type ListUsersSimpleResponses,
+  type ListWebhookDeliveriesData,
+  type ListWebhookDeliveriesErrors,
+  type ListWebhookDeliveriesResponse,
+  type ListWebhookDeliveriesResponses,
+  type ListWebhooksData,
+  type ListWebhooksErrors,
+  type ListWebhooksResponse,
+  type ListWebhooksResponses,
+  type LocationInfo,
+  type LocationRequest,
   type LoginReq,
   type LogoutData,
   type LogoutErrors,
@@ -203,16 +371,35 @@ export {
   type MailActiveResponses,
   type MailSettings,
   type MailSettingsResponse,
+  type NodeEvaluation,
+  type NodeHistory,
+  type NodeHistoryData,
+  type NodeHistoryErrors,
+  type NodeHistoryQuery,
+  type NodeHistoryRequest,
+  type NodeHistoryResponse,
+  type NodeHistoryResponses,
   type NodeInfo,
   type NodeInfoData,
   type NodeInfoErrors,
   type NodeInfoRequest,
   type NodeInfoResponse,
   type NodeInfoResponses,
+  type NodeLifecycle,
+  NodeSort,
+  type NodeSync,
   type OidcCallbackQuery,
   type OidcSetupResponse,
   type OidcUrlQuery,
   type PasswordUpdate,
+  type PingRes,
+  type PingWebhookData,
+  type PingWebhookErrors,
+  type PingWebhookResponse,
+  type PingWebhookResponses,
+  type Placement,
+  type PlacementRequest,
+  type PlacementStrategy,
   type ResetPasswordData,
   type ResetPasswordError,
   type ResetPasswordErrors,
@@ -228,10 +415,24 @@ export {
   type ResetUserPasswordError,
   type ResetUserPasswordErrors,
   type ResetUserPasswordResponses,
+  Resolution,
+  type RetentionSettings,
+  type RevokeApiKey,
+  type RevokeApiKeyData,
+  type RevokeApiKeyError,
+  type RevokeApiKeyErrors,
+  type RevokeApiKeyResponses,
+  type RuleData,
+  type RuleInfo,
+  type RuleRequest,
   type SaveMailSettingsData,
   type SaveMailSettingsError,
   type SaveMailSettingsErrors,
   type SaveMailSettingsResponses,
+  type SaveRetentionSettingsData,
+  type SaveRetentionSettingsError,
+  type SaveRetentionSettingsErrors,
+  type SaveRetentionSettingsResponses,
   type SaveUserSettingsData,
   type SaveUserSettingsError,
   type SaveUserSettingsErrors,
@@ -240,26 +441,63 @@ export {
   type SendResetLinkError,
   type SendResetLinkErrors,
   type SendResetLinkResponses,
+  type SetLocationGroups,
+  type SetLocationGroupsData,
+  type SetLocationGroupsError,
+  type SetLocationGroupsErrors,
+  type SetLocationGroupsResponses,
+  type SetMaintenance,
+  type SetNodeMaintenanceData,
+  type SetNodeMaintenanceError,
+  type SetNodeMaintenanceErrors,
+  type SetNodeMaintenanceResponses,
   type SetupPayload,
   type SimpleGroupInfo,
   type SimpleUserInfo,
+  SortOrder,
+  type SshKeyInfo,
   SsoType,
   type StartEmailChangeData,
   type StartEmailChangeError,
   type StartEmailChangeErrors,
   type StartEmailChangeResponses,
+  type Step,
+  type StepResult,
+  type StepStatus,
+  type SyncDrift,
+  type SyncStatus,
+  type TestAlertChannelData,
+  type TestAlertChannelErrors,
+  type TestAlertChannelResponses,
+  type TestChannelRequest,
+  type TestConnection,
   type TestMailData,
   type TestMailErrors,
   type TestMailResponses,
+  type TestNodeConnectionData,
+  type TestNodeConnectionError,
+  type TestNodeConnectionErrors,
+  type TestNodeConnectionResponse,
+  type TestNodeConnectionResponses,
   type TestTokenData,
+  type TlsPin,
   type UpdateAccountData,
   type UpdateAccountError,
   type UpdateAccountErrors,
   type UpdateAccountResponses,
+  type UpdateAlertRuleData,
+  type UpdateAlertRuleError,
+  type UpdateAlertRuleErrors,
+  type UpdateAlertRuleResponses,
   type UpdateAvatarData,
   type UpdateAvatarError,
   type UpdateAvatarErrors,
   type UpdateAvatarResponses,
+  type UpdateLocation,
+  type UpdateLocationData,
+  type UpdateLocationError,
+  type UpdateLocationErrors,
+  type UpdateLocationResponses,
   type UpdateNode,
   type UpdateNodeData,
   type UpdateNodeError,
@@ -269,6 +507,10 @@ export {
   type UpdatePasswordError,
   type UpdatePasswordErrors,
   type UpdatePasswordResponses,
+  type UpdateWebhookData,
+  type UpdateWebhookError,
+  type UpdateWebhookErrors,
+  type UpdateWebhookResponses,
   type UserAvatarResetRequest,
   type UserEditReq,
   type UserInfo,
@@ -279,5 +521,9 @@ export {
   type UserListInfo,
   type UserSettings,
   type UserSettingsResponse,
-  type UserViewPath
+  type UserViewPath,
+  type WebhookData,
+  type WebhookEvent,
+  type WebhookInfo,
+  type WebhookRequest
 } from './types.gen';
@@ -10,6 +10,9 @@ import {
 } from 'msw';
 
 import type {
+  AddSshKeyData,
+  AddSshKeyResponses,
+  ApiKeyUsageResponses,
   AuthConfigResponses,
   AuthenticateData,
   AvatarByIdResponses,
@@ -21,18 +24,42 @@ import type {
   ConfirmEmailChangeResponses,
   ConvertOidcUserData,
   ConvertOidcUserResponses,
+  CreateAlertChannelData,
+  CreateAlertChannelResponses,
+  CreateAlertRuleData,
+  CreateAlertRuleResponses,
+  CreateApiKeyData,
+  CreateApiKeyResponses,
   CreateGroupData,
   CreateGroupResponses,
+  CreateLocationData,
+  CreateLocationResponses,
   CreateNodeData,
+  CreateNodeEnrollmentResponses,
   CreateNodeResponses,
   CreateUserData,
   CreateUserResponses,
+  CreateWebhookData,
+  CreateWebhookResponses,
+  DeleteAlertChannelData,
+  DeleteAlertChannelResponses,
+  DeleteAlertRuleData,
+  DeleteAlertRuleResponses,
   DeleteGroupData,
   DeleteGroupResponses,
+  DeleteLocationData,
+  DeleteLocationResponses,
   DeleteNodeData,
   DeleteNodeResponses,
+  DeleteSshKeyData,
+  DeleteSshKeyResponses,
   DeleteUserData,
   DeleteUserResponses,
+  DeleteWebhookData,
+  DeleteWebhookResponses,
+  DiagnoseNodeResponses,
+  DryRunPlacementData,
+  DryRunPlacementResponses,
   EditGroupData,
   EditGroupResponses,
   EditUserData,
@@ -40,6 +67,7 @@ import type {
   GetGeneralSettingsResponses,
   GetMailSettingsResponses,
   GetOidcSettingsResponses,
+  GetRetentionSettingsResponses,
   GetUserSettingsResponses,
   GroupInfoResponses,
   InfoResponses,
@@ -47,36 +75,63 @@ import type {
   InitOidcResponses,
   IsSetupResponses,
   KeyResponses,
+  ListAlertChannelsResponses,
+  ListAlertRulesResponses,
+  ListAlertsResponses,
+  ListApiKeysResponses,
   ListGroupsResponses,
   ListGroupsSimpleResponses,
+  ListLocationsResponses,
   ListNodesResponses,
+  ListSshKeysResponses,
   ListUsersResponses,
   ListUsersSimpleResponses,
+  ListWebhookDeliveriesResponses,
+  ListWebhooksResponses,
   MailActiveResponses,
+  NodeHistoryResponses,
   NodeInfoResponses,
+  PingWebhookResponses,
   ResetPasswordData,
   ResetPasswordResponses,
   ResetUserAvatarData,
   ResetUserAvatarResponses,
   ResetUserPasswordData,
   ResetUserPasswordResponses,
+  RevokeApiKeyData,
+  RevokeApiKeyResponses,
   SaveMailSettingsData,
   SaveMailSettingsResponses,
+  SaveRetentionSettingsData,
+  SaveRetentionSettingsResponses,
   SaveUserSettingsData,
   SaveUserSettingsResponses,
   SendResetLinkData,
   SendResetLinkResponses,
+  SetLocationGroupsData,
+  SetLocationGroupsResponses,
+  SetNodeMaintenanceData,
+  SetNodeMaintenanceResponses,
   StartEmailChangeData,
   StartEmailChangeResponses,
+  TestAlertChannelResponses,
   TestMailResponses,
+  TestNodeConnectionData,
+  TestNodeConnectionResponses,
   UpdateAccountData,
   UpdateAccountResponses,
+  UpdateAlertRuleData,
+  UpdateAlertRuleResponses,
   UpdateAvatarData,
   UpdateAvatarResponses,
+  UpdateLocationData,
+  UpdateLocationResponses,
   UpdateNodeData,
   UpdateNodeResponses,
   UpdatePasswordData,
   UpdatePasswordResponses,
+  UpdateWebhookData,
+  UpdateWebhookResponses,
   UserInfoResponses
 } from './types.gen';
 
@@ -1059,6 +1114,112 @@ export function handleConvertOidcUser(
   );
 }
 
+export type HandleDeleteSshKeyResponse = {
+  body: DeleteSshKeyResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `DELETE /api/user/ssh_keys` operation.
+ */
+export function handleDeleteSshKey(
+  response?:
+    | HandleDeleteSshKeyResponse
+    | HttpResponseResolver<never, DeleteSshKeyData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.delete<never, DeleteSshKeyData['body']>(
+    `${options?.baseUrl ?? '*'}/api/user/ssh_keys`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleListSshKeysResponse = {
+  body: ListSshKeysResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `GET /api/user/ssh_keys` operation.
+ */
+export function handleListSshKeys(
+  response?: HandleListSshKeysResponse | HttpResponseResolver<never, never>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.get<never, never>(
+    `${options?.baseUrl ?? '*'}/api/user/ssh_keys`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleAddSshKeyResponse = {
+  body: AddSshKeyResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/user/ssh_keys` operation.
+ */
+export function handleAddSshKey(
+  response?:
+    | HandleAddSshKeyResponse
+    | HttpResponseResolver<never, AddSshKeyData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<never, AddSshKeyData['body']>(
+    `${options?.baseUrl ?? '*'}/api/user/ssh_keys`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
 export type HandleGetGeneralSettingsResponse = {
   body: GetGeneralSettingsResponses[200];
   status?: 200;
@@ -1095,6 +1256,78 @@ export function handleGetGeneralSettings(
   );
 }
 
+export type HandleGetRetentionSettingsResponse = {
+  body: GetRetentionSettingsResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `GET /api/settings/retention` operation.
+ */
+export function handleGetRetentionSettings(
+  response?:
+    | HandleGetRetentionSettingsResponse
+    | HttpResponseResolver<never, never>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.get<never, never>(
+    `${options?.baseUrl ?? '*'}/api/settings/retention`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleSaveRetentionSettingsResponse = {
+  body: SaveRetentionSettingsResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/settings/retention` operation.
+ */
+export function handleSaveRetentionSettings(
+  response?:
+    | HandleSaveRetentionSettingsResponse
+    | HttpResponseResolver<never, SaveRetentionSettingsData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<never, SaveRetentionSettingsData['body']>(
+    `${options?.baseUrl ?? '*'}/api/settings/retention`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
 export type HandleGetUserSettingsResponse = {
   body: GetUserSettingsResponses[200];
   status?: 200;
@@ -1563,22 +1796,22 @@ export function handleListUsersSimple(
   );
 }
 
-export type HandleDeleteNodeResponse = {
-  body: DeleteNodeResponses[200];
+export type HandleRevokeApiKeyResponse = {
+  body: RevokeApiKeyResponses[200];
   status?: 200;
 };
 
 /**
- * Handler for the `DELETE /api/nodes` operation.
+ * Handler for the `DELETE /api/api_keys` operation.
  */
-export function handleDeleteNode(
+export function handleRevokeApiKey(
   response?:
-    | HandleDeleteNodeResponse
-    | HttpResponseResolver<never, DeleteNodeData['body']>,
+    | HandleRevokeApiKeyResponse
+    | HttpResponseResolver<never, RevokeApiKeyData['body']>,
   options?: RequestHandlerOptions
 ): HttpHandler {
-  return http.delete<never, DeleteNodeData['body']>(
-    `${options?.baseUrl ?? '*'}/api/nodes`,
+  return http.delete<never, RevokeApiKeyData['body']>(
+    `${options?.baseUrl ?? '*'}/api/api_keys`,
     (info) => {
       if (typeof response === 'function') {
         return response(info);
@@ -1599,20 +1832,20 @@ export function handleDeleteNode(
   );
 }
 
-export type HandleListNodesResponse = {
-  body: ListNodesResponses[200];
+export type HandleListApiKeysResponse = {
+  body: ListApiKeysResponses[200];
   status?: 200;
 };
 
 /**
- * Handler for the `GET /api/nodes` operation.
+ * Handler for the `GET /api/api_keys` operation.
  */
-export function handleListNodes(
-  response?: HandleListNodesResponse | HttpResponseResolver<never, never>,
+export function handleListApiKeys(
+  response?: HandleListApiKeysResponse | HttpResponseResolver<never, never>,
   options?: RequestHandlerOptions
 ): HttpHandler {
   return http.get<never, never>(
-    `${options?.baseUrl ?? '*'}/api/nodes`,
+    `${options?.baseUrl ?? '*'}/api/api_keys`,
     (info) => {
       if (typeof response === 'function') {
         return response(info);
@@ -1633,22 +1866,22 @@ export function handleListNodes(
   );
 }
 
-export type HandleCreateNodeResponse = {
-  body: CreateNodeResponses[200];
+export type HandleCreateApiKeyResponse = {
+  body: CreateApiKeyResponses[200];
   status?: 200;
 };
 
 /**
- * Handler for the `POST /api/nodes` operation.
+ * Handler for the `POST /api/api_keys` operation.
  */
-export function handleCreateNode(
+export function handleCreateApiKey(
   response?:
-    | HandleCreateNodeResponse
-    | HttpResponseResolver<never, CreateNodeData['body']>,
+    | HandleCreateApiKeyResponse
+    | HttpResponseResolver<never, CreateApiKeyData['body']>,
   options?: RequestHandlerOptions
 ): HttpHandler {
-  return http.post<never, CreateNodeData['body']>(
-    `${options?.baseUrl ?? '*'}/api/nodes`,
+  return http.post<never, CreateApiKeyData['body']>(
+    `${options?.baseUrl ?? '*'}/api/api_keys`,
     (info) => {
       if (typeof response === 'function') {
         return response(info);
@@ -1669,17 +1902,17 @@ export function handleCreateNode(
   );
 }
 
-export type HandleNodeInfoResponse = {
-  body: NodeInfoResponses[200];
+export type HandleApiKeyUsageResponse = {
+  body: ApiKeyUsageResponses[200];
   status?: 200;
 };
 
 /**
- * Handler for the `GET /api/nodes/{uuid}` operation.
+ * Handler for the `GET /api/api_keys/{uuid}/usage` operation.
  */
-export function handleNodeInfo(
+export function handleApiKeyUsage(
   response?:
-    | HandleNodeInfoResponse
+    | HandleApiKeyUsageResponse
     | HttpResponseResolver<
         {
           uuid: string;
@@ -1694,7 +1927,7 @@ export function handleNodeInfo(
     },
     never
   >(
-    `${options?.baseUrl ?? '*'}/api/nodes/:uuid`,
+    `${options?.baseUrl ?? '*'}/api/api_keys/:uuid/usage`,
     (info) => {
       if (typeof response === 'function') {
         return response(info);
@@ -1715,32 +1948,22 @@ export function handleNodeInfo(
   );
 }
 
-export type HandleUpdateNodeResponse = {
-  body: UpdateNodeResponses[200];
+export type HandleDeleteNodeResponse = {
+  body: DeleteNodeResponses[200];
   status?: 200;
 };
 
 /**
- * Handler for the `POST /api/nodes/{uuid}` operation.
+ * Handler for the `DELETE /api/nodes` operation.
  */
-export function handleUpdateNode(
+export function handleDeleteNode(
   response?:
-    | HandleUpdateNodeResponse
-    | HttpResponseResolver<
-        {
-          uuid: string;
-        },
-        UpdateNodeData['body']
-      >,
+    | HandleDeleteNodeResponse
+    | HttpResponseResolver<never, DeleteNodeData['body']>,
   options?: RequestHandlerOptions
 ): HttpHandler {
-  return http.post<
-    {
-      uuid: string;
-    },
-    UpdateNodeData['body']
-  >(
-    `${options?.baseUrl ?? '*'}/api/nodes/:uuid`,
+  return http.delete<never, DeleteNodeData['body']>(
+    `${options?.baseUrl ?? '*'}/api/nodes`,
     (info) => {
       if (typeof response === 'function') {
         return response(info);
@@ -1761,66 +1984,1266 @@ export function handleUpdateNode(
   );
 }
 
-export type MswHandlerFactories = {
-  /**
-   * Handler for the `GET /api/setup` operation.
-   */
-  isSetup: typeof handleIsSetup;
-  /**
-   * Handler for the `POST /api/setup` operation.
-   */
-  completeSetup: typeof handleCompleteSetup;
-  /**
-   * Handler for the `GET /api/setup/oidc` operation.
-   */
-  getOidcSettings: typeof handleGetOidcSettings;
-  /**
-   * Handler for the `POST /api/setup/oidc` operation.
-   */
-  initOidc: typeof handleInitOidc;
-  /**
-   * Handler for the `GET /api/auth/password` operation.
-   */
-  key: typeof handleKey;
-  /**
-   * Handler for the `POST /api/auth/password` operation.
-   */
-  authenticate: typeof handleAuthenticate;
-  /**
-   * Handler for the `POST /api/auth/logout` operation.
-   */
-  logout: typeof handleLogout;
-  /**
-   * Handler for the `GET /api/auth/test_token` operation.
-   */
-  testToken: typeof handleTestToken;
-  /**
-   * Handler for the `GET /api/auth/config` operation.
-   */
-  authConfig: typeof handleAuthConfig;
-  /**
-   * Handler for the `POST /api/user/account/password` operation.
-   */
-  updatePassword: typeof handleUpdatePassword;
-  /**
-   * Handler for the `POST /api/user/account/email_change_start` operation.
-   */
-  startEmailChange: typeof handleStartEmailChange;
-  /**
-   * Handler for the `POST /api/user/account/update` operation.
-   */
-  updateAccount: typeof handleUpdateAccount;
-  /**
-   * Handler for the `POST /api/user/account/email_change_confirm` operation.
-   */
-  confirmEmailChange: typeof handleConfirmEmailChange;
-  /**
-   * Handler for the `POST /api/user/account/avatar` operation.
-   */
-  updateAvatar: typeof handleUpdateAvatar;
-  /**
-   * Handler for the `GET /api/user/info` operation.
-   */
+export type HandleListNodesResponse = {
+  body: ListNodesResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `GET /api/nodes` operation.
+ */
+export function handleListNodes(
+  response?: HandleListNodesResponse | HttpResponseResolver<never, never>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.get<never, never>(
+    `${options?.baseUrl ?? '*'}/api/nodes`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleCreateNodeResponse = {
+  body: CreateNodeResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/nodes` operation.
+ */
+export function handleCreateNode(
+  response?:
+    | HandleCreateNodeResponse
+    | HttpResponseResolver<never, CreateNodeData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<never, CreateNodeData['body']>(
+    `${options?.baseUrl ?? '*'}/api/nodes`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleNodeInfoResponse = {
+  body: NodeInfoResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `GET /api/nodes/{uuid}` operation.
+ */
+export function handleNodeInfo(
+  response?:
+    | HandleNodeInfoResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        never
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.get<
+    {
+      uuid: string;
+    },
+    never
+  >(
+    `${options?.baseUrl ?? '*'}/api/nodes/:uuid`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleUpdateNodeResponse = {
+  body: UpdateNodeResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/nodes/{uuid}` operation.
+ */
+export function handleUpdateNode(
+  response?:
+    | HandleUpdateNodeResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        UpdateNodeData['body']
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<
+    {
+      uuid: string;
+    },
+    UpdateNodeData['body']
+  >(
+    `${options?.baseUrl ?? '*'}/api/nodes/:uuid`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleSetNodeMaintenanceResponse = {
+  body: SetNodeMaintenanceResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/nodes/{uuid}/maintenance` operation.
+ */
+export function handleSetNodeMaintenance(
+  response?:
+    | HandleSetNodeMaintenanceResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        SetNodeMaintenanceData['body']
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<
+    {
+      uuid: string;
+    },
+    SetNodeMaintenanceData['body']
+  >(
+    `${options?.baseUrl ?? '*'}/api/nodes/:uuid/maintenance`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleNodeHistoryResponse = {
+  body: NodeHistoryResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `GET /api/nodes/{uuid}/history` operation.
+ */
+export function handleNodeHistory(
+  response?:
+    | HandleNodeHistoryResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        never
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.get<
+    {
+      uuid: string;
+    },
+    never
+  >(
+    `${options?.baseUrl ?? '*'}/api/nodes/:uuid/history`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleDeleteLocationResponse = {
+  body: DeleteLocationResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `DELETE /api/nodes/locations` operation.
+ */
+export function handleDeleteLocation(
+  response?:
+    | HandleDeleteLocationResponse
+    | HttpResponseResolver<never, DeleteLocationData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.delete<never, DeleteLocationData['body']>(
+    `${options?.baseUrl ?? '*'}/api/nodes/locations`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleListLocationsResponse = {
+  body: ListLocationsResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `GET /api/nodes/locations` operation.
+ */
+export function handleListLocations(
+  response?: HandleListLocationsResponse | HttpResponseResolver<never, never>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.get<never, never>(
+    `${options?.baseUrl ?? '*'}/api/nodes/locations`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleCreateLocationResponse = {
+  body: CreateLocationResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/nodes/locations` operation.
+ */
+export function handleCreateLocation(
+  response?:
+    | HandleCreateLocationResponse
+    | HttpResponseResolver<never, CreateLocationData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<never, CreateLocationData['body']>(
+    `${options?.baseUrl ?? '*'}/api/nodes/locations`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleUpdateLocationResponse = {
+  body: UpdateLocationResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/nodes/locations/{uuid}` operation.
+ */
+export function handleUpdateLocation(
+  response?:
+    | HandleUpdateLocationResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        UpdateLocationData['body']
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<
+    {
+      uuid: string;
+    },
+    UpdateLocationData['body']
+  >(
+    `${options?.baseUrl ?? '*'}/api/nodes/locations/:uuid`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleSetLocationGroupsResponse = {
+  body: SetLocationGroupsResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/nodes/locations/{uuid}/groups` operation.
+ */
+export function handleSetLocationGroups(
+  response?:
+    | HandleSetLocationGroupsResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        SetLocationGroupsData['body']
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<
+    {
+      uuid: string;
+    },
+    SetLocationGroupsData['body']
+  >(
+    `${options?.baseUrl ?? '*'}/api/nodes/locations/:uuid/groups`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleDryRunPlacementResponse = {
+  body: DryRunPlacementResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/nodes/placement` operation.
+ */
+export function handleDryRunPlacement(
+  response?:
+    | HandleDryRunPlacementResponse
+    | HttpResponseResolver<never, DryRunPlacementData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<never, DryRunPlacementData['body']>(
+    `${options?.baseUrl ?? '*'}/api/nodes/placement`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleCreateNodeEnrollmentResponse = {
+  body: CreateNodeEnrollmentResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/nodes/{uuid}/enrollment` operation.
+ */
+export function handleCreateNodeEnrollment(
+  response?:
+    | HandleCreateNodeEnrollmentResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        never
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<
+    {
+      uuid: string;
+    },
+    never
+  >(
+    `${options?.baseUrl ?? '*'}/api/nodes/:uuid/enrollment`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleTestNodeConnectionResponse = {
+  body: TestNodeConnectionResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/nodes/test-connection` operation.
+ */
+export function handleTestNodeConnection(
+  response?:
+    | HandleTestNodeConnectionResponse
+    | HttpResponseResolver<never, TestNodeConnectionData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<never, TestNodeConnectionData['body']>(
+    `${options?.baseUrl ?? '*'}/api/nodes/test-connection`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleDiagnoseNodeResponse = {
+  body: DiagnoseNodeResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/nodes/{uuid}/diagnose` operation.
+ */
+export function handleDiagnoseNode(
+  response?:
+    | HandleDiagnoseNodeResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        never
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<
+    {
+      uuid: string;
+    },
+    never
+  >(
+    `${options?.baseUrl ?? '*'}/api/nodes/:uuid/diagnose`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleListAlertsResponse = {
+  body: ListAlertsResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `GET /api/alerts` operation.
+ */
+export function handleListAlerts(
+  response?: HandleListAlertsResponse | HttpResponseResolver<never, never>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.get<never, never>(
+    `${options?.baseUrl ?? '*'}/api/alerts`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleDeleteAlertRuleResponse = {
+  body: DeleteAlertRuleResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `DELETE /api/alerts/rules` operation.
+ */
+export function handleDeleteAlertRule(
+  response?:
+    | HandleDeleteAlertRuleResponse
+    | HttpResponseResolver<never, DeleteAlertRuleData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.delete<never, DeleteAlertRuleData['body']>(
+    `${options?.baseUrl ?? '*'}/api/alerts/rules`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleListAlertRulesResponse = {
+  body: ListAlertRulesResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `GET /api/alerts/rules` operation.
+ */
+export function handleListAlertRules(
+  response?: HandleListAlertRulesResponse | HttpResponseResolver<never, never>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.get<never, never>(
+    `${options?.baseUrl ?? '*'}/api/alerts/rules`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleCreateAlertRuleResponse = {
+  body: CreateAlertRuleResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/alerts/rules` operation.
+ */
+export function handleCreateAlertRule(
+  response?:
+    | HandleCreateAlertRuleResponse
+    | HttpResponseResolver<never, CreateAlertRuleData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<never, CreateAlertRuleData['body']>(
+    `${options?.baseUrl ?? '*'}/api/alerts/rules`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleUpdateAlertRuleResponse = {
+  body: UpdateAlertRuleResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/alerts/rules/{uuid}` operation.
+ */
+export function handleUpdateAlertRule(
+  response?:
+    | HandleUpdateAlertRuleResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        UpdateAlertRuleData['body']
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<
+    {
+      uuid: string;
+    },
+    UpdateAlertRuleData['body']
+  >(
+    `${options?.baseUrl ?? '*'}/api/alerts/rules/:uuid`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleDeleteAlertChannelResponse = {
+  body: DeleteAlertChannelResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `DELETE /api/alerts/channels` operation.
+ */
+export function handleDeleteAlertChannel(
+  response?:
+    | HandleDeleteAlertChannelResponse
+    | HttpResponseResolver<never, DeleteAlertChannelData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.delete<never, DeleteAlertChannelData['body']>(
+    `${options?.baseUrl ?? '*'}/api/alerts/channels`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleListAlertChannelsResponse = {
+  body: ListAlertChannelsResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `GET /api/alerts/channels` operation.
+ */
+export function handleListAlertChannels(
+  response?:
+    | HandleListAlertChannelsResponse
+    | HttpResponseResolver<never, never>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.get<never, never>(
+    `${options?.baseUrl ?? '*'}/api/alerts/channels`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleCreateAlertChannelResponse = {
+  body: CreateAlertChannelResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/alerts/channels` operation.
+ */
+export function handleCreateAlertChannel(
+  response?:
+    | HandleCreateAlertChannelResponse
+    | HttpResponseResolver<never, CreateAlertChannelData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<never, CreateAlertChannelData['body']>(
+    `${options?.baseUrl ?? '*'}/api/alerts/channels`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleTestAlertChannelResponse = {
+  body: TestAlertChannelResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/alerts/channels/{uuid}/test` operation.
+ */
+export function handleTestAlertChannel(
+  response?:
+    | HandleTestAlertChannelResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        never
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<
+    {
+      uuid: string;
+    },
+    never
+  >(
+    `${options?.baseUrl ?? '*'}/api/alerts/channels/:uuid/test`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleDeleteWebhookResponse = {
+  body: DeleteWebhookResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `DELETE /api/webhooks` operation.
+ */
+export function handleDeleteWebhook(
+  response?:
+    | HandleDeleteWebhookResponse
+    | HttpResponseResolver<never, DeleteWebhookData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.delete<never, DeleteWebhookData['body']>(
+    `${options?.baseUrl ?? '*'}/api/webhooks`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleListWebhooksResponse = {
+  body: ListWebhooksResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `GET /api/webhooks` operation.
+ */
+export function handleListWebhooks(
+  response?: HandleListWebhooksResponse | HttpResponseResolver<never, never>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.get<never, never>(
+    `${options?.baseUrl ?? '*'}/api/webhooks`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleCreateWebhookResponse = {
+  body: CreateWebhookResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/webhooks` operation.
+ */
+export function handleCreateWebhook(
+  response?:
+    | HandleCreateWebhookResponse
+    | HttpResponseResolver<never, CreateWebhookData['body']>,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<never, CreateWebhookData['body']>(
+    `${options?.baseUrl ?? '*'}/api/webhooks`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleUpdateWebhookResponse = {
+  body: UpdateWebhookResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/webhooks/{uuid}` operation.
+ */
+export function handleUpdateWebhook(
+  response?:
+    | HandleUpdateWebhookResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        UpdateWebhookData['body']
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<
+    {
+      uuid: string;
+    },
+    UpdateWebhookData['body']
+  >(
+    `${options?.baseUrl ?? '*'}/api/webhooks/:uuid`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return new HttpResponse(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandlePingWebhookResponse = {
+  body: PingWebhookResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `POST /api/webhooks/{uuid}/ping` operation.
+ */
+export function handlePingWebhook(
+  response?:
+    | HandlePingWebhookResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        never
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.post<
+    {
+      uuid: string;
+    },
+    never
+  >(
+    `${options?.baseUrl ?? '*'}/api/webhooks/:uuid/ping`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type HandleListWebhookDeliveriesResponse = {
+  body: ListWebhookDeliveriesResponses[200];
+  status?: 200;
+};
+
+/**
+ * Handler for the `GET /api/webhooks/{uuid}/deliveries` operation.
+ */
+export function handleListWebhookDeliveries(
+  response?:
+    | HandleListWebhookDeliveriesResponse
+    | HttpResponseResolver<
+        {
+          uuid: string;
+        },
+        never
+      >,
+  options?: RequestHandlerOptions
+): HttpHandler {
+  return http.get<
+    {
+      uuid: string;
+    },
+    never
+  >(
+    `${options?.baseUrl ?? '*'}/api/webhooks/:uuid/deliveries`,
+    (info) => {
+      if (typeof response === 'function') {
+        return response(info);
+      }
+      const body = response?.body;
+      if (body !== undefined) {
+        return HttpResponse.json(body, { status: response?.status ?? 200 });
+      }
+      if (options?.responseFallback === 'passthrough') {
+        return;
+      }
+      return new Response('Not Implemented', {
+        status: 501,
+        statusText: 'Not Implemented'
+      });
+    },
+    options
+  );
+}
+
+export type MswHandlerFactories = {
+  /**
+   * Handler for the `GET /api/setup` operation.
+   */
+  isSetup: typeof handleIsSetup;
+  /**
+   * Handler for the `POST /api/setup` operation.
+   */
+  completeSetup: typeof handleCompleteSetup;
+  /**
+   * Handler for the `GET /api/setup/oidc` operation.
+   */
+  getOidcSettings: typeof handleGetOidcSettings;
+  /**
+   * Handler for the `POST /api/setup/oidc` operation.
+   */
+  initOidc: typeof handleInitOidc;
+  /**
+   * Handler for the `GET /api/auth/password` operation.
+   */
+  key: typeof handleKey;
+  /**
+   * Handler for the `POST /api/auth/password` operation.
+   */
+  authenticate: typeof handleAuthenticate;
+  /**
+   * Handler for the `POST /api/auth/logout` operation.
+   */
+  logout: typeof handleLogout;
+  /**
+   * Handler for the `GET /api/auth/test_token` operation.
+   */
+  testToken: typeof handleTestToken;
+  /**
+   * Handler for the `GET /api/auth/config` operation.
+   */
+  authConfig: typeof handleAuthConfig;
+  /**
+   * Handler for the `POST /api/user/account/password` operation.
+   */
+  updatePassword: typeof handleUpdatePassword;
+  /**
+   * Handler for the `POST /api/user/account/email_change_start` operation.
+   */
+  startEmailChange: typeof handleStartEmailChange;
+  /**
+   * Handler for the `POST /api/user/account/update` operation.
+   */
+  updateAccount: typeof handleUpdateAccount;
+  /**
+   * Handler for the `POST /api/user/account/email_change_confirm` operation.
+   */
+  confirmEmailChange: typeof handleConfirmEmailChange;
+  /**
+   * Handler for the `POST /api/user/account/avatar` operation.
+   */
+  updateAvatar: typeof handleUpdateAvatar;
+  /**
+   * Handler for the `GET /api/user/info` operation.
+   */
   info: typeof handleInfo;
   /**
    * Handler for the `GET /api/user/info/avatar/{uuid}` operation.
@@ -1870,10 +3293,30 @@ export type MswHandlerFactories = {
    * Handler for the `PUT /api/user/management/convert-oidc` operation.
    */
   convertOidcUser: typeof handleConvertOidcUser;
+  /**
+   * Handler for the `DELETE /api/user/ssh_keys` operation.
+   */
+  deleteSshKey: typeof handleDeleteSshKey;
+  /**
+   * Handler for the `GET /api/user/ssh_keys` operation.
+   */
+  listSshKeys: typeof handleListSshKeys;
+  /**
+   * Handler for the `POST /api/user/ssh_keys` operation.
+   */
+  addSshKey: typeof handleAddSshKey;
   /**
    * Handler for the `GET /api/settings/general` operation.
    */
   getGeneralSettings: typeof handleGetGeneralSettings;
+  /**
+   * Handler for the `GET /api/settings/retention` operation.
+   */
+  getRetentionSettings: typeof handleGetRetentionSettings;
+  /**
+   * Handler for the `POST /api/settings/retention` operation.
+   */
+  saveRetentionSettings: typeof handleSaveRetentionSettings;
   /**
    * Handler for the `GET /api/settings/user` operation.
    */
@@ -1926,6 +3369,22 @@ export type MswHandlerFactories = {
    * Handler for the `GET /api/group/users` operation.
    */
   listUsersSimple: typeof handleListUsersSimple;
+  /**
+   * Handler for the `DELETE /api/api_keys` operation.
+   */
+  revokeApiKey: typeof handleRevokeApiKey;
+  /**
+   * Handler for the `GET /api/api_keys` operation.
+   */
+  listApiKeys: typeof handleListApiKeys;
+  /**
+   * Handler for the `POST /api/api_keys` operation.
+   */
+  createApiKey: typeof handleCreateApiKey;
+  /**
+   * Handler for the `GET /api/api_keys/{uuid}/usage` operation.
+   */
+  apiKeyUsage: typeof handleApiKeyUsage;
   /**
    * Handler for the `DELETE /api/nodes` operation.
    */
@@ -1946,6 +3405,110 @@ export type MswHandlerFactories = {
    * Handler for the `POST /api/nodes/{uuid}` operation.
    */
   updateNode: typeof handleUpdateNode;
+  /**
+   * Handler for the `POST /api/nodes/{uuid}/maintenance` operation.
+   */
+  setNodeMaintenance: typeof handleSetNodeMaintenance;
+  /**
+   * Handler for the `GET /api/nodes/{uuid}/history` operation.
+   */
+  nodeHistory: typeof handleNodeHistory;
+  /**
+   * Handler for the `DELETE /api/nodes/locations` operation.
+   */
+  deleteLocation: typeof handleDeleteLocation;
+  /**
+   * Handler for the `GET /api/nodes/locations` operation.
+   */
+  listLocations: typeof handleListLocations;
+  /**
+   * Handler for the `POST /api/nodes/locations` operation.
+   */
+  createLocation: typeof handleCreateLocation;
+  /**
+   * Handler for the `POST /api/nodes/locations/{uuid}` operation.
+   */
+  updateLocation: typeof handleUpdateLocation;
+  /**
+   * Handler for the `POST /api/nodes/locations/{uuid}/groups` operation.
+   */
+  setLocationGroups: typeof handleSetLocationGroups;
+  /**
+   * Handler for the `POST /api/nodes/placement` operation.
+   */
+  dryRunPlacement: typeof handleDryRunPlacement;
+  /**
+   * Handler for the `POST /api/nodes/{uuid}/enrollment` operation.
+   */
+  createNodeEnrollment: typeof handleCreateNodeEnrollment;
+  /**
+   * Handler for the `POST /api/nodes/test-connection` operation.
+   */
+  testNodeConnection: typeof handleTestNodeConnection;
+  /**
+   * Handler for the `POST /api/nodes/{uuid}/diagnose` operation.
+   */
+  diagnoseNode: typeof handleDiagnoseNode;
+  /**
+   * Handler for the `GET /api/alerts` operation.
+   */
+  listAlerts: typeof handleListAlerts;
+  /**
+   * Handler for the `DELETE /api/alerts/rules` operation.
+   */
+  deleteAlertRule: typeof handleDeleteAlertRule;
+  /**
+   * Handler for the `GET /api/alerts/rules` operation.
+   */
+  listAlertRules: typeof handleListAlertRules;
+  /**
+   * Handler for the `POST /api/alerts/rules` operation.
+   */
+  createAlertRule: typeof handleCreateAlertRule;
+  /**
+   * Handler for the `POST /api/alerts/rules/{uuid}` operation.
+   */
+  updateAlertRule: typeof handleUpdateAlertRule;
+  /**
+   * Handler for the `DELETE /api/alerts/channels` operation.
+   */
+  deleteAlertChannel: typeof handleDeleteAlertChannel;
+  /**
+   * Handler for the `GET /api/alerts/channels` operation.
+   */
+  listAlertChannels: typeof handleListAlertChannels;
+  /**
+   * Handler for the `POST /api/alerts/channels` operation.
+   */
+  createAlertChannel: typeof handleCreateAlertChannel;
+  /**
+   * Handler for the `POST /api/alerts/channels/{uuid}/test` operation.
+   */
+  testAlertChannel: typeof handleTestAlertChannel;
+  /**
+   * Handler for the `DELETE /api/webhooks` operation.
+   */
+  deleteWebhook: typeof handleDeleteWebhook;
+  /**
+   * Handler for the `GET /api/webhooks` operation.
+   */
+  listWebhooks: typeof handleListWebhooks;
+  /**
+   * Handler for the `POST /api/webhooks` operation.
+   */
+  createWebhook: typeof handleCreateWebhook;
+  /**
+   * Handler for the `POST /api/webhooks/{uuid}` operation.
+   */
+  updateWebhook: typeof handleUpdateWebhook;
+  /**
+   * Handler for the `POST /api/webhooks/{uuid}/ping` operation.
+   */
+  pingWebhook: typeof handlePingWebhook;
+  /**
+   * Handler for the `GET /api/webhooks/{uuid}/deliveries` operation.
+   */
+  listWebhookDeliveries: typeof handleListWebhookDeliveries;
 };
 
 export type CreateMswHandlersResult = {
@@ -1997,7 +3560,12 @@ export function createMswHandlers(
     resetUserPassword: wrap(handleResetUserPassword),
     changeUserEmail: wrap(handleChangeUserEmail),
     convertOidcUser: wrap(handleConvertOidcUser),
+    deleteSshKey: wrap(handleDeleteSshKey),
+    listSshKeys: wrap(handleListSshKeys),
+    addSshKey: wrap(handleAddSshKey),
     getGeneralSettings: wrap(handleGetGeneralSettings),
+    getRetentionSettings: wrap(handleGetRetentionSettings),
+    saveRetentionSettings: wrap(handleSaveRetentionSettings),
     getUserSettings: wrap(handleGetUserSettings),
     saveUserSettings: wrap(handleSaveUserSettings),
     getMailSettings: wrap(handleGetMailSettings),
@@ -2011,11 +3579,41 @@ export function createMswHandlers(
     editGroup: wrap(handleEditGroup),
     groupInfo: wrap(handleGroupInfo),
     listUsersSimple: wrap(handleListUsersSimple),
+    revokeApiKey: wrap(handleRevokeApiKey),
+    listApiKeys: wrap(handleListApiKeys),
+    createApiKey: wrap(handleCreateApiKey),
+    apiKeyUsage: wrap(handleApiKeyUsage),
     deleteNode: wrap(handleDeleteNode),
     listNodes: wrap(handleListNodes),
     createNode: wrap(handleCreateNode),
     nodeInfo: wrap(handleNodeInfo),
-    updateNode: wrap(handleUpdateNode)
+    updateNode: wrap(handleUpdateNode),
+    setNodeMaintenance: wrap(handleSetNodeMaintenance),
+    nodeHistory: wrap(handleNodeHistory),
+    deleteLocation: wrap(handleDeleteLocation),
+    listLocations: wrap(handleListLocations),
+    createLocation: wrap(handleCreateLocation),
+    updateLocation: wrap(handleUpdateLocation),
+    setLocationGroups: wrap(handleSetLocationGroups),
+    dryRunPlacement: wrap(handleDryRunPlacement),
+    createNodeEnrollment: wrap(handleCreateNodeEnrollment),
+    testNodeConnection: wrap(handleTestNodeConnection),
+    diagnoseNode: wrap(handleDiagnoseNode),
+    listAlerts: wrap(handleListAlerts),
+    deleteAlertRule: wrap(handleDeleteAlertRule),
+    listAlertRules: wrap(handleListAlertRules),
+    createAlertRule: wrap(handleCreateAlertRule),
+    updateAlertRule: wrap(handleUpdateAlertRule),
+    deleteAlertChannel: wrap(handleDeleteAlertChannel),
+    listAlertChannels: wrap(handleListAlertChannels),
+    createAlertChannel: wrap(handleCreateAlertChannel),
+    testAlertChannel: wrap(handleTestAlertChannel),
+    deleteWebhook: wrap(handleDeleteWebhook),
+    listWebhooks: wrap(handleListWebhooks),
+    createWebhook: wrap(handleCreateWebhook),
+    updateWebhook: wrap(handleUpdateWebhook),
+    pingWebhook: wrap(handlePingWebhook),
+    listWebhookDeliveries: wrap(handleListWebhookDeliveries)
   };
   const all: CreateMswHandlersResult['all'] = (options = {}) => {
     type OverrideValue<R> = R | [response?: R, options?: RequestHandlerOptions];
@@ -2028,6 +3626,8 @@ export function createMswHandlers(
     const overrides = options.pick ?? {};
     return [
       invoke(pick.avatarById, overrides.avatarById),
+      invoke(pick.setLocationGroups, overrides.setLocationGroups),
+      invoke(pick.testAlertChannel, overrides.testAlertChannel),
       invoke(pick.updatePassword, overrides.updatePassword),
       invoke(pick.startEmailChange, overrides.startEmailChange),
       invoke(pick.updateAccount, overrides.updateAccount),
@@ -2042,6 +3642,15 @@ export function createMswHandlers(
       invoke(pick.sendResetLink, overrides.sendResetLink),
       invoke(pick.resetPassword, overrides.resetPassword),
       invoke(pick.userInfo, overrides.userInfo),
+      invoke(pick.apiKeyUsage, overrides.apiKeyUsage),
+      invoke(pick.setNodeMaintenance, overrides.setNodeMaintenance),
+      invoke(pick.nodeHistory, overrides.nodeHistory),
+      invoke(pick.updateLocation, overrides.updateLocation),
+      invoke(pick.createNodeEnrollment, overrides.createNodeEnrollment),
+      invoke(pick.diagnoseNode, overrides.diagnoseNode),
+      invoke(pick.updateAlertRule, overrides.updateAlertRule),
+      invoke(pick.pingWebhook, overrides.pingWebhook),
+      invoke(pick.listWebhookDeliveries, overrides.listWebhookDeliveries),
       invoke(pick.getOidcSettings, overrides.getOidcSettings),
       invoke(pick.initOidc, overrides.initOidc),
       invoke(pick.key, overrides.key),
@@ -2054,25 +3663,49 @@ export function createMswHandlers(
       invoke(pick.listUsers, overrides.listUsers),
       invoke(pick.createUser, overrides.createUser),
       invoke(pick.editUser, overrides.editUser),
+      invoke(pick.deleteSshKey, overrides.deleteSshKey),
+      invoke(pick.listSshKeys, overrides.listSshKeys),
+      invoke(pick.addSshKey, overrides.addSshKey),
       invoke(pick.getGeneralSettings, overrides.getGeneralSettings),
+      invoke(pick.getRetentionSettings, overrides.getRetentionSettings),
+      invoke(pick.saveRetentionSettings, overrides.saveRetentionSettings),
       invoke(pick.getUserSettings, overrides.getUserSettings),
       invoke(pick.saveUserSettings, overrides.saveUserSettings),
       invoke(pick.getMailSettings, overrides.getMailSettings),
       invoke(pick.saveMailSettings, overrides.saveMailSettings),
       invoke(pick.testMail, overrides.testMail),
       invoke(pick.listUsersSimple, overrides.listUsersSimple),
+      invoke(pick.deleteLocation, overrides.deleteLocation),
+      invoke(pick.listLocations, overrides.listLocations),
+      invoke(pick.createLocation, overrides.createLocation),
+      invoke(pick.dryRunPlacement, overrides.dryRunPlacement),
+      invoke(pick.testNodeConnection, overrides.testNodeConnection),
+      invoke(pick.deleteAlertRule, overrides.deleteAlertRule),
+      invoke(pick.listAlertRules, overrides.listAlertRules),
+      invoke(pick.createAlertRule, overrides.createAlertRule),
+      invoke(pick.deleteAlertChannel, overrides.deleteAlertChannel),
+      invoke(pick.listAlertChannels, overrides.listAlertChannels),
+      invoke(pick.createAlertChannel, overrides.createAlertChannel),
       invoke(pick.groupInfo, overrides.groupInfo),
       invoke(pick.nodeInfo, overrides.nodeInfo),
       invoke(pick.updateNode, overrides.updateNode),
+      invoke(pick.updateWebhook, overrides.updateWebhook),
       invoke(pick.isSetup, overrides.isSetup),
       invoke(pick.completeSetup, overrides.completeSetup),
       invoke(pick.deleteGroup, overrides.deleteGroup),
       invoke(pick.listGroups, overrides.listGroups),
       invoke(pick.createGroup, overrides.createGroup),
       invoke(pick.editGroup, overrides.editGroup),
+      invoke(pick.revokeApiKey, overrides.revokeApiKey),
+      invoke(pick.listApiKeys, overrides.listApiKeys),
+      invoke(pick.createApiKey, overrides.createApiKey),
       invoke(pick.deleteNode, overrides.deleteNode),
       invoke(pick.listNodes, overrides.listNodes),
-      invoke(pick.createNode, overrides.createNode)
+      invoke(pick.createNode, overrides.createNode),
+      invoke(pick.listAlerts, overrides.listAlerts),
+      invoke(pick.deleteWebhook, overrides.deleteWebhook),
+      invoke(pick.listWebhooks, overrides.listWebhooks),
+      invoke(pick.createWebhook, overrides.createWebhook)
     ];
   };
   return { all, pick };
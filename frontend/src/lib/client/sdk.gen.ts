@@ -8,7 +8,22 @@ import type {
   TDataShape
 } from './client';
 import { client } from './client.gen';
+import {
+  apiKeyUsageResponseTransformer,
+  createNodeEnrollmentResponseTransformer,
+  listAlertsResponseTransformer,
+  listApiKeysResponseTransformer,
+  listSshKeysResponseTransformer,
+  listWebhookDeliveriesResponseTransformer,
+  nodeHistoryResponseTransformer
+} from './transformers.gen';
 import type {
+  AddSshKeyData,
+  AddSshKeyErrors,
+  AddSshKeyResponses,
+  ApiKeyUsageData,
+  ApiKeyUsageErrors,
+  ApiKeyUsageResponses,
   AuthConfigData,
   AuthConfigErrors,
   AuthConfigResponses,
@@ -28,24 +43,63 @@ import type {
   ConvertOidcUserData,
   ConvertOidcUserErrors,
   ConvertOidcUserResponses,
+  CreateAlertChannelData,
+  CreateAlertChannelErrors,
+  CreateAlertChannelResponses,
+  CreateAlertRuleData,
+  CreateAlertRuleErrors,
+  CreateAlertRuleResponses,
+  CreateApiKeyData,
+  CreateApiKeyErrors,
+  CreateApiKeyResponses,
   CreateGroupData,
   CreateGroupErrors,
   CreateGroupResponses,
+  CreateLocationData,
+  CreateLocationErrors,
+  CreateLocationResponses,
   CreateNodeData,
+  CreateNodeEnrollmentData,
+  CreateNodeEnrollmentErrors,
+  CreateNodeEnrollmentResponses,
   CreateNodeErrors,
   CreateNodeResponses,
   CreateUserData,
   CreateUserErrors,
   CreateUserResponses,
+  CreateWebhookData,
+  CreateWebhookErrors,
+  CreateWebhookResponses,
+  DeleteAlertChannelData,
+  DeleteAlertChannelErrors,
+  DeleteAlertChannelResponses,
+  DeleteAlertRuleData,
+  DeleteAlertRuleErrors,
+  DeleteAlertRuleResponses,
   DeleteGroupData,
   DeleteGroupErrors,
   DeleteGroupResponses,
+  DeleteLocationData,
+  DeleteLocationErrors,
+  DeleteLocationResponses,
   DeleteNodeData,
   DeleteNodeErrors,
   DeleteNodeResponses,
+  DeleteSshKeyData,
+  DeleteSshKeyErrors,
+  DeleteSshKeyResponses,
   DeleteUserData,
   DeleteUserErrors,
   DeleteUserResponses,
+  DeleteWebhookData,
+  DeleteWebhookErrors,
+  DeleteWebhookResponses,
+  DiagnoseNodeData,
+  DiagnoseNodeErrors,
+  DiagnoseNodeResponses,
+  DryRunPlacementData,
+  DryRunPlacementErrors,
+  DryRunPlacementResponses,
   EditGroupData,
   EditGroupErrors,
   EditGroupResponses,
@@ -61,6 +115,9 @@ import type {
   GetOidcSettingsData,
   GetOidcSettingsErrors,
   GetOidcSettingsResponses,
+  GetRetentionSettingsData,
+  GetRetentionSettingsErrors,
+  GetRetentionSettingsResponses,
   GetUserSettingsData,
   GetUserSettingsErrors,
   GetUserSettingsResponses,
@@ -78,29 +135,59 @@ import type {
   IsSetupResponses,
   KeyData,
   KeyResponses,
+  ListAlertChannelsData,
+  ListAlertChannelsErrors,
+  ListAlertChannelsResponses,
+  ListAlertRulesData,
+  ListAlertRulesErrors,
+  ListAlertRulesResponses,
+  ListAlertsData,
+  ListAlertsErrors,
+  ListAlertsResponses,
+  ListApiKeysData,
+  ListApiKeysErrors,
+  ListApiKeysResponses,
   ListGroupsData,
   ListGroupsErrors,
   ListGroupsResponses,
   ListGroupsSimpleData,
   ListGroupsSimpleErrors,
   ListGroupsSimpleResponses,
+  ListLocationsData,
+  ListLocationsErrors,
+  ListLocationsResponses,
   ListNodesData,
   ListNodesErrors,
   ListNodesResponses,
+  ListSshKeysData,
+  ListSshKeysErrors,
+  ListSshKeysResponses,
   ListUsersData,
   ListUsersErrors,
   ListUsersResponses,
   ListUsersSimpleData,
   ListUsersSimpleErrors,
   ListUsersSimpleResponses,
+  ListWebhookDeliveriesData,
+  ListWebhookDeliveriesErrors,
+  ListWebhookDeliveriesResponses,
+  ListWebhooksData,
+  ListWebhooksErrors,
+  ListWebhooksResponses,
   LogoutData,
   LogoutErrors,
   MailActiveData,
   MailActiveErrors,
   MailActiveResponses,
+  NodeHistoryData,
+  NodeHistoryErrors,
+  NodeHistoryResponses,
   NodeInfoData,
   NodeInfoErrors,
   NodeInfoResponses,
+  PingWebhookData,
+  PingWebhookErrors,
+  PingWebhookResponses,
   ResetPasswordData,
   ResetPasswordErrors,
   ResetPasswordResponses,
@@ -110,34 +197,61 @@ import type {
   ResetUserPasswordData,
   ResetUserPasswordErrors,
   ResetUserPasswordResponses,
+  RevokeApiKeyData,
+  RevokeApiKeyErrors,
+  RevokeApiKeyResponses,
   SaveMailSettingsData,
   SaveMailSettingsErrors,
   SaveMailSettingsResponses,
+  SaveRetentionSettingsData,
+  SaveRetentionSettingsErrors,
+  SaveRetentionSettingsResponses,
   SaveUserSettingsData,
   SaveUserSettingsErrors,
   SaveUserSettingsResponses,
   SendResetLinkData,
   SendResetLinkErrors,
   SendResetLinkResponses,
+  SetLocationGroupsData,
+  SetLocationGroupsErrors,
+  SetLocationGroupsResponses,
+  SetNodeMaintenanceData,
+  SetNodeMaintenanceErrors,
+  SetNodeMaintenanceResponses,
   StartEmailChangeData,
   StartEmailChangeErrors,
   StartEmailChangeResponses,
+  TestAlertChannelData,
+  TestAlertChannelErrors,
+  TestAlertChannelResponses,
   TestMailData,
   TestMailErrors,
   TestMailResponses,
+  TestNodeConnectionData,
+  TestNodeConnectionErrors,
+  TestNodeConnectionResponses,
   TestTokenData,
   UpdateAccountData,
   UpdateAccountErrors,
   UpdateAccountResponses,
+  UpdateAlertRuleData,
+  UpdateAlertRuleErrors,
+  UpdateAlertRuleResponses,
   UpdateAvatarData,
   UpdateAvatarErrors,
   UpdateAvatarResponses,
+  UpdateLocationData,
+  UpdateLocationErrors,
+  UpdateLocationResponses,
   UpdateNodeData,
   UpdateNodeErrors,
   UpdateNodeResponses,
   UpdatePasswordData,
   UpdatePasswordErrors,
   UpdatePasswordResponses,
+  UpdateWebhookData,
+  UpdateWebhookErrors,
+  UpdateWebhookResponses,
   UserInfoData,
   UserInfoErrors,
   UserInfoResponses
@@ -529,6 +643,51 @@ export const convertOidcUser = <ThrowOnError extends boolean = false>(
     }
   });
 
+export const deleteSshKey = <ThrowOnError extends boolean = false>(
+  options: Options<DeleteSshKeyData, ThrowOnError>
+): RequestResult<DeleteSshKeyResponses, DeleteSshKeyErrors, ThrowOnError> =>
+  (options.client ?? client).delete<
+    DeleteSshKeyResponses,
+    DeleteSshKeyErrors,
+    ThrowOnError
+  >({
+    url: '/api/user/ssh_keys',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const listSshKeys = <ThrowOnError extends boolean = false>(
+  options?: Options<ListSshKeysData, ThrowOnError>
+): RequestResult<ListSshKeysResponses, ListSshKeysErrors, ThrowOnError> =>
+  (options?.client ?? client).get<
+    ListSshKeysResponses,
+    ListSshKeysErrors,
+    ThrowOnError
+  >({
+    responseTransformer: listSshKeysResponseTransformer,
+    url: '/api/user/ssh_keys',
+    ...options
+  });
+
+export const addSshKey = <ThrowOnError extends boolean = false>(
+  options: Options<AddSshKeyData, ThrowOnError>
+): RequestResult<AddSshKeyResponses, AddSshKeyErrors, ThrowOnError> =>
+  (options.client ?? client).post<
+    AddSshKeyResponses,
+    AddSshKeyErrors,
+    ThrowOnError
+  >({
+    url: '/api/user/ssh_keys',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
 export const getGeneralSettings = <ThrowOnError extends boolean = false>(
   options?: Options<GetGeneralSettingsData, ThrowOnError>
 ): RequestResult<
@@ -542,6 +701,39 @@ export const getGeneralSettings = <ThrowOnError extends boolean = false>(
     ThrowOnError
   >({ url: '/api/settings/general', ...options });
 
+export const getRetentionSettings = <ThrowOnError extends boolean = false>(
+  options?: Options<GetRetentionSettingsData, ThrowOnError>
+): RequestResult<
+  GetRetentionSettingsResponses,
+  GetRetentionSettingsErrors,
+  ThrowOnError
+> =>
+  (options?.client ?? client).get<
+    GetRetentionSettingsResponses,
+    GetRetentionSettingsErrors,
+    ThrowOnError
+  >({ url: '/api/settings/retention', ...options });
+
+export const saveRetentionSettings = <ThrowOnError extends boolean = false>(
+  options: Options<SaveRetentionSettingsData, ThrowOnError>
+): RequestResult<
+  SaveRetentionSettingsResponses,
+  SaveRetentionSettingsErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).post<
+    SaveRetentionSettingsResponses,
+    SaveRetentionSettingsErrors,
+    ThrowOnError
+  >({
+    url: '/api/settings/retention',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
 export const getUserSettings = <ThrowOnError extends boolean = false>(
   options?: Options<GetUserSettingsData, ThrowOnError>
 ): RequestResult<
@@ -728,6 +920,64 @@ export const listUsersSimple = <ThrowOnError extends boolean = false>(
     ThrowOnError
   >({ url: '/api/group/users', ...options });
 
+export const revokeApiKey = <ThrowOnError extends boolean = false>(
+  options: Options<RevokeApiKeyData, ThrowOnError>
+): RequestResult<RevokeApiKeyResponses, RevokeApiKeyErrors, ThrowOnError> =>
+  (options.client ?? client).delete<
+    RevokeApiKeyResponses,
+    RevokeApiKeyErrors,
+    ThrowOnError
+  >({
+    url: '/api/api_keys',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const listApiKeys = <ThrowOnError extends boolean = false>(
+  options?: Options<ListApiKeysData, ThrowOnError>
+): RequestResult<ListApiKeysResponses, ListApiKeysErrors, ThrowOnError> =>
+  (options?.client ?? client).get<
+    ListApiKeysResponses,
+    ListApiKeysErrors,
+    ThrowOnError
+  >({
+    responseTransformer: listApiKeysResponseTransformer,
+    url: '/api/api_keys',
+    ...options
+  });
+
+export const createApiKey = <ThrowOnError extends boolean = false>(
+  options: Options<CreateApiKeyData, ThrowOnError>
+): RequestResult<CreateApiKeyResponses, CreateApiKeyErrors, ThrowOnError> =>
+  (options.client ?? client).post<
+    CreateApiKeyResponses,
+    CreateApiKeyErrors,
+    ThrowOnError
+  >({
+    url: '/api/api_keys',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const apiKeyUsage = <ThrowOnError extends boolean = false>(
+  options: Options<ApiKeyUsageData, ThrowOnError>
+): RequestResult<ApiKeyUsageResponses, ApiKeyUsageErrors, ThrowOnError> =>
+  (options.client ?? client).get<
+    ApiKeyUsageResponses,
+    ApiKeyUsageErrors,
+    ThrowOnError
+  >({
+    responseTransformer: apiKeyUsageResponseTransformer,
+    url: '/api/api_keys/{uuid}/usage',
+    ...options
+  });
+
 export const deleteNode = <ThrowOnError extends boolean = false>(
   options: Options<DeleteNodeData, ThrowOnError>
 ): RequestResult<DeleteNodeResponses, DeleteNodeErrors, ThrowOnError> =>
@@ -793,3 +1043,410 @@ export const updateNode = <ThrowOnError extends boolean = false>(
       ...options.headers
     }
   });
+
+export const setNodeMaintenance = <ThrowOnError extends boolean = false>(
+  options: Options<SetNodeMaintenanceData, ThrowOnError>
+): RequestResult<
+  SetNodeMaintenanceResponses,
+  SetNodeMaintenanceErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).post<
+    SetNodeMaintenanceResponses,
+    SetNodeMaintenanceErrors,
+    ThrowOnError
+  >({
+    url: '/api/nodes/{uuid}/maintenance',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const nodeHistory = <ThrowOnError extends boolean = false>(
+  options: Options<NodeHistoryData, ThrowOnError>
+): RequestResult<NodeHistoryResponses, NodeHistoryErrors, ThrowOnError> =>
+  (options.client ?? client).get<
+    NodeHistoryResponses,
+    NodeHistoryErrors,
+    ThrowOnError
+  >({
+    responseTransformer: nodeHistoryResponseTransformer,
+    url: '/api/nodes/{uuid}/history',
+    ...options
+  });
+
+export const deleteLocation = <ThrowOnError extends boolean = false>(
+  options: Options<DeleteLocationData, ThrowOnError>
+): RequestResult<DeleteLocationResponses, DeleteLocationErrors, ThrowOnError> =>
+  (options.client ?? client).delete<
+    DeleteLocationResponses,
+    DeleteLocationErrors,
+    ThrowOnError
+  >({
+    url: '/api/nodes/locations',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const listLocations = <ThrowOnError extends boolean = false>(
+  options?: Options<ListLocationsData, ThrowOnError>
+): RequestResult<ListLocationsResponses, ListLocationsErrors, ThrowOnError> =>
+  (options?.client ?? client).get<
+    ListLocationsResponses,
+    ListLocationsErrors,
+    ThrowOnError
+  >({ url: '/api/nodes/locations', ...options });
+
+export const createLocation = <ThrowOnError extends boolean = false>(
+  options: Options<CreateLocationData, ThrowOnError>
+): RequestResult<CreateLocationResponses, CreateLocationErrors, ThrowOnError> =>
+  (options.client ?? client).post<
+    CreateLocationResponses,
+    CreateLocationErrors,
+    ThrowOnError
+  >({
+    url: '/api/nodes/locations',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const updateLocation = <ThrowOnError extends boolean = false>(
+  options: Options<UpdateLocationData, ThrowOnError>
+): RequestResult<UpdateLocationResponses, UpdateLocationErrors, ThrowOnError> =>
+  (options.client ?? client).post<
+    UpdateLocationResponses,
+    UpdateLocationErrors,
+    ThrowOnError
+  >({
+    url: '/api/nodes/locations/{uuid}',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const setLocationGroups = <ThrowOnError extends boolean = false>(
+  options: Options<SetLocationGroupsData, ThrowOnError>
+): RequestResult<
+  SetLocationGroupsResponses,
+  SetLocationGroupsErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).post<
+    SetLocationGroupsResponses,
+    SetLocationGroupsErrors,
+    ThrowOnError
+  >({
+    url: '/api/nodes/locations/{uuid}/groups',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const dryRunPlacement = <ThrowOnError extends boolean = false>(
+  options: Options<DryRunPlacementData, ThrowOnError>
+): RequestResult<
+  DryRunPlacementResponses,
+  DryRunPlacementErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).post<
+    DryRunPlacementResponses,
+    DryRunPlacementErrors,
+    ThrowOnError
+  >({
+    url: '/api/nodes/placement',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const createNodeEnrollment = <ThrowOnError extends boolean = false>(
+  options: Options<CreateNodeEnrollmentData, ThrowOnError>
+): RequestResult<
+  CreateNodeEnrollmentResponses,
+  CreateNodeEnrollmentErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).post<
+    CreateNodeEnrollmentResponses,
+    CreateNodeEnrollmentErrors,
+    ThrowOnError
+  >({
+    responseTransformer: createNodeEnrollmentResponseTransformer,
+    url: '/api/nodes/{uuid}/enrollment',
+    ...options
+  });
+
+export const testNodeConnection = <ThrowOnError extends boolean = false>(
+  options: Options<TestNodeConnectionData, ThrowOnError>
+): RequestResult<
+  TestNodeConnectionResponses,
+  TestNodeConnectionErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).post<
+    TestNodeConnectionResponses,
+    TestNodeConnectionErrors,
+    ThrowOnError
+  >({
+    url: '/api/nodes/test-connection',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const diagnoseNode = <ThrowOnError extends boolean = false>(
+  options: Options<DiagnoseNodeData, ThrowOnError>
+): RequestResult<DiagnoseNodeResponses, DiagnoseNodeErrors, ThrowOnError> =>
+  (options.client ?? client).post<
+    DiagnoseNodeResponses,
+    DiagnoseNodeErrors,
+    ThrowOnError
+  >({ url: '/api/nodes/{uuid}/diagnose', ...options });
+
+export const listAlerts = <ThrowOnError extends boolean = false>(
+  options?: Options<ListAlertsData, ThrowOnError>
+): RequestResult<ListAlertsResponses, ListAlertsErrors, ThrowOnError> =>
+  (options?.client ?? client).get<
+    ListAlertsResponses,
+    ListAlertsErrors,
+    ThrowOnError
+  >({
+    responseTransformer: listAlertsResponseTransformer,
+    url: '/api/alerts',
+    ...options
+  });
+
+export const deleteAlertRule = <ThrowOnError extends boolean = false>(
+  options: Options<DeleteAlertRuleData, ThrowOnError>
+): RequestResult<
+  DeleteAlertRuleResponses,
+  DeleteAlertRuleErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).delete<
+    DeleteAlertRuleResponses,
+    DeleteAlertRuleErrors,
+    ThrowOnError
+  >({
+    url: '/api/alerts/rules',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const listAlertRules = <ThrowOnError extends boolean = false>(
+  options?: Options<ListAlertRulesData, ThrowOnError>
+): RequestResult<ListAlertRulesResponses, ListAlertRulesErrors, ThrowOnError> =>
+  (options?.client ?? client).get<
+    ListAlertRulesResponses,
+    ListAlertRulesErrors,
+    ThrowOnError
+  >({ url: '/api/alerts/rules', ...options });
+
+export const createAlertRule = <ThrowOnError extends boolean = false>(
+  options: Options<CreateAlertRuleData, ThrowOnError>
+): RequestResult<
+  CreateAlertRuleResponses,
+  CreateAlertRuleErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).post<
+    CreateAlertRuleResponses,
+    CreateAlertRuleErrors,
+    ThrowOnError
+  >({
+    url: '/api/alerts/rules',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const updateAlertRule = <ThrowOnError extends boolean = false>(
+  options: Options<UpdateAlertRuleData, ThrowOnError>
+): RequestResult<
+  UpdateAlertRuleResponses,
+  UpdateAlertRuleErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).post<
+    UpdateAlertRuleResponses,
+    UpdateAlertRuleErrors,
+    ThrowOnError
+  >({
+    url: '/api/alerts/rules/{uuid}',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const deleteAlertChannel = <ThrowOnError extends boolean = false>(
+  options: Options<DeleteAlertChannelData, ThrowOnError>
+): RequestResult<
+  DeleteAlertChannelResponses,
+  DeleteAlertChannelErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).delete<
+    DeleteAlertChannelResponses,
+    DeleteAlertChannelErrors,
+    ThrowOnError
+  >({
+    url: '/api/alerts/channels',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const listAlertChannels = <ThrowOnError extends boolean = false>(
+  options?: Options<ListAlertChannelsData, ThrowOnError>
+): RequestResult<
+  ListAlertChannelsResponses,
+  ListAlertChannelsErrors,
+  ThrowOnError
+> =>
+  (options?.client ?? client).get<
+    ListAlertChannelsResponses,
+    ListAlertChannelsErrors,
+    ThrowOnError
+  >({ url: '/api/alerts/channels', ...options });
+
+export const createAlertChannel = <ThrowOnError extends boolean = false>(
+  options: Options<CreateAlertChannelData, ThrowOnError>
+): RequestResult<
+  CreateAlertChannelResponses,
+  CreateAlertChannelErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).post<
+    CreateAlertChannelResponses,
+    CreateAlertChannelErrors,
+    ThrowOnError
+  >({
+    url: '/api/alerts/channels',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const testAlertChannel = <ThrowOnError extends boolean = false>(
+  options: Options<TestAlertChannelData, ThrowOnError>
+): RequestResult<
+  TestAlertChannelResponses,
+  TestAlertChannelErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).post<
+    TestAlertChannelResponses,
+    TestAlertChannelErrors,
+    ThrowOnError
+  >({ url: '/api/alerts/channels/{uuid}/test', ...options });
+
+export const deleteWebhook = <ThrowOnError extends boolean = false>(
+  options: Options<DeleteWebhookData, ThrowOnError>
+): RequestResult<DeleteWebhookResponses, DeleteWebhookErrors, ThrowOnError> =>
+  (options.client ?? client).delete<
+    DeleteWebhookResponses,
+    DeleteWebhookErrors,
+    ThrowOnError
+  >({
+    url: '/api/webhooks',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const listWebhooks = <ThrowOnError extends boolean = false>(
+  options?: Options<ListWebhooksData, ThrowOnError>
+): RequestResult<ListWebhooksResponses, ListWebhooksErrors, ThrowOnError> =>
+  (options?.client ?? client).get<
+    ListWebhooksResponses,
+    ListWebhooksErrors,
+    ThrowOnError
+  >({ url: '/api/webhooks', ...options });
+
+export const createWebhook = <ThrowOnError extends boolean = false>(
+  options: Options<CreateWebhookData, ThrowOnError>
+): RequestResult<CreateWebhookResponses, CreateWebhookErrors, ThrowOnError> =>
+  (options.client ?? client).post<
+    CreateWebhookResponses,
+    CreateWebhookErrors,
+    ThrowOnError
+  >({
+    url: '/api/webhooks',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const updateWebhook = <ThrowOnError extends boolean = false>(
+  options: Options<UpdateWebhookData, ThrowOnError>
+): RequestResult<UpdateWebhookResponses, UpdateWebhookErrors, ThrowOnError> =>
+  (options.client ?? client).post<
+    UpdateWebhookResponses,
+    UpdateWebhookErrors,
+    ThrowOnError
+  >({
+    url: '/api/webhooks/{uuid}',
+    ...options,
+    headers: {
+      'Content-Type': 'application/json',
+      ...options.headers
+    }
+  });
+
+export const pingWebhook = <ThrowOnError extends boolean = false>(
+  options: Options<PingWebhookData, ThrowOnError>
+): RequestResult<PingWebhookResponses, PingWebhookErrors, ThrowOnError> =>
+  (options.client ?? client).post<
+    PingWebhookResponses,
+    PingWebhookErrors,
+    ThrowOnError
+  >({ url: '/api/webhooks/{uuid}/ping', ...options });
+
+export const listWebhookDeliveries = <ThrowOnError extends boolean = false>(
+  options: Options<ListWebhookDeliveriesData, ThrowOnError>
+): RequestResult<
+  ListWebhookDeliveriesResponses,
+  ListWebhookDeliveriesErrors,
+  ThrowOnError
+> =>
+  (options.client ?? client).get<
+    ListWebhookDeliveriesResponses,
+    ListWebhookDeliveriesErrors,
+    ThrowOnError
+  >({
+    responseTransformer: listWebhookDeliveriesResponseTransformer,
+    url: '/api/webhooks/{uuid}/deliveries',
+    ...options
+  });
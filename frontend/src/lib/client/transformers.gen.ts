@@ -0,0 +1,122 @@
+// This file is auto-generated by @hey-api/openapi-ts
+
+import type {
+  ApiKeyUsageResponse,
+  CreateNodeEnrollmentResponse,
+  ListAlertsResponse,
+  ListApiKeysResponse,
+  ListSshKeysResponse,
+  ListWebhookDeliveriesResponse,
+  NodeHistoryResponse
+} from './types.gen';
+
+const sshKeyInfoSchemaResponseTransformer = (data: any) => {
+  data.created_at = new Date(data.created_at);
+  return data;
+};
+
+export const listSshKeysResponseTransformer = async (
+  data: any
+): Promise<ListSshKeysResponse> => {
+  data = data.map((item: any) => {
+    return sshKeyInfoSchemaResponseTransformer(item);
+  });
+  return data;
+};
+
+const apiKeyInfoSchemaResponseTransformer = (data: any) => {
+  data.created_at = new Date(data.created_at);
+  if (data.expires_at) {
+    data.expires_at = new Date(data.expires_at);
+  }
+  if (data.last_used_at) {
+    data.last_used_at = new Date(data.last_used_at);
+  }
+  return data;
+};
+
+export const listApiKeysResponseTransformer = async (
+  data: any
+): Promise<ListApiKeysResponse> => {
+  data = data.map((item: any) => {
+    return apiKeyInfoSchemaResponseTransformer(item);
+  });
+  return data;
+};
+
+const apiKeyUsageSchemaResponseTransformer = (data: any) => {
+  data.created_at = new Date(data.created_at);
+  return data;
+};
+
+export const apiKeyUsageResponseTransformer = async (
+  data: any
+): Promise<ApiKeyUsageResponse> => {
+  data = data.map((item: any) => {
+    return apiKeyUsageSchemaResponseTransformer(item);
+  });
+  return data;
+};
+
+const historySampleSchemaResponseTransformer = (data: any) => {
+  data.timestamp = new Date(data.timestamp);
+  return data;
+};
+
+const nodeHistorySchemaResponseTransformer = (data: any) => {
+  data.samples = data.samples.map((item: any) => {
+    return historySampleSchemaResponseTransformer(item);
+  });
+  return data;
+};
+
+export const nodeHistoryResponseTransformer = async (
+  data: any
+): Promise<NodeHistoryResponse> => {
+  data = nodeHistorySchemaResponseTransformer(data);
+  return data;
+};
+
+const createEnrollmentResSchemaResponseTransformer = (data: any) => {
+  data.expires_at = new Date(data.expires_at);
+  return data;
+};
+
+export const createNodeEnrollmentResponseTransformer = async (
+  data: any
+): Promise<CreateNodeEnrollmentResponse> => {
+  data = createEnrollmentResSchemaResponseTransformer(data);
+  return data;
+};
+
+const alertInfoSchemaResponseTransformer = (data: any) => {
+  data.fired_at = new Date(data.fired_at);
+  if (data.resolved_at) {
+    data.resolved_at = new Date(data.resolved_at);
+  }
+  return data;
+};
+
+export const listAlertsResponseTransformer = async (
+  data: any
+): Promise<ListAlertsResponse> => {
+  data = data.map((item: any) => {
+    return alertInfoSchemaResponseTransformer(item);
+  });
+  return data;
+};
+
+const deliveryInfoSchemaResponseTransformer = (data: any) => {
+  data.created_at = new Date(data.created_at);
+  data.updated_at = new Date(data.updated_at);
+  return data;
+};
+
+export const listWebhookDeliveriesResponseTransformer = async (
+  data: any
+): Promise<ListWebhookDeliveriesResponse> => {
+  data = data.map((item: any) => {
+    return deliveryInfoSchemaResponseTransformer(item);
+  });
+  return data;
+};
@@ -8,6 +8,61 @@ export type AccountUpdate = {
   username: string;
 };
 
+export type AddSshKey = {
+  /**
+   * Public key in the OpenSSH `authorized_keys` format.
+   */
+  key: string;
+  /**
+   * Falls back to the comment of the key if empty.
+   */
+  name?: string;
+};
+
+export type AddSshKeyRes = {
+  fingerprint: string;
+  uuid: string;
+};
+
+export type AlertCondition =
+  | {
+      minutes: number;
+      type: 'node_disconnected';
+    }
+  | {
+      percent: number;
+      type: 'disk_usage';
+    };
+
+export type AlertInfo = {
+  fired_at: Date;
+  message: string;
+  node: string;
+  resolved_at?: Date | null;
+  rule: string;
+  uuid: string;
+};
+
+export type ApiKeyInfo = {
+  created_at: Date;
+  expires_at?: Date | null;
+  id: string;
+  last_used_at?: Date | null;
+  name: string;
+  permissions: Array<string>;
+  prefix: string;
+};
+
+export type ApiKeyRequest = {
+  uuid: string;
+};
+
+export type ApiKeyUsage = {
+  created_at: Date;
+  method: string;
+  path: string;
+};
+
 export type AuthConfig = {
   instant_redirect: boolean;
   mail_enabled: boolean;
@@ -27,23 +82,91 @@ export type ChangeUserEmail = {
   uuid: string;
 };
 
+export type ChannelInfo = {
+  kind: ChannelKind;
+  name: string;
+  target: string;
+  uuid: string;
+};
+
+export type ChannelKind = 'email' | 'webhook';
+
+export type CreateApiKey = {
+  expires_at?: Date | null;
+  name: string;
+  permissions: Array<string>;
+};
+
+export type CreateApiKeyRes = {
+  /**
+   * The full key, only returned once on creation.
+   */
+  key: string;
+  uuid: string;
+};
+
+export type CreateChannel = {
+  kind: ChannelKind;
+  name: string;
+  /**
+   * Email address or webhook URL depending on `kind`.
+   */
+  target: string;
+};
+
+export type CreateChannelRes = {
+  uuid: string;
+};
+
+export type CreateEnrollmentRes = {
+  /**
+   * Single use, wings exchanges it for the node token on startup.
+   */
+  code: string;
+  expires_at: Date;
+};
+
 export type CreateGroupRequest = {
   name: string;
 };
 
+export type CreateLocation = {
+  description?: string | null;
+  name: string;
+};
+
+export type CreateLocationRes = {
+  uuid: string;
+};
+
 export type CreateNode = {
   address: string;
   cpu_limit?: number | null;
   disk_limit_mb?: number | null;
+  location?: string | null;
   memory_limit_mb?: number | null;
   name: string;
+  /**
+   * Wings opens the websocket to the panel, for nodes the panel can not reach.
+   */
+  reverse_connect?: boolean;
   secure: boolean;
+  tags?: Array<string>;
+  /**
+   * PEM bundle of the CA that issued the certificate of the node.
+   */
+  tls_ca?: string | null;
+  tls_pin?: TlsPin | null;
 };
 
 export type CreateNodeRes = {
   uuid: string;
 };
 
+export type CreateRuleRes = {
+  uuid: string;
+};
+
 export type CreateUser = {
   email: string;
   name: string;
@@ -54,6 +177,18 @@ export type CreateUserResponse = {
   uuid: string;
 };
 
+export type CreateWebhookRes = {
+  /**
+   * Only returned once, used to verify the signature of deliveries.
+   */
+  secret: string;
+  uuid: string;
+};
+
+export type DeleteChannel = {
+  uuid: string;
+};
+
 export type DeleteGroupRequest = {
   uuid: string;
 };
@@ -62,10 +197,26 @@ export type DeleteNode = {
   uuid: string;
 };
 
+export type DeleteSshKey = {
+  uuid: string;
+};
+
 export type DeleteUserRequest = {
   uuid: string;
 };
 
+export type DeliveryInfo = {
+  attempts: number;
+  created_at: Date;
+  delivered: boolean;
+  error?: string | null;
+  event: string;
+  payload: string;
+  status_code?: number | null;
+  updated_at: Date;
+  uuid: string;
+};
+
 export type DetailUserInfo = {
   email: string;
   groups: Array<SimpleGroupInfo>;
@@ -75,6 +226,19 @@ export type DetailUserInfo = {
   uuid: string;
 };
 
+export type DiagnoseRequest = {
+  uuid: string;
+};
+
+export type Diagnosis = {
+  failed_step?: Step | null;
+  ok: boolean;
+  /**
+   * Every step in order, including skipped ones.
+   */
+  steps: Array<StepResult>;
+};
+
 export type EditGroupRequest = {
   name: string;
   permissions: Array<string>;
@@ -91,6 +255,10 @@ export type EmailChangeConfirm = {
   old_code: string;
 };
 
+export type EnrollmentRequest = {
+  uuid: string;
+};
+
 export type GeneralSettings = {
   site_url: string;
 };
@@ -122,6 +290,15 @@ export type GroupViewPath = {
   uuid: string;
 };
 
+export type HistorySample = {
+  cpu_percent: number;
+  disk_total_mb: number;
+  disk_used_mb: number;
+  memory_total_mb: number;
+  memory_used_mb: number;
+  timestamp: Date;
+};
+
 export type IsSetupResponse = {
   db_backend: string;
   is_setup: boolean;
@@ -131,11 +308,51 @@ export type KeyRes = {
   key: string;
 };
 
+export type ListAlerts = {
+  limit?: number | null;
+  /**
+   * Only alerts that are still firing.
+   */
+  open?: boolean;
+};
+
+export type ListDeliveries = {
+  limit?: number | null;
+};
+
 export type ListGroupResponse = {
   admin_group?: string | null;
   groups: Array<GroupInfo>;
 };
 
+export type ListNodes = {
+  connected?: boolean | null;
+  limit?: number | null;
+  location?: string | null;
+  /**
+   * Case insensitive substring of the node name.
+   */
+  name?: string | null;
+  offset?: number | null;
+  order?: SortOrder;
+  sort?: NodeSort;
+  tag?: string | null;
+};
+
+export type LocationInfo = {
+  description?: string | null;
+  /**
+   * Groups that may view the nodes of this location without `node:view`.
+   */
+  groups: Array<string>;
+  id: string;
+  name: string;
+};
+
+export type LocationRequest = {
+  uuid: string;
+};
+
 export type LoginReq = {
   email: string;
   password: string;
@@ -161,23 +378,103 @@ export type MailSettingsResponse = {
   settings: MailSettings;
 };
 
+export type NodeEvaluation = {
+  accepted: boolean;
+  id: string;
+  name: string;
+  /**
+   * Why the node was rejected, empty for accepted nodes.
+   */
+  reasons: Array<string>;
+  /**
+   * Utilization of the node after placing the server, between 0 and 1.
+   */
+  utilization: number;
+};
+
+export type NodeHistory = {
+  resolution: Resolution;
+  samples: Array<HistorySample>;
+};
+
+export type NodeHistoryQuery = {
+  /**
+   * Defaults to one hour before `to`.
+   */
+  from?: Date | null;
+  /**
+   * Defaults to the finest resolution that is still retained at `from`.
+   */
+  resolution?: Resolution | null;
+  /**
+   * Defaults to now.
+   */
+  to?: Date | null;
+};
+
+export type NodeHistoryRequest = {
+  uuid: string;
+};
+
 export type NodeInfo = {
   address: string;
   connected: boolean;
   cpu_limit?: number | null;
   disk_limit_mb?: number | null;
+  first_connected_at?: string | null;
   id: string;
+  last_seen_at?: string | null;
+  lifecycle: NodeLifecycle;
+  location?: string | null;
+  maintenance: boolean;
+  maintenance_message?: string | null;
   memory_limit_mb?: number | null;
   name: string;
   port: number;
+  reverse_connect: boolean;
   secure: boolean;
-  token: string;
+  /**
+   * Result of pushing the configuration to wings, `None` until it connected
+   * for the first time.
+   */
+  sync?: NodeSync | null;
+  tags: Array<string>;
+  tls_ca?: string | null;
+  tls_pin?: TlsPin | null;
+  /**
+   * Secret of the wings channel, only included for users with `node:edit`.
+   */
+  token?: string | null;
 };
 
 export type NodeInfoRequest = {
   uuid: string;
 };
 
+/**
+ * Where a node is in its setup, persisted so a node that was never set up
+ * can be told apart from one that went away.
+ */
+export type NodeLifecycle = 'connected' | 'pending_setup' | 'enrolled' | 'lost';
+
+export const NodeSort = {
+  NAME: 'name',
+  ADDRESS: 'address',
+  CONNECTED: 'connected'
+} as const;
+
+export type NodeSort = (typeof NodeSort)[keyof typeof NodeSort];
+
+export type NodeSync = {
+  /**
+   * What wings had configured differently before the last sync.
+   */
+  drift: Array<SyncDrift>;
+  requested_at: string;
+  status: SyncStatus;
+  synced_at?: string | null;
+};
+
 export type OidcCallbackQuery = {
   code?: string | null;
   error?: string | null;
@@ -199,6 +496,32 @@ export type PasswordUpdate = {
   old_password: string;
 };
 
+export type PingRes = {
+  delivery: string;
+};
+
+export type Placement = {
+  evaluations: Array<NodeEvaluation>;
+  node?: string | null;
+};
+
+/**
+ * Restricts which nodes are considered at all, independent of capacity.
+ */
+export type PlacementRequest = {
+  cpu: number;
+  disk_mb: number;
+  location?: string | null;
+  memory_mb: number;
+  strategy?: PlacementStrategy;
+  /**
+   * Nodes must carry all of these tags.
+   */
+  tags?: Array<string>;
+};
+
+export type PlacementStrategy = 'bin_pack' | 'spread';
+
 export type ResetPasswordPayload = {
   new_password: string;
   token: string;
@@ -213,10 +536,68 @@ export type ResetUserPassword = {
   uuid: string;
 };
 
+export const Resolution = {
+  RAW: 'raw',
+  MINUTE: 'minute',
+  QUARTER_HOUR: 'quarter_hour'
+} as const;
+
+export type Resolution = (typeof Resolution)[keyof typeof Resolution];
+
+/**
+ * How long node samples are kept at each resolution. Older samples are only
+ * available as averages of the next coarser resolution.
+ */
+export type RetentionSettings = {
+  minute_hours: number;
+  quarter_hour_days: number;
+  raw_minutes: number;
+};
+
+export type RevokeApiKey = {
+  uuid: string;
+};
+
+export type RuleData = {
+  channels?: Array<string>;
+  condition: AlertCondition;
+  enabled?: boolean;
+  name: string;
+  /**
+   * Restricts the rule to a single node, otherwise all nodes are checked.
+   */
+  node?: string | null;
+};
+
+export type RuleInfo = {
+  channels: Array<string>;
+  condition: AlertCondition;
+  enabled: boolean;
+  name: string;
+  node?: string | null;
+  uuid: string;
+};
+
+export type RuleRequest = {
+  uuid: string;
+};
+
 export const SsoType = { OIDC: 'Oidc', NONE: 'None' } as const;
 
 export type SsoType = (typeof SsoType)[keyof typeof SsoType];
 
+export type SetLocationGroups = {
+  groups: Array<string>;
+};
+
+export type SetMaintenance = {
+  enabled: boolean;
+  /**
+   * Shown to users trying to create or start servers on the node.
+   */
+  message?: string | null;
+};
+
 export type SetupPayload = {
   admin_email: string;
   admin_password: string;
@@ -233,13 +614,104 @@ export type SimpleUserInfo = {
   name: string;
 };
 
+export const SortOrder = { ASC: 'asc', DESC: 'desc' } as const;
+
+export type SortOrder = (typeof SortOrder)[keyof typeof SortOrder];
+
+export type SshKeyInfo = {
+  created_at: Date;
+  fingerprint: string;
+  id: string;
+  key_type: string;
+  name: string;
+  public_key: string;
+};
+
+export type Step = 'dns' | 'tcp' | 'tls' | 'upgrade' | 'signature';
+
+export type StepResult = {
+  /**
+   * What the step found, like the resolved addresses.
+   */
+  detail?: string | null;
+  duration_ms: number;
+  error?: string | null;
+  status: StepStatus;
+  step: Step;
+};
+
+export type StepStatus = 'ok' | 'failed' | 'skipped';
+
+export type SyncDrift = {
+  actual?: number | null;
+  desired?: number | null;
+  field: string;
+};
+
+export type SyncStatus = 'synced' | 'pending';
+
+export type TestChannelRequest = {
+  uuid: string;
+};
+
+export type TestConnection = {
+  address: string;
+  secure: boolean;
+  tls_ca?: string | null;
+  tls_pin?: TlsPin | null;
+  /**
+   * Without a token only the network and TLS are checked.
+   */
+  token?: string | null;
+};
+
+/**
+ * SHA-256 fingerprint the TLS certificate of a node has to match, hex encoded.
+ */
+export type TlsPin =
+  | {
+      sha256: string;
+      type: 'certificate';
+    }
+  | {
+      sha256: string;
+      type: 'spki';
+    };
+
+export type UpdateLocation = {
+  description?: string | null;
+  name: string;
+};
+
 export type UpdateNode = {
   address: string;
   cpu_limit?: number | null;
   disk_limit_mb?: number | null;
+  /**
+   * Left unchanged if missing, `null` removes the node from its location.
+   */
+  location?: string | null;
   memory_limit_mb?: number | null;
   name: string;
+  /**
+   * Wings opens the websocket to the panel, for nodes the panel can not reach.
+   * Left unchanged if missing.
+   */
+  reverse_connect?: boolean | null;
   secure: boolean;
+  /**
+   * Left unchanged if missing.
+   */
+  tags?: Array<string> | null;
+  /**
+   * PEM bundle of the CA that issued the certificate of the node. Left
+   * unchanged if missing, `null` removes it.
+   */
+  tls_ca?: string | null;
+  /**
+   * Left unchanged if missing, `null` removes it.
+   */
+  tls_pin?: TlsPin | null;
 };
 
 export type UserAvatarResetRequest = {
@@ -290,6 +762,35 @@ export type UserViewPath = {
   uuid: string;
 };
 
+export type WebhookData = {
+  enabled?: boolean;
+  events: Array<WebhookEvent>;
+  name: string;
+  url: string;
+};
+
+export type WebhookEvent =
+  | 'node.created'
+  | 'node.updated'
+  | 'node.deleted'
+  | 'node.connected'
+  | 'node.disconnected'
+  | 'alert.fired'
+  | 'alert.resolved'
+  | 'ping';
+
+export type WebhookInfo = {
+  enabled: boolean;
+  events: Array<WebhookEvent>;
+  name: string;
+  url: string;
+  uuid: string;
+};
+
+export type WebhookRequest = {
+  uuid: string;
+};
+
 export type IsSetupData = {
   body?: never;
   path?: never;
@@ -1132,14 +1633,26 @@ export type ConvertOidcUserResponses = {
   200: unknown;
 };
 
-export type GetGeneralSettingsData = {
-  body?: never;
+export type DeleteSshKeyData = {
+  body: DeleteSshKey;
   path?: never;
   query?: never;
-  url: '/api/settings/general';
+  url: '/api/user/ssh_keys';
 };
 
-export type GetGeneralSettingsErrors = {
+export type DeleteSshKeyErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
   /**
    * An error occurred
    */
@@ -1150,21 +1663,23 @@ export type GetGeneralSettingsErrors = {
   '5XX': unknown;
 };
 
-export type GetGeneralSettingsResponses = {
-  200: GeneralSettings;
-};
+export type DeleteSshKeyError = DeleteSshKeyErrors[keyof DeleteSshKeyErrors];
 
-export type GetGeneralSettingsResponse =
-  GetGeneralSettingsResponses[keyof GetGeneralSettingsResponses];
+export type DeleteSshKeyResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
 
-export type GetUserSettingsData = {
+export type ListSshKeysData = {
   body?: never;
   path?: never;
   query?: never;
-  url: '/api/settings/user';
+  url: '/api/user/ssh_keys';
 };
 
-export type GetUserSettingsErrors = {
+export type ListSshKeysErrors = {
   /**
    * An error occurred
    */
@@ -1175,8 +1690,165 @@ export type GetUserSettingsErrors = {
   '5XX': unknown;
 };
 
-export type GetUserSettingsResponses = {
-  200: UserSettingsResponse;
+export type ListSshKeysResponses = {
+  200: Array<SshKeyInfo>;
+};
+
+export type ListSshKeysResponse =
+  ListSshKeysResponses[keyof ListSshKeysResponses];
+
+export type AddSshKeyData = {
+  body: AddSshKey;
+  path?: never;
+  query?: never;
+  url: '/api/user/ssh_keys';
+};
+
+export type AddSshKeyErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type AddSshKeyError = AddSshKeyErrors[keyof AddSshKeyErrors];
+
+export type AddSshKeyResponses = {
+  200: AddSshKeyRes;
+};
+
+export type AddSshKeyResponse = AddSshKeyResponses[keyof AddSshKeyResponses];
+
+export type GetGeneralSettingsData = {
+  body?: never;
+  path?: never;
+  query?: never;
+  url: '/api/settings/general';
+};
+
+export type GetGeneralSettingsErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type GetGeneralSettingsResponses = {
+  200: GeneralSettings;
+};
+
+export type GetGeneralSettingsResponse =
+  GetGeneralSettingsResponses[keyof GetGeneralSettingsResponses];
+
+export type GetRetentionSettingsData = {
+  body?: never;
+  path?: never;
+  query?: never;
+  url: '/api/settings/retention';
+};
+
+export type GetRetentionSettingsErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type GetRetentionSettingsResponses = {
+  /**
+   * How long node samples are kept at each resolution. Older samples are only
+   * available as averages of the next coarser resolution.
+   */
+  200: RetentionSettings;
+};
+
+export type GetRetentionSettingsResponse =
+  GetRetentionSettingsResponses[keyof GetRetentionSettingsResponses];
+
+export type SaveRetentionSettingsData = {
+  body: RetentionSettings;
+  path?: never;
+  query?: never;
+  url: '/api/settings/retention';
+};
+
+export type SaveRetentionSettingsErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type SaveRetentionSettingsError =
+  SaveRetentionSettingsErrors[keyof SaveRetentionSettingsErrors];
+
+export type SaveRetentionSettingsResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
+
+export type GetUserSettingsData = {
+  body?: never;
+  path?: never;
+  query?: never;
+  url: '/api/settings/user';
+};
+
+export type GetUserSettingsErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type GetUserSettingsResponses = {
+  200: UserSettingsResponse;
 };
 
 export type GetUserSettingsResponse =
@@ -1566,6 +2238,136 @@ export type ListUsersSimpleResponses = {
 export type ListUsersSimpleResponse =
   ListUsersSimpleResponses[keyof ListUsersSimpleResponses];
 
+export type RevokeApiKeyData = {
+  body: RevokeApiKey;
+  path?: never;
+  query?: never;
+  url: '/api/api_keys';
+};
+
+export type RevokeApiKeyErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type RevokeApiKeyError = RevokeApiKeyErrors[keyof RevokeApiKeyErrors];
+
+export type RevokeApiKeyResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
+
+export type ListApiKeysData = {
+  body?: never;
+  path?: never;
+  query?: never;
+  url: '/api/api_keys';
+};
+
+export type ListApiKeysErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type ListApiKeysResponses = {
+  200: Array<ApiKeyInfo>;
+};
+
+export type ListApiKeysResponse =
+  ListApiKeysResponses[keyof ListApiKeysResponses];
+
+export type CreateApiKeyData = {
+  body: CreateApiKey;
+  path?: never;
+  query?: never;
+  url: '/api/api_keys';
+};
+
+export type CreateApiKeyErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type CreateApiKeyError = CreateApiKeyErrors[keyof CreateApiKeyErrors];
+
+export type CreateApiKeyResponses = {
+  200: CreateApiKeyRes;
+};
+
+export type CreateApiKeyResponse =
+  CreateApiKeyResponses[keyof CreateApiKeyResponses];
+
+export type ApiKeyUsageData = {
+  body?: never;
+  path: {
+    uuid: string;
+  };
+  query?: never;
+  url: '/api/api_keys/{uuid}/usage';
+};
+
+export type ApiKeyUsageErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type ApiKeyUsageResponses = {
+  200: Array<ApiKeyUsage>;
+};
+
+export type ApiKeyUsageResponse =
+  ApiKeyUsageResponses[keyof ApiKeyUsageResponses];
+
 export type DeleteNodeData = {
   body: DeleteNode;
   path?: never;
@@ -1608,7 +2410,19 @@ export type DeleteNodeResponses = {
 export type ListNodesData = {
   body?: never;
   path?: never;
-  query?: never;
+  query?: {
+    connected?: boolean;
+    limit?: number;
+    location?: string;
+    /**
+     * Case insensitive substring of the node name.
+     */
+    name?: string;
+    offset?: number;
+    order?: SortOrder;
+    sort?: NodeSort;
+    tag?: string;
+  };
   url: '/api/nodes';
 };
 
@@ -1733,3 +2547,917 @@ export type UpdateNodeResponses = {
    */
   200: unknown;
 };
+
+export type SetNodeMaintenanceData = {
+  body: SetMaintenance;
+  path: {
+    uuid: string;
+  };
+  query?: never;
+  url: '/api/nodes/{uuid}/maintenance';
+};
+
+export type SetNodeMaintenanceErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type SetNodeMaintenanceError =
+  SetNodeMaintenanceErrors[keyof SetNodeMaintenanceErrors];
+
+export type SetNodeMaintenanceResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
+
+export type NodeHistoryData = {
+  body?: never;
+  path: {
+    uuid: string;
+  };
+  query?: {
+    /**
+     * Defaults to one hour before `to`.
+     */
+    from?: Date;
+    /**
+     * Defaults to the finest resolution that is still retained at `from`.
+     */
+    resolution?: Resolution;
+    /**
+     * Defaults to now.
+     */
+    to?: Date;
+  };
+  url: '/api/nodes/{uuid}/history';
+};
+
+export type NodeHistoryErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type NodeHistoryResponses = {
+  200: NodeHistory;
+};
+
+export type NodeHistoryResponse =
+  NodeHistoryResponses[keyof NodeHistoryResponses];
+
+export type DeleteLocationData = {
+  body: LocationRequest;
+  path?: never;
+  query?: never;
+  url: '/api/nodes/locations';
+};
+
+export type DeleteLocationErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type DeleteLocationError =
+  DeleteLocationErrors[keyof DeleteLocationErrors];
+
+export type DeleteLocationResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
+
+export type ListLocationsData = {
+  body?: never;
+  path?: never;
+  query?: never;
+  url: '/api/nodes/locations';
+};
+
+export type ListLocationsErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type ListLocationsResponses = {
+  200: Array<LocationInfo>;
+};
+
+export type ListLocationsResponse =
+  ListLocationsResponses[keyof ListLocationsResponses];
+
+export type CreateLocationData = {
+  body: CreateLocation;
+  path?: never;
+  query?: never;
+  url: '/api/nodes/locations';
+};
+
+export type CreateLocationErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type CreateLocationError =
+  CreateLocationErrors[keyof CreateLocationErrors];
+
+export type CreateLocationResponses = {
+  200: CreateLocationRes;
+};
+
+export type CreateLocationResponse =
+  CreateLocationResponses[keyof CreateLocationResponses];
+
+export type UpdateLocationData = {
+  body: UpdateLocation;
+  path: {
+    uuid: string;
+  };
+  query?: never;
+  url: '/api/nodes/locations/{uuid}';
+};
+
+export type UpdateLocationErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type UpdateLocationError =
+  UpdateLocationErrors[keyof UpdateLocationErrors];
+
+export type UpdateLocationResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
+
+export type SetLocationGroupsData = {
+  body: SetLocationGroups;
+  path: {
+    uuid: string;
+  };
+  query?: never;
+  url: '/api/nodes/locations/{uuid}/groups';
+};
+
+export type SetLocationGroupsErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type SetLocationGroupsError =
+  SetLocationGroupsErrors[keyof SetLocationGroupsErrors];
+
+export type SetLocationGroupsResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
+
+export type DryRunPlacementData = {
+  body: PlacementRequest;
+  path?: never;
+  query?: never;
+  url: '/api/nodes/placement';
+};
+
+export type DryRunPlacementErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type DryRunPlacementError =
+  DryRunPlacementErrors[keyof DryRunPlacementErrors];
+
+export type DryRunPlacementResponses = {
+  200: Placement;
+};
+
+export type DryRunPlacementResponse =
+  DryRunPlacementResponses[keyof DryRunPlacementResponses];
+
+export type CreateNodeEnrollmentData = {
+  body?: never;
+  path: {
+    uuid: string;
+  };
+  query?: never;
+  url: '/api/nodes/{uuid}/enrollment';
+};
+
+export type CreateNodeEnrollmentErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type CreateNodeEnrollmentResponses = {
+  200: CreateEnrollmentRes;
+};
+
+export type CreateNodeEnrollmentResponse =
+  CreateNodeEnrollmentResponses[keyof CreateNodeEnrollmentResponses];
+
+export type TestNodeConnectionData = {
+  body: TestConnection;
+  path?: never;
+  query?: never;
+  url: '/api/nodes/test-connection';
+};
+
+export type TestNodeConnectionErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type TestNodeConnectionError =
+  TestNodeConnectionErrors[keyof TestNodeConnectionErrors];
+
+export type TestNodeConnectionResponses = {
+  200: Diagnosis;
+};
+
+export type TestNodeConnectionResponse =
+  TestNodeConnectionResponses[keyof TestNodeConnectionResponses];
+
+export type DiagnoseNodeData = {
+  body?: never;
+  path: {
+    uuid: string;
+  };
+  query?: never;
+  url: '/api/nodes/{uuid}/diagnose';
+};
+
+export type DiagnoseNodeErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type DiagnoseNodeResponses = {
+  200: Diagnosis;
+};
+
+export type DiagnoseNodeResponse =
+  DiagnoseNodeResponses[keyof DiagnoseNodeResponses];
+
+export type ListAlertsData = {
+  body?: never;
+  path?: never;
+  query?: {
+    limit?: number;
+    /**
+     * Only alerts that are still firing.
+     */
+    open?: boolean;
+  };
+  url: '/api/alerts';
+};
+
+export type ListAlertsErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type ListAlertsResponses = {
+  200: Array<AlertInfo>;
+};
+
+export type ListAlertsResponse = ListAlertsResponses[keyof ListAlertsResponses];
+
+export type DeleteAlertRuleData = {
+  body: RuleRequest;
+  path?: never;
+  query?: never;
+  url: '/api/alerts/rules';
+};
+
+export type DeleteAlertRuleErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type DeleteAlertRuleError =
+  DeleteAlertRuleErrors[keyof DeleteAlertRuleErrors];
+
+export type DeleteAlertRuleResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
+
+export type ListAlertRulesData = {
+  body?: never;
+  path?: never;
+  query?: never;
+  url: '/api/alerts/rules';
+};
+
+export type ListAlertRulesErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type ListAlertRulesResponses = {
+  200: Array<RuleInfo>;
+};
+
+export type ListAlertRulesResponse =
+  ListAlertRulesResponses[keyof ListAlertRulesResponses];
+
+export type CreateAlertRuleData = {
+  body: RuleData;
+  path?: never;
+  query?: never;
+  url: '/api/alerts/rules';
+};
+
+export type CreateAlertRuleErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type CreateAlertRuleError =
+  CreateAlertRuleErrors[keyof CreateAlertRuleErrors];
+
+export type CreateAlertRuleResponses = {
+  200: CreateRuleRes;
+};
+
+export type CreateAlertRuleResponse =
+  CreateAlertRuleResponses[keyof CreateAlertRuleResponses];
+
+export type UpdateAlertRuleData = {
+  body: RuleData;
+  path: {
+    uuid: string;
+  };
+  query?: never;
+  url: '/api/alerts/rules/{uuid}';
+};
+
+export type UpdateAlertRuleErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type UpdateAlertRuleError =
+  UpdateAlertRuleErrors[keyof UpdateAlertRuleErrors];
+
+export type UpdateAlertRuleResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
+
+export type DeleteAlertChannelData = {
+  body: DeleteChannel;
+  path?: never;
+  query?: never;
+  url: '/api/alerts/channels';
+};
+
+export type DeleteAlertChannelErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type DeleteAlertChannelError =
+  DeleteAlertChannelErrors[keyof DeleteAlertChannelErrors];
+
+export type DeleteAlertChannelResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
+
+export type ListAlertChannelsData = {
+  body?: never;
+  path?: never;
+  query?: never;
+  url: '/api/alerts/channels';
+};
+
+export type ListAlertChannelsErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type ListAlertChannelsResponses = {
+  200: Array<ChannelInfo>;
+};
+
+export type ListAlertChannelsResponse =
+  ListAlertChannelsResponses[keyof ListAlertChannelsResponses];
+
+export type CreateAlertChannelData = {
+  body: CreateChannel;
+  path?: never;
+  query?: never;
+  url: '/api/alerts/channels';
+};
+
+export type CreateAlertChannelErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type CreateAlertChannelError =
+  CreateAlertChannelErrors[keyof CreateAlertChannelErrors];
+
+export type CreateAlertChannelResponses = {
+  200: CreateChannelRes;
+};
+
+export type CreateAlertChannelResponse =
+  CreateAlertChannelResponses[keyof CreateAlertChannelResponses];
+
+export type TestAlertChannelData = {
+  body?: never;
+  path: {
+    uuid: string;
+  };
+  query?: never;
+  url: '/api/alerts/channels/{uuid}/test';
+};
+
+export type TestAlertChannelErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type TestAlertChannelResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
+
+export type DeleteWebhookData = {
+  body: WebhookRequest;
+  path?: never;
+  query?: never;
+  url: '/api/webhooks';
+};
+
+export type DeleteWebhookErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type DeleteWebhookError = DeleteWebhookErrors[keyof DeleteWebhookErrors];
+
+export type DeleteWebhookResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
+
+export type ListWebhooksData = {
+  body?: never;
+  path?: never;
+  query?: never;
+  url: '/api/webhooks';
+};
+
+export type ListWebhooksErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type ListWebhooksResponses = {
+  200: Array<WebhookInfo>;
+};
+
+export type ListWebhooksResponse =
+  ListWebhooksResponses[keyof ListWebhooksResponses];
+
+export type CreateWebhookData = {
+  body: WebhookData;
+  path?: never;
+  query?: never;
+  url: '/api/webhooks';
+};
+
+export type CreateWebhookErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type CreateWebhookError = CreateWebhookErrors[keyof CreateWebhookErrors];
+
+export type CreateWebhookResponses = {
+  200: CreateWebhookRes;
+};
+
+export type CreateWebhookResponse =
+  CreateWebhookResponses[keyof CreateWebhookResponses];
+
+export type UpdateWebhookData = {
+  body: WebhookData;
+  path: {
+    uuid: string;
+  };
+  query?: never;
+  url: '/api/webhooks/{uuid}';
+};
+
+export type UpdateWebhookErrors = {
+  /**
+   * Failed to parse the request body as JSON
+   */
+  400: string;
+  /**
+   * Expected request with `Content-Type: application/json`
+   */
+  415: string;
+  /**
+   * Failed to deserialize the JSON body into the target type
+   */
+  422: string;
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type UpdateWebhookError = UpdateWebhookErrors[keyof UpdateWebhookErrors];
+
+export type UpdateWebhookResponses = {
+  /**
+   * no content
+   */
+  200: unknown;
+};
+
+export type PingWebhookData = {
+  body?: never;
+  path: {
+    uuid: string;
+  };
+  query?: never;
+  url: '/api/webhooks/{uuid}/ping';
+};
+
+export type PingWebhookErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type PingWebhookResponses = {
+  200: PingRes;
+};
+
+export type PingWebhookResponse =
+  PingWebhookResponses[keyof PingWebhookResponses];
+
+export type ListWebhookDeliveriesData = {
+  body?: never;
+  path: {
+    uuid: string;
+  };
+  query?: {
+    limit?: number;
+  };
+  url: '/api/webhooks/{uuid}/deliveries';
+};
+
+export type ListWebhookDeliveriesErrors = {
+  /**
+   * An error occurred
+   */
+  '4XX': unknown;
+  /**
+   * An error occurred
+   */
+  '5XX': unknown;
+};
+
+export type ListWebhookDeliveriesResponses = {
+  200: Array<DeliveryInfo>;
+};
+
+export type ListWebhookDeliveriesResponse =
+  ListWebhookDeliveriesResponses[keyof ListWebhookDeliveriesResponses];
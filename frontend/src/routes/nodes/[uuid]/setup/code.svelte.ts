@@ -1,23 +1,45 @@
 // @ts-ignore this will be replaced during build
 const version = __version__;
 
-export const dockerCompose = (token: string) =>
+export type SetupOptions = {
+  code: string;
+  panelUrl: string;
+  reverseConnect: boolean;
+};
+
+// wings exchanges the enrollment code for its token on the first start
+const environment = ({ code, panelUrl, reverseConnect }: SetupOptions) => [
+  `API__ENROLLMENT_CODE=${code}`,
+  `API__PANEL_URL=${panelUrl}`,
+  ...(reverseConnect ? ['API__REVERSE_CONNECT=true'] : [])
+];
+
+export const dockerCompose = (options: SetupOptions) =>
   `services:
   smaug-wings:
     image: ghcr.io/profiidev/smaug/smaug-wings:${version}
     restart: unless-stopped
     environment:
-      - TOKEN=${token}
+${environment(options)
+  .map((variable) => `      - ${variable}`)
+  .join('\n')}
     ports:
       - "8000:8000"
     volumes:
-      - /var/run/docker.sock:/var/run/docker.sock`;
+      - /var/run/docker.sock:/var/run/docker.sock
+      - smaug-wings-data:/app/data
+
+volumes:
+  smaug-wings-data:`;
 
-export const dockerRun = (token: string) =>
+export const dockerRun = (options: SetupOptions) =>
   `docker run -d \\
-  --name smaug-wings \\ 
+  --name smaug-wings \\
   --restart unless-stopped \\
-  -e TOKEN=${token} \\
+${environment(options)
+  .map((variable) => `  -e ${variable} \\`)
+  .join('\n')}
   -p 8000:8000 \\
   -v /var/run/docker.sock:/var/run/docker.sock \\
+  -v smaug-wings-data:/app/data \\
   ghcr.io/profiidev/smaug/smaug-wings:${version}`;
@@ -3,27 +3,40 @@ use centaurus::{
   error::{ErrorReportStatusExt, Result},
   eyre::Context,
 };
-use chrono::Utc;
+use chrono::{TimeDelta, Utc};
 use hmac::{KeyInit, Mac};
 use http::{HeaderMap, StatusCode};
 use rand::Rng;
+use sha2::{Digest, Sha256};
 
 type HmacSha3_512 = hmac::Hmac<sha2::Sha512>;
 
 const TIMESTAMP_HEADER: &str = "x-wings-timestamp";
 const NONCE_HEADER: &str = "x-wings-nonce";
 const SIGNATURE_HEADER: &str = "x-wings-signature";
+/// Identifies the node for requests wings sends to the panel.
+pub const NODE_HEADER: &str = "x-wings-node";
+/// Requests signed further in the past or future are rejected by
+/// [`SignData::validate_request`], so nonces only have to be remembered for
+/// about twice as long.
+pub const MAX_SIGNATURE_AGE: TimeDelta = TimeDelta::seconds(60);
 
 pub struct SignData {
   timestamp: String,
   nonce: String,
+  /// Hex encoded SHA-256 of the request body, if the signature covers it.
+  body: Option<String>,
 }
 
 impl Default for SignData {
   fn default() -> Self {
     let timestamp = Utc::now().timestamp_millis().to_string();
     let nonce = random_nonce();
-    Self { timestamp, nonce }
+    Self {
+      timestamp,
+      nonce,
+      body: None,
+    }
   }
 }
 
@@ -34,11 +47,32 @@ impl SignData {
 
   pub fn from_timestamp(timestamp: String) -> Self {
     let nonce = random_nonce();
-    Self { timestamp, nonce }
+    Self {
+      timestamp,
+      nonce,
+      body: None,
+    }
+  }
+
+  /// Lets the signature cover the request body as well.
+  pub fn with_body(mut self, body: &[u8]) -> Self {
+    self.body = Some(hash_body(body));
+    self
+  }
+
+  pub fn timestamp(&self) -> &str {
+    &self.timestamp
+  }
+
+  pub fn nonce(&self) -> &str {
+    &self.nonce
   }
 
   fn signature(&self, token: &str) -> Result<String> {
-    let data = format!("{}.{}", self.timestamp, self.nonce);
+    let data = match &self.body {
+      Some(body) => format!("{}.{}.{}", self.timestamp, self.nonce, body),
+      None => format!("{}.{}", self.timestamp, self.nonce),
+    };
     hmac(&data, token)
   }
 
@@ -79,11 +113,44 @@ impl SignData {
       bail!(UNAUTHORIZED, "Invalid wings nonce");
     }
 
-    let sign_data = SignData { timestamp, nonce };
+    let sign_data = SignData {
+      timestamp,
+      nonce,
+      body: None,
+    };
     sign_data.validate(token, &signature)?;
 
     Ok(sign_data.timestamp)
   }
+
+  /// Validates a request wings sent to the panel, the signature has to cover
+  /// the given body and must be recent. Whether the nonce was used before is
+  /// left to the caller.
+  pub fn validate_request(headers: &HeaderMap, token: &str, body: Option<&[u8]>) -> Result<Self> {
+    let timestamp = get_header_value(headers, TIMESTAMP_HEADER)?;
+    let nonce = get_header_value(headers, NONCE_HEADER)?;
+    let signature = get_header_value(headers, SIGNATURE_HEADER)?;
+
+    let signed_at = timestamp
+      .parse::<i64>()
+      .status_context(StatusCode::UNAUTHORIZED, "Invalid wings timestamp")?;
+    if (Utc::now().timestamp_millis() - signed_at).abs() > MAX_SIGNATURE_AGE.num_milliseconds() {
+      bail!(UNAUTHORIZED, "Expired wings signature");
+    }
+
+    let sign_data = SignData {
+      timestamp,
+      nonce,
+      body: body.map(hash_body),
+    };
+    sign_data.validate(token, &signature)?;
+
+    Ok(sign_data)
+  }
+}
+
+fn hash_body(body: &[u8]) -> String {
+  hex::encode(Sha256::digest(body))
 }
 
 fn random_nonce() -> String {
@@ -103,7 +170,8 @@ fn get_header_value(headers: &HeaderMap, key: &str) -> Result<String> {
   Ok(value)
 }
 
-fn hmac(data: &str, key: &str) -> Result<String> {
+/// Hex encoded HMAC-SHA512 of `data`.
+pub fn hmac(data: &str, key: &str) -> Result<String> {
   let mut mac = HmacSha3_512::new_from_slice(key.as_bytes()).context("hamc error")?;
   mac.update(data.as_bytes());
   Ok(hex::encode(mac.finalize().into_bytes()))
@@ -1,8 +1,89 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(tag = "type")]
 pub enum WingsMessage {
   Hello,
   World,
+  NodeStats(NodeStats),
+  /// Desired configuration of the node, sent by the panel on every connect and
+  /// whenever it changes.
+  SyncConfig(NodeConfig),
+  /// Answer of wings to `SyncConfig` once it applied the configuration.
+  SyncReport(SyncReport),
+}
+
+impl WingsMessage {
+  /// Name of the variant, matching the serialized `type` tag.
+  pub fn kind(&self) -> &'static str {
+    match self {
+      WingsMessage::Hello => "Hello",
+      WingsMessage::World => "World",
+      WingsMessage::NodeStats(_) => "NodeStats",
+      WingsMessage::SyncConfig(_) => "SyncConfig",
+      WingsMessage::SyncReport(_) => "SyncReport",
+    }
+  }
+}
+
+/// Host resource usage reported by wings on a fixed interval.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct NodeStats {
+  pub cpu_percent: f64,
+  pub memory_used_mb: f64,
+  pub memory_total_mb: f64,
+  pub disk_used_mb: f64,
+  pub disk_total_mb: f64,
+}
+
+/// Resource limits of the node, the panel is the source of truth for them.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct NodeConfig {
+  pub memory_limit_mb: Option<f64>,
+  pub disk_limit_mb: Option<f64>,
+  pub cpu_limit: Option<i32>,
+}
+
+impl NodeConfig {
+  /// Fields where `self` differs from the desired configuration.
+  pub fn drift(&self, desired: &NodeConfig) -> Vec<Drift> {
+    let fields = [
+      (
+        "memory_limit_mb",
+        self.memory_limit_mb,
+        desired.memory_limit_mb,
+      ),
+      ("disk_limit_mb", self.disk_limit_mb, desired.disk_limit_mb),
+      (
+        "cpu_limit",
+        self.cpu_limit.map(f64::from),
+        desired.cpu_limit.map(f64::from),
+      ),
+    ];
+
+    fields
+      .into_iter()
+      .filter(|(_, actual, desired)| actual != desired)
+      .map(|(field, actual, desired)| Drift {
+        field: field.to_string(),
+        desired,
+        actual,
+      })
+      .collect()
+  }
+}
+
+/// A value wings had configured differently than the panel, `None` is unset.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Drift {
+  pub field: String,
+  pub desired: Option<f64>,
+  pub actual: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct SyncReport {
+  /// What differed before wings applied the configuration, empty if it was
+  /// already in sync.
+  pub drift: Vec<Drift>,
 }
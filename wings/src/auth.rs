@@ -28,5 +28,5 @@ impl<S: Sync> FromRequestParts<S> for Auth {
 }
 
 pub fn state(router: Router, config: &Config) -> Router {
-  router.layer(Extension(WingsToken(config.token.clone())))
+  router.layer(Extension(WingsToken(config.token().to_string())))
 }
@@ -0,0 +1,49 @@
+use std::fs;
+
+use centaurus::{error::Result, eyre::Context};
+use rustls::pki_types::{CertificateDer, pem::PemObject};
+use sha2::{Digest, Sha256};
+use tracing::info;
+use webpki::EndEntityCert;
+
+use crate::{config::Config, enroll::persist};
+
+/// Generates the self-signed certificate if enabled and there is none yet, and
+/// logs the fingerprints the panel can pin when TLS is on.
+pub fn init(config: &Config) -> Result<()> {
+  if !config.tls_enabled() {
+    return Ok(());
+  }
+
+  let cert_file = config.tls_cert_file();
+  if config.tls.self_signed && !cert_file.exists() {
+    let generated = rcgen::generate_simple_self_signed(config.tls.hostnames.clone())
+      .context("Failed to generate self-signed certificate")?;
+    persist(
+      &config.tls_key_file(),
+      &generated.signing_key.serialize_pem(),
+    )?;
+    persist(&cert_file, &generated.cert.pem())?;
+    info!(
+      "Generated self-signed certificate for {}",
+      config.tls.hostnames.join(", ")
+    );
+  }
+
+  let pem = fs::read(&cert_file).context("Failed to read certificate")?;
+  let cert = CertificateDer::from_pem_slice(&pem).context("Invalid certificate")?;
+  let spki = EndEntityCert::try_from(&cert)
+    .context("Invalid certificate")?
+    .subject_public_key_info();
+
+  info!(
+    "Certificate SHA-256 fingerprint: {}",
+    hex::encode(Sha256::digest(&cert))
+  );
+  info!(
+    "Public key (SPKI) SHA-256 fingerprint: {}",
+    hex::encode(Sha256::digest(&spki))
+  );
+
+  Ok(())
+}
@@ -1,17 +1,37 @@
+use std::{
+  env, fs,
+  path::{Path, PathBuf},
+  time::Duration,
+};
+
 use centaurus::{
   Config,
   backend::{
     auth::settings::AuthConfig,
     config::{BaseConfig, MetricsConfig, SiteConfig},
   },
+  bail,
+  error::Result,
+  eyre::Context,
 };
 use figment::{
   Figment,
-  providers::{Env, Serialized},
+  providers::{Env, Format, Serialized, Toml},
 };
 use serde::{Deserialize, Serialize};
-use tracing::instrument;
+use tracing::{instrument, warn};
+use uuid::Uuid;
 
+/// Used when neither `--config` nor `WINGS_CONFIG` point somewhere else. Unlike
+/// an explicitly given file it may be missing, so wings can be configured
+/// through the environment alone.
+const DEFAULT_CONFIG_FILE: &str = "config.toml";
+/// Read before the sectioned variables, which take precedence.
+const DEPRECATED_TOKEN_ENV: &str = "TOKEN";
+
+/// Loaded from the toml file, with environment variables on top. Keys of the
+/// sections are set through the environment as `SECTION__KEY`, for example
+/// `TLS__ENABLED=true`. See `config.example.toml` for all options.
 #[derive(Deserialize, Serialize, Clone, Config)]
 pub struct Config {
   #[serde(flatten)]
@@ -27,7 +47,78 @@ pub struct Config {
   #[auth]
   pub auth: AuthConfig,
 
-  pub token: String,
+  pub api: ApiConfig,
+  pub tls: TlsConfig,
+  pub runtime: RuntimeConfig,
+  pub paths: PathsConfig,
+  pub sftp: SftpConfig,
+  pub limits: LimitsConfig,
+}
+
+/// How wings reaches and authenticates with the panel api.
+#[derive(Deserialize, Serialize, Clone, Default)]
+pub struct ApiConfig {
+  /// Shared with the panel, takes precedence over a token persisted by
+  /// enrollment.
+  pub token: Option<String>,
+  /// Exchanged with the panel for the token on the first start.
+  pub enrollment_code: Option<String>,
+  /// Base url of the panel api, required for enrollment and reverse connect.
+  pub panel_url: Option<String>,
+  /// Id of this node in the panel, only needed for reverse connect.
+  pub node_id: Option<Uuid>,
+  /// Open the websocket to the panel instead of waiting for the panel to
+  /// connect, for nodes behind NAT.
+  pub reverse_connect: bool,
+}
+
+#[derive(Deserialize, Serialize, Clone)]
+pub struct TlsConfig {
+  /// Serve HTTPS and WSS, the certificate is reloaded when it changes on disk.
+  pub enabled: bool,
+  /// Defaults to `tls/cert.pem` in the data directory.
+  pub cert_file: Option<PathBuf>,
+  /// Defaults to `tls/key.pem` in the data directory.
+  pub key_file: Option<PathBuf>,
+  /// Generate a self-signed certificate if there is none yet. Its fingerprint
+  /// is logged on startup, to be pinned on the node in the panel. Implies
+  /// `enabled`.
+  pub self_signed: bool,
+  /// Subject alternative names of the generated certificate.
+  pub hostnames: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone)]
+pub struct RuntimeConfig {
+  /// How often host stats are collected and sent to the panel.
+  pub stats_interval_secs: u64,
+  /// Delay between attempts to open the reverse connect websocket.
+  pub reconnect_delay_secs: u64,
+}
+
+#[derive(Deserialize, Serialize, Clone)]
+pub struct PathsConfig {
+  /// Holds the `volumes`, `backups` and `state` directories unless they are
+  /// moved elsewhere below.
+  pub data_dir: PathBuf,
+  pub volumes_dir: Option<PathBuf>,
+  pub backups_dir: Option<PathBuf>,
+}
+
+#[derive(Deserialize, Serialize, Clone)]
+pub struct SftpConfig {
+  pub enabled: bool,
+  pub port: u16,
+}
+
+/// Upper bounds for what the servers on this node may allocate in total,
+/// unset means the whole host. Replaced by the limits of the node in the panel
+/// once it is connected.
+#[derive(Deserialize, Serialize, Clone, Default)]
+pub struct LimitsConfig {
+  pub memory_mb: Option<f64>,
+  pub disk_mb: Option<f64>,
+  pub cpu: Option<i32>,
 }
 
 impl Default for Config {
@@ -41,21 +132,357 @@ impl Default for Config {
       site: SiteConfig {
         site_url: "http://localhost:8080".parse().unwrap(),
       },
-      token: "test-token".to_string(),
       auth: AuthConfig::default(),
+      api: ApiConfig::default(),
+      tls: TlsConfig {
+        enabled: false,
+        cert_file: None,
+        key_file: None,
+        self_signed: false,
+        hostnames: vec!["localhost".to_string()],
+      },
+      runtime: RuntimeConfig {
+        stats_interval_secs: 10,
+        reconnect_delay_secs: 5,
+      },
+      paths: PathsConfig {
+        data_dir: PathBuf::from("data"),
+        volumes_dir: None,
+        backups_dir: None,
+      },
+      sftp: SftpConfig {
+        enabled: false,
+        port: 2022,
+      },
+      limits: LimitsConfig::default(),
     }
   }
 }
 
 impl Config {
+  /// Reads the given file, or the default one if it exists.
   #[instrument]
-  pub fn parse() -> Self {
-    let config = Figment::new()
-      .merge(Serialized::defaults(Self::default()))
-      .merge(Env::raw().global());
+  pub fn load(file: Option<&Path>) -> Result<Self> {
+    let mut figment = Figment::new().merge(Serialized::defaults(Self::default()));
+
+    let file = match file {
+      Some(file) if !file.exists() => bail!("Config file {} does not exist", file.display()),
+      Some(file) => Some(file),
+      None => Some(Path::new(DEFAULT_CONFIG_FILE)).filter(|file| file.exists()),
+    };
+    if let Some(file) = file {
+      figment = figment.merge(Toml::file_exact(file));
+    }
+
+    let config = figment
+      .merge(
+        Env::raw()
+          .only(&[DEPRECATED_TOKEN_ENV])
+          .map(|_| "api.token".into()),
+      )
+      .merge(Env::raw().split("__").global())
+      .extract()
+      .context("Failed to parse configuration")?;
+
+    Ok(config)
+  }
+
+  /// Loads and validates the configuration without starting wings.
+  pub fn check(file: Option<&Path>) -> Result<()> {
+    Self::load(file)?.validate()
+  }
+
+  /// Setups from before the sectioned variables still set `TOKEN`.
+  pub fn warn_deprecated() {
+    if env::var_os(DEPRECATED_TOKEN_ENV).is_some() {
+      warn!(
+        "{} is deprecated, set API__TOKEN instead",
+        DEPRECATED_TOKEN_ENV
+      );
+    }
+  }
+
+  /// Collects every problem instead of stopping at the first, so they can be
+  /// fixed in one go.
+  pub fn validate(&self) -> Result<()> {
+    let mut problems = Vec::new();
+
+    if self.api.token.is_none()
+      && self.api.enrollment_code.is_none()
+      && !self.paths.token_file().exists()
+    {
+      problems.push(
+        "api.token is not set and there is neither an enrollment code nor a token from an earlier enrollment"
+          .to_string(),
+      );
+    }
+    // was the default before wings required a token of its own
+    if self.api.token.as_deref() == Some("test-token") {
+      problems.push("api.token must not be the old default test-token".to_string());
+    }
+    if let Some(url) = &self.api.panel_url
+      && !url.starts_with("http://")
+      && !url.starts_with("https://")
+    {
+      problems.push("api.panel_url must be a http(s) url".to_string());
+    }
+    if self.api.panel_url.is_none() && self.api.enrollment_code.is_some() {
+      problems.push("api.panel_url is required for enrollment".to_string());
+    }
+    if self.api.panel_url.is_none() && self.api.reverse_connect {
+      problems.push("api.panel_url is required for reverse connect".to_string());
+    }
+    if self.api.reverse_connect
+      && self.api.node_id.is_none()
+      && self.api.enrollment_code.is_none()
+      && !self.paths.node_file().exists()
+    {
+      problems.push("api.node_id is required for reverse connect".to_string());
+    }
+
+    if self.tls_enabled() && !self.tls.self_signed {
+      for file in [self.tls_cert_file(), self.tls_key_file()] {
+        if !file.exists() {
+          problems.push(format!("{} does not exist", file.display()));
+        }
+      }
+    }
+    if self.tls.self_signed && self.tls.hostnames.is_empty() {
+      problems.push("tls.hostnames must not be empty for a self-signed certificate".to_string());
+    }
+
+    if self.runtime.stats_interval_secs == 0 {
+      problems.push("runtime.stats_interval_secs must be positive".to_string());
+    }
+    if self.runtime.reconnect_delay_secs == 0 {
+      problems.push("runtime.reconnect_delay_secs must be positive".to_string());
+    }
+
+    if self.sftp.enabled && self.sftp.port == self.base.port {
+      problems.push("sftp.port must differ from port".to_string());
+    }
+
+    if self.limits.memory_mb.is_some_and(|memory| memory <= 0.0)
+      || self.limits.disk_mb.is_some_and(|disk| disk <= 0.0)
+      || self.limits.cpu.is_some_and(|cpu| cpu <= 0)
+    {
+      problems.push("limits must be positive".to_string());
+    }
+
+    if !problems.is_empty() {
+      bail!("Invalid configuration:\n  - {}", problems.join("\n  - "));
+    }
+
+    Ok(())
+  }
+
+  pub fn token(&self) -> &str {
+    self
+      .api
+      .token
+      .as_deref()
+      .expect("token is resolved on startup")
+  }
+
+  pub fn tls_enabled(&self) -> bool {
+    self.tls.enabled || self.tls.self_signed
+  }
+
+  pub fn tls_cert_file(&self) -> PathBuf {
+    self
+      .tls
+      .cert_file
+      .clone()
+      .unwrap_or_else(|| self.paths.data_dir.join("tls").join("cert.pem"))
+  }
+
+  pub fn tls_key_file(&self) -> PathBuf {
+    self
+      .tls
+      .key_file
+      .clone()
+      .unwrap_or_else(|| self.paths.data_dir.join("tls").join("key.pem"))
+  }
+}
+
+impl RuntimeConfig {
+  pub fn stats_interval(&self) -> Duration {
+    Duration::from_secs(self.stats_interval_secs)
+  }
+
+  pub fn reconnect_delay(&self) -> Duration {
+    Duration::from_secs(self.reconnect_delay_secs)
+  }
+}
+
+impl PathsConfig {
+  pub fn volumes(&self) -> PathBuf {
+    self
+      .volumes_dir
+      .clone()
+      .unwrap_or_else(|| self.data_dir.join("volumes"))
+  }
+
+  pub fn backups(&self) -> PathBuf {
+    self
+      .backups_dir
+      .clone()
+      .unwrap_or_else(|| self.data_dir.join("backups"))
+  }
+
+  /// Local state of wings itself, like the credentials from enrollment.
+  pub fn state(&self) -> PathBuf {
+    self.data_dir.join("state")
+  }
+
+  pub fn token_file(&self) -> PathBuf {
+    self.state().join("token")
+  }
+
+  pub fn node_file(&self) -> PathBuf {
+    self.state().join("node")
+  }
+
+  /// Creates the data directory layout, the state directory is only readable
+  /// by the owner as it holds the token.
+  pub fn create(&self) -> Result<()> {
+    for dir in [self.volumes(), self.backups(), self.state()] {
+      fs::create_dir_all(&dir).context(format!("Failed to create {}", dir.display()))?;
+    }
+
+    #[cfg(unix)]
+    {
+      use std::os::unix::fs::PermissionsExt;
+      let state = self.state();
+      fs::set_permissions(&state, fs::Permissions::from_mode(0o700)).context(format!(
+        "Failed to restrict permissions of {}",
+        state.display()
+      ))?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+// the closures of `Jail` have to return figment's large error
+#[allow(clippy::result_large_err)]
+mod test {
+  use figment::Jail;
+
+  use super::*;
+
+  #[test]
+  fn default_config_needs_a_token() {
+    Jail::expect_with(|_| {
+      let err = Config::check(None).unwrap_err();
+      assert!(err.to_string().contains("api.token is not set"));
+      Ok(())
+    });
+  }
+
+  #[test]
+  fn check_reads_the_environment() {
+    Jail::expect_with(|jail| {
+      jail.set_env("API__TOKEN", "secret");
+      Config::check(None).unwrap();
+      Ok(())
+    });
+  }
+
+  #[test]
+  fn check_rejects_missing_file() {
+    Jail::expect_with(|_| {
+      let err = Config::check(Some(Path::new("missing.toml"))).unwrap_err();
+      assert!(err.to_string().contains("does not exist"));
+      Ok(())
+    });
+  }
+
+  #[test]
+  fn check_reads_the_file() {
+    Jail::expect_with(|jail| {
+      jail.create_file(
+        "wings.toml",
+        r#"
+        [api]
+        token = "secret"
+
+        [runtime]
+        stats_interval_secs = 0
+        "#,
+      )?;
+      let err = Config::check(Some(Path::new("wings.toml"))).unwrap_err();
+      assert!(err.to_string().contains("runtime.stats_interval_secs"));
+      assert!(!err.to_string().contains("api.token"));
+      Ok(())
+    });
+  }
+
+  #[test]
+  fn deprecated_token_is_an_alias() {
+    Jail::expect_with(|jail| {
+      jail.set_env("TOKEN", "old");
+      assert_eq!(
+        Config::load(None).unwrap().api.token.as_deref(),
+        Some("old")
+      );
+
+      jail.set_env("API__TOKEN", "new");
+      assert_eq!(
+        Config::load(None).unwrap().api.token.as_deref(),
+        Some("new")
+      );
+      Ok(())
+    });
+  }
+
+  #[test]
+  fn validate_collects_all_problems() {
+    let mut config = Config::default();
+    config.api.token = Some("test-token".to_string());
+    config.api.enrollment_code = Some("code".to_string());
+    config.api.reverse_connect = true;
+    config.sftp.enabled = true;
+    config.sftp.port = config.base.port;
+    config.limits.cpu = Some(0);
+
+    let err = config.validate().unwrap_err().to_string();
+    for problem in [
+      "old default test-token",
+      "api.panel_url is required for enrollment",
+      "api.panel_url is required for reverse connect",
+      "sftp.port must differ",
+      "limits must be positive",
+    ] {
+      assert!(err.contains(problem), "{problem} missing from {err}");
+    }
+  }
+
+  #[test]
+  fn validate_rejects_non_http_panel_url() {
+    let mut config = Config::default();
+    config.api.token = Some("secret".to_string());
+    config.api.panel_url = Some("ftp://panel".to_string());
+    assert!(config.validate().is_err());
+
+    config.api.panel_url = Some("https://panel/api".to_string());
+    config.validate().unwrap();
+  }
+
+  #[test]
+  fn validate_requires_tls_files_unless_self_signed() {
+    let mut config = Config::default();
+    config.api.token = Some("secret".to_string());
+    config.paths.data_dir = PathBuf::from("does-not-exist");
+    config.tls.enabled = true;
+    let err = config.validate().unwrap_err().to_string();
+    assert!(err.contains("cert.pem does not exist"));
 
-    let config: Self = config.extract().expect("Failed to parse configuration");
+    config.tls.self_signed = true;
+    config.validate().unwrap();
 
-    config
+    config.tls.hostnames.clear();
+    assert!(config.validate().is_err());
   }
 }
@@ -0,0 +1,212 @@
+use std::{
+  fs::{self, OpenOptions},
+  io::{ErrorKind, Write},
+  path::Path,
+};
+
+use centaurus::{
+  bail,
+  error::Result,
+  eyre::{Context, ContextCompat},
+};
+use reqwest::Client;
+use serde::Deserialize;
+use shared::auth::SignData;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::config::Config;
+
+#[derive(Deserialize)]
+struct Enrollment {
+  node: Uuid,
+  token: String,
+}
+
+/// Resolves the token and node id used to authenticate with the panel.
+/// A configured token wins over credentials persisted by an earlier
+/// enrollment, otherwise a configured enrollment code is exchanged for them,
+/// which are then persisted for the next start.
+pub async fn resolve_credentials(config: &mut Config) -> Result<()> {
+  if resolve_persisted(config)? {
+    return Ok(());
+  }
+
+  let Some(code) = &config.api.enrollment_code else {
+    return Ok(());
+  };
+  let panel_url = config
+    .api
+    .panel_url
+    .as_ref()
+    .context("api.panel_url is required for enrollment")?;
+  if panel_url.starts_with("http://") {
+    warn!("api.panel_url is plain http, the enrollment code and token are sent unencrypted");
+  }
+
+  let token_file = config.paths.token_file();
+  let node_file = config.paths.node_file();
+
+  let enrollment = exchange(panel_url, code).await?;
+  persist(&node_file, &enrollment.node.to_string())?;
+  persist(&token_file, &enrollment.token)?;
+  info!(
+    "Enrolled as node {}, token persisted to {}",
+    enrollment.node,
+    token_file.display()
+  );
+
+  config.api.node_id = Some(enrollment.node);
+  config.api.token = Some(enrollment.token);
+  Ok(())
+}
+
+/// Applies the configured or persisted credentials, returns false if the node
+/// still has to enroll.
+fn resolve_persisted(config: &mut Config) -> Result<bool> {
+  let token_file = config.paths.token_file();
+  let node_file = config.paths.node_file();
+
+  if config.api.node_id.is_none()
+    && let Some(node) = read(&node_file)?
+  {
+    config.api.node_id = Some(node.parse().context("Invalid node id in node file")?);
+  }
+
+  let persisted = read(&token_file)?;
+  if let Some(token) = &config.api.token {
+    if persisted.is_some_and(|persisted| &persisted != token) {
+      warn!(
+        "api.token differs from the token in {}, using api.token",
+        token_file.display()
+      );
+    }
+    return Ok(true);
+  }
+
+  if let Some(token) = persisted {
+    info!("Using token from {}", token_file.display());
+    config.api.token = Some(token);
+    return Ok(true);
+  }
+
+  Ok(false)
+}
+
+async fn exchange(panel_url: &str, code: &str) -> Result<Enrollment> {
+  let url = format!("{}/wings/enroll", panel_url.trim_end_matches('/'));
+  let data = SignData::new();
+  let headers = data.to_header_map(code)?;
+
+  let res = Client::new()
+    .post(url)
+    .headers(headers)
+    .json(&serde_json::json!({ "code": code }))
+    .send()
+    .await
+    .context("Failed to reach the panel")?;
+  if !res.status().is_success() {
+    bail!("Panel rejected the enrollment code with {}", res.status());
+  }
+
+  SignData::validate_header_map(res.headers(), code, Some(data))
+    .context("Failed to verify the enrollment response")?;
+
+  Ok(res.json().await.context("Invalid enrollment response")?)
+}
+
+fn read(path: &Path) -> Result<Option<String>> {
+  match fs::read_to_string(path) {
+    Ok(content) => Ok(Some(content.trim().to_string())),
+    Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+    Err(err) => Err(err)
+      .context(format!("Failed to read {}", path.display()))
+      .map_err(Into::into),
+  }
+}
+
+/// Writes the file readable only by the owner, creating missing directories.
+pub fn persist(path: &Path, content: &str) -> Result<()> {
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent).context("Failed to create data directory")?;
+  }
+
+  let mut options = OpenOptions::new();
+  options.write(true).create(true).truncate(true);
+  #[cfg(unix)]
+  {
+    use std::os::unix::fs::OpenOptionsExt;
+    options.mode(0o600);
+  }
+
+  let mut file = options
+    .open(path)
+    .context(format!("Failed to create {}", path.display()))?;
+  file
+    .write_all(content.as_bytes())
+    .context(format!("Failed to write {}", path.display()))?;
+
+  Ok(())
+}
+
+#[cfg(test)]
+// the closures of `Jail` have to return figment's large error
+#[allow(clippy::result_large_err)]
+mod test {
+  use figment::Jail;
+
+  use super::*;
+
+  fn config(jail: &Jail) -> Config {
+    let mut config = Config::default();
+    config.paths.data_dir = jail.directory().to_path_buf();
+    config
+  }
+
+  #[test]
+  fn configured_token_wins_over_persisted() {
+    Jail::expect_with(|jail| {
+      let mut config = config(jail);
+      persist(&config.paths.token_file(), "persisted").unwrap();
+      config.api.token = Some("configured".to_string());
+
+      assert!(resolve_persisted(&mut config).unwrap());
+      assert_eq!(config.api.token.as_deref(), Some("configured"));
+      Ok(())
+    });
+  }
+
+  #[test]
+  fn persisted_credentials_are_used() {
+    Jail::expect_with(|jail| {
+      let mut config = config(jail);
+      assert!(!resolve_persisted(&mut config).unwrap());
+
+      let node = Uuid::new_v4();
+      persist(&config.paths.node_file(), &node.to_string()).unwrap();
+      persist(&config.paths.token_file(), "persisted\n").unwrap();
+
+      assert!(resolve_persisted(&mut config).unwrap());
+      assert_eq!(config.api.token.as_deref(), Some("persisted"));
+      assert_eq!(config.api.node_id, Some(node));
+      Ok(())
+    });
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn persist_creates_owner_only_file() {
+    use std::os::unix::fs::PermissionsExt;
+
+    Jail::expect_with(|jail| {
+      let path = jail.directory().join("state/token");
+      persist(&path, "secret").unwrap();
+      persist(&path, "new").unwrap();
+
+      assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+      let mode = fs::metadata(&path).unwrap().permissions().mode();
+      assert_eq!(mode & 0o777, 0o600);
+      Ok(())
+    });
+  }
+}
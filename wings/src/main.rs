@@ -1,3 +1,5 @@
+use std::{env, path::PathBuf, process::exit};
+
 use aide::axum::ApiRouter;
 use axum::Extension;
 use centaurus::{
@@ -12,32 +14,103 @@ use centaurus::{
 use dotenvy::dotenv;
 use tracing::info;
 
-use crate::config::Config;
+use crate::{config::Config, tls::TlsListener};
 
 extern crate centaurus_wings as centaurus;
 
 mod auth;
+mod cert;
 mod config;
 mod dummy;
+mod enroll;
+mod metrics;
+mod reverse;
+mod stats;
+mod sync;
+mod tls;
 mod ws;
 
+const USAGE: &str = "Usage: wings [--config <file>] [config check]";
+
+enum Command {
+  Run,
+  CheckConfig,
+}
+
 #[tokio::main]
 async fn main() {
   #[cfg(debug_assertions)]
   dotenv().ok();
 
-  let config = Config::parse();
+  let (command, file) = parse_args();
+
+  if let Command::CheckConfig = command {
+    match Config::check(file.as_deref()) {
+      Ok(()) => println!("Configuration is valid"),
+      Err(err) => {
+        eprintln!("{:#}", err);
+        exit(1);
+      }
+    }
+    return;
+  }
+
+  let mut config = Config::load(file.as_deref()).expect("Failed to load configuration");
   init_logging(config.base.log_level);
+  Config::warn_deprecated();
+  config.validate().expect("Refusing to start");
+  config
+    .paths
+    .create()
+    .expect("Failed to create the data directory");
 
   rustls::crypto::aws_lc_rs::default_provider()
     .install_default()
     .unwrap();
 
+  enroll::resolve_credentials(&mut config)
+    .await
+    .expect("Failed to enroll with the panel");
+  cert::init(&config).expect("Failed to prepare the TLS certificate");
+
   let listener = listener_setup(config.base.port).await;
-  let app = build_router(router, state, config).await;
+  if config.tls_enabled() {
+    let listener = TlsListener::new(listener, &config).expect("Failed to set up TLS");
+    let app = build_router(router, state, config).await;
+
+    info!("Starting application with TLS");
+    tls::run_app_connect_info(listener, app).await;
+  } else {
+    let app = build_router(router, state, config).await;
+
+    info!("Starting application");
+    run_app_connect_info(listener, app).await;
+  }
+}
+
+/// The config file is taken from `--config`, then `WINGS_CONFIG`.
+fn parse_args() -> (Command, Option<PathBuf>) {
+  let mut args = env::args().skip(1);
+  let mut command = Command::Run;
+  let mut file = env::var_os("WINGS_CONFIG").map(PathBuf::from);
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--config" => match args.next() {
+        Some(path) => file = Some(PathBuf::from(path)),
+        None => usage(),
+      },
+      "config" if args.next().as_deref() == Some("check") => command = Command::CheckConfig,
+      _ => usage(),
+    }
+  }
+
+  (command, file)
+}
 
-  info!("Starting application");
-  run_app_connect_info(listener, app).await;
+fn usage() -> ! {
+  eprintln!("{}", USAGE);
+  exit(2);
 }
 
 fn router(_limiter: &mut RateLimiter) -> ApiRouter {
@@ -47,5 +120,11 @@ fn router(_limiter: &mut RateLimiter) -> ApiRouter {
 async fn state(router: ApiRouter, config: Config) -> ApiRouter {
   let router = auth::state(router.into(), &config);
   let router = dummy::state(router);
+  let (router, stats) = stats::state(router, config.runtime.stats_interval());
+  let (router, sync) = sync::state(router, &config.limits);
+  metrics::init(&config.metrics.metrics_name);
+  if config.api.reverse_connect {
+    reverse::spawn_connect(&config, stats, sync).expect("Failed to start reverse connect");
+  }
   router.layer(Extension(config)).into()
 }
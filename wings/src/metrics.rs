@@ -0,0 +1,71 @@
+use std::sync::OnceLock;
+
+use metrics::{Unit, counter, describe_counter, describe_gauge, gauge};
+use shared::msg::NodeStats;
+
+const MB: f64 = 1024.0 * 1024.0;
+
+static PREFIX: OnceLock<String> = OnceLock::new();
+
+fn name(metric: &str) -> String {
+  let prefix = PREFIX.get().map(String::as_str).unwrap_or("smaug-wings");
+  format!("{}_{}", prefix, metric)
+}
+
+/// Uses the same prefix as the http metrics of centaurus.
+pub fn init(prefix: &str) {
+  PREFIX.get_or_init(|| prefix.to_string());
+
+  describe_gauge!(
+    name("host_cpu_usage"),
+    Unit::Percent,
+    "CPU usage of the host"
+  );
+  describe_gauge!(
+    name("host_memory_used_bytes"),
+    Unit::Bytes,
+    "Memory in use on the host"
+  );
+  describe_gauge!(
+    name("host_memory_total_bytes"),
+    Unit::Bytes,
+    "Total memory of the host"
+  );
+  describe_gauge!(
+    name("host_disk_used_bytes"),
+    Unit::Bytes,
+    "Disk space in use on the host"
+  );
+  describe_gauge!(
+    name("host_disk_total_bytes"),
+    Unit::Bytes,
+    "Total disk space of the host"
+  );
+  describe_gauge!(
+    name("backend_connections"),
+    Unit::Count,
+    "Open websocket connections from the panel"
+  );
+  describe_counter!(
+    name("backend_connections_total"),
+    Unit::Count,
+    "Websocket connections the panel established"
+  );
+}
+
+pub fn host_stats(stats: &NodeStats) {
+  gauge!(name("host_cpu_usage")).set(stats.cpu_percent);
+  gauge!(name("host_memory_used_bytes")).set(stats.memory_used_mb * MB);
+  gauge!(name("host_memory_total_bytes")).set(stats.memory_total_mb * MB);
+  gauge!(name("host_disk_used_bytes")).set(stats.disk_used_mb * MB);
+  gauge!(name("host_disk_total_bytes")).set(stats.disk_total_mb * MB);
+}
+
+pub fn backend_connected() {
+  gauge!(name("backend_connections")).increment(1);
+  counter!(name("backend_connections_total")).increment(1);
+}
+
+pub fn backend_disconnected() {
+  gauge!(name("backend_connections")).decrement(1);
+}
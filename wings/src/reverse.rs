@@ -0,0 +1,114 @@
+use std::future::ready;
+
+use axum::extract::ws;
+use centaurus::{
+  bail,
+  error::Result,
+  eyre::{Context, ContextCompat},
+};
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
+use shared::auth::{NODE_HEADER, SignData};
+use tokio::{net::TcpStream, spawn, time::sleep};
+use tokio_tungstenite::{
+  MaybeTlsStream, WebSocketStream, connect_async,
+  tungstenite::{self, client::IntoClientRequest},
+};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::{config::Config, stats::HostStatsState, sync::SyncState, ws::connection};
+
+/// Keeps a websocket to the panel open, for nodes the panel can not reach.
+pub fn spawn_connect(config: &Config, stats: HostStatsState, sync: SyncState) -> Result<()> {
+  let panel_url = config
+    .api
+    .panel_url
+    .as_ref()
+    .context("api.panel_url is required for reverse connect")?;
+  let node = config
+    .api
+    .node_id
+    .context("api.node_id is required for reverse connect")?;
+
+  let url = if let Some(rest) = panel_url.strip_prefix("https://") {
+    format!("wss://{}", rest)
+  } else if let Some(rest) = panel_url.strip_prefix("http://") {
+    format!("ws://{}", rest)
+  } else {
+    bail!("api.panel_url must be a http(s) url");
+  };
+  let url = format!("{}/wings/connect", url.trim_end_matches('/'));
+  let token = config.token().to_string();
+  let reconnect_delay = config.runtime.reconnect_delay();
+
+  spawn(async move {
+    loop {
+      match dial(&url, node, &token).await {
+        Ok(stream) => {
+          info!("Opened websocket to the panel");
+          connection(adapt(stream), stats.clone(), sync.clone()).await;
+          warn!("Websocket to the panel closed, reconnecting");
+        }
+        Err(err) => warn!("Failed to open websocket to the panel: {:?}", err),
+      }
+      sleep(reconnect_delay).await;
+    }
+  });
+
+  Ok(())
+}
+
+async fn dial(
+  url: &str,
+  node: Uuid,
+  token: &str,
+) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
+  let mut request = url.into_client_request().context("Invalid panel url")?;
+
+  let data = SignData::new();
+  data.add_to_header_map(request.headers_mut(), token)?;
+  request
+    .headers_mut()
+    .insert(NODE_HEADER, node.to_string().parse().unwrap());
+
+  let (stream, res) = connect_async(request)
+    .await
+    .context("Failed to connect to the panel")?;
+  SignData::validate_header_map(res.headers(), token, Some(data))
+    .context("Failed to verify the panel websocket")?;
+
+  Ok(stream)
+}
+
+/// Adapts the client side websocket to the message types of the websocket the
+/// panel opens, so both can be served by the same connection handler.
+fn adapt(
+  stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+) -> impl Stream<Item = std::result::Result<ws::Message, axum::Error>>
++ Sink<ws::Message, Error = axum::Error>
++ Unpin {
+  stream
+    .sink_map_err(axum::Error::new)
+    .with(|msg| ready(Ok(to_tungstenite(msg))))
+    .map(|msg| msg.map(from_tungstenite).map_err(axum::Error::new))
+}
+
+fn to_tungstenite(msg: ws::Message) -> tungstenite::Message {
+  match msg {
+    ws::Message::Binary(data) => tungstenite::Message::Binary(data),
+    ws::Message::Text(text) => tungstenite::Message::Text(text.as_str().into()),
+    ws::Message::Ping(data) => tungstenite::Message::Ping(data),
+    ws::Message::Pong(data) => tungstenite::Message::Pong(data),
+    ws::Message::Close(_) => tungstenite::Message::Close(None),
+  }
+}
+
+fn from_tungstenite(msg: tungstenite::Message) -> ws::Message {
+  match msg {
+    tungstenite::Message::Binary(data) => ws::Message::Binary(data),
+    tungstenite::Message::Text(text) => ws::Message::Text(text.as_str().into()),
+    tungstenite::Message::Ping(data) => ws::Message::Ping(data),
+    tungstenite::Message::Pong(data) => ws::Message::Pong(data),
+    tungstenite::Message::Close(_) | tungstenite::Message::Frame(_) => ws::Message::Close(None),
+  }
+}
@@ -0,0 +1,90 @@
+use std::{collections::HashSet, time::Duration};
+
+use axum::{Extension, Router, extract::FromRequestParts};
+use shared::msg::NodeStats;
+use sysinfo::{Disks, System};
+use tokio::{
+  spawn,
+  sync::watch::{self, Receiver, Sender, error::RecvError},
+  time::interval,
+};
+
+use crate::metrics;
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Latest host stats, collected in the background independent of whether the
+/// panel is connected so they can also be scraped directly.
+#[derive(Clone, FromRequestParts)]
+#[from_request(via(Extension))]
+pub struct HostStatsState(Receiver<NodeStats>);
+
+impl HostStatsState {
+  pub fn latest(&self) -> NodeStats {
+    *self.0.borrow()
+  }
+
+  pub async fn changed(&mut self) -> Result<(), RecvError> {
+    self.0.changed().await
+  }
+}
+
+/// Also returns the stats for connections the panel does not open itself.
+pub fn state(router: Router, collect_interval: Duration) -> (Router, HostStatsState) {
+  let (sender, receiver) = watch::channel(NodeStats::default());
+  spawn(collect_task(sender, collect_interval));
+  let stats = HostStatsState(receiver);
+  (router.layer(Extension(stats.clone())), stats)
+}
+
+async fn collect_task(sender: Sender<NodeStats>, collect_interval: Duration) {
+  let mut host = HostStats::new();
+  let mut interval = interval(collect_interval);
+
+  loop {
+    interval.tick().await;
+    let stats = host.collect();
+    metrics::host_stats(&stats);
+    sender.send_replace(stats);
+  }
+}
+
+struct HostStats {
+  system: System,
+  disks: Disks,
+}
+
+impl HostStats {
+  fn new() -> Self {
+    Self {
+      system: System::new(),
+      disks: Disks::new_with_refreshed_list(),
+    }
+  }
+
+  /// CPU usage is measured since the previous call, so the first sample after
+  /// creating the collector reads as idle.
+  fn collect(&mut self) -> NodeStats {
+    self.system.refresh_cpu_usage();
+    self.system.refresh_memory();
+    self.disks.refresh(true);
+
+    // the same device can be mounted several times, only count it once
+    let mut devices = HashSet::new();
+    let (mut disk_total, mut disk_available) = (0, 0);
+    for disk in self.disks.list() {
+      if devices.insert(disk.name()) {
+        disk_total += disk.total_space();
+        disk_available += disk.available_space();
+      }
+    }
+
+    NodeStats {
+      cpu_percent: self.system.global_cpu_usage() as f64,
+      memory_used_mb: self.system.used_memory() as f64 / MB,
+      memory_total_mb: self.system.total_memory() as f64 / MB,
+      disk_used_mb: disk_total.saturating_sub(disk_available) as f64 / MB,
+      disk_total_mb: disk_total as f64 / MB,
+    }
+  }
+}
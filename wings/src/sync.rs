@@ -0,0 +1,37 @@
+use std::sync::{Arc, Mutex};
+
+use axum::{Extension, Router, extract::FromRequestParts};
+use shared::msg::{NodeConfig, SyncReport};
+use tracing::info;
+
+use crate::config::LimitsConfig;
+
+/// Configuration of the node as last pushed by the panel, starting out with
+/// the local limits until the first sync.
+#[derive(Clone, FromRequestParts)]
+#[from_request(via(Extension))]
+pub struct SyncState(Arc<Mutex<NodeConfig>>);
+
+impl SyncState {
+  /// Applies the desired configuration and reports what differed.
+  pub fn apply(&self, desired: NodeConfig) -> SyncReport {
+    let mut applied = self.0.lock().unwrap();
+    let drift = applied.drift(&desired);
+    if !drift.is_empty() {
+      info!("Applied configuration from the panel, drift: {:?}", drift);
+    }
+    *applied = desired;
+
+    SyncReport { drift }
+  }
+}
+
+/// Also returns the state for connections the panel does not open itself.
+pub fn state(router: Router, limits: &LimitsConfig) -> (Router, SyncState) {
+  let sync = SyncState(Arc::new(Mutex::new(NodeConfig {
+    memory_limit_mb: limits.memory_mb,
+    disk_limit_mb: limits.disk_mb,
+    cpu_limit: limits.cpu,
+  })));
+  (router.layer(Extension(sync.clone())), sync)
+}
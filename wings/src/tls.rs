@@ -0,0 +1,197 @@
+use std::{
+  fs, io,
+  net::SocketAddr,
+  path::{Path, PathBuf},
+  sync::{Arc, RwLock},
+  time::{Duration, SystemTime},
+};
+
+use axum::{
+  Router,
+  serve::{Listener, ListenerExt},
+};
+use centaurus::{backend::init::shutdown_signal, bail, error::Result, eyre::Context};
+use rustls::{
+  ServerConfig,
+  crypto::CryptoProvider,
+  pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+  server::{ClientHello, ResolvesServerCert},
+  sign::CertifiedKey,
+};
+use tokio::{
+  net::{TcpListener, TcpStream},
+  spawn,
+  sync::mpsc,
+  time::{sleep, timeout},
+};
+use tokio_rustls::{TlsAcceptor, server::TlsStream};
+use tracing::{debug, info, warn};
+
+use crate::config::Config;
+
+const RELOAD_INTERVAL: Duration = Duration::from_secs(10);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Accepts tcp connections and hands them to axum once the TLS handshake is
+/// done. Handshakes run in their own tasks, so a slow client can not hold up
+/// the others.
+pub struct TlsListener {
+  incoming: mpsc::Receiver<(TlsStream<TcpStream>, SocketAddr)>,
+  addr: SocketAddr,
+}
+
+impl TlsListener {
+  pub fn new(mut listener: TcpListener, config: &Config) -> Result<Self> {
+    let resolver = Arc::new(CertResolver::new(
+      config.tls_cert_file(),
+      config.tls_key_file(),
+    )?);
+    spawn(resolver.clone().watch());
+
+    let mut server_config = ServerConfig::builder()
+      .with_no_client_auth()
+      .with_cert_resolver(resolver);
+    server_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+    let acceptor = TlsAcceptor::from(Arc::new(server_config));
+
+    let addr = listener
+      .local_addr()
+      .context("Failed to read listener address")?;
+    let (sender, incoming) = mpsc::channel(64);
+
+    spawn(async move {
+      loop {
+        let (stream, addr) = Listener::accept(&mut listener).await;
+        let acceptor = acceptor.clone();
+        let sender = sender.clone();
+
+        spawn(async move {
+          match timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+            Ok(Ok(stream)) => {
+              sender.send((stream, addr)).await.ok();
+            }
+            Ok(Err(err)) => debug!("TLS handshake with {} failed: {}", addr, err),
+            Err(_) => debug!("TLS handshake with {} timed out", addr),
+          }
+        });
+      }
+    });
+
+    Ok(Self { incoming, addr })
+  }
+}
+
+impl Listener for TlsListener {
+  type Io = TlsStream<TcpStream>;
+  type Addr = SocketAddr;
+
+  async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+    self.incoming.recv().await.expect("TLS accept task stopped")
+  }
+
+  fn local_addr(&self) -> io::Result<Self::Addr> {
+    Ok(self.addr)
+  }
+}
+
+/// Same as `run_app_connect_info`, but over TLS.
+pub async fn run_app_connect_info(listener: TlsListener, app: Router) {
+  // tapping the io is what lets axum provide the peer address for a custom
+  // listener, the limiter keys on it
+  let listener = listener.tap_io(|stream| {
+    stream.get_ref().0.set_nodelay(true).ok();
+  });
+
+  axum::serve(
+    listener,
+    app.into_make_service_with_connect_info::<SocketAddr>(),
+  )
+  .with_graceful_shutdown(shutdown_signal())
+  .await
+  .expect("Failed to start server");
+}
+
+/// Serves the current certificate and swaps it when the files on disk change.
+#[derive(Debug)]
+struct CertResolver {
+  cert_file: PathBuf,
+  key_file: PathBuf,
+  current: RwLock<Loaded>,
+}
+
+#[derive(Debug)]
+struct Loaded {
+  key: Arc<CertifiedKey>,
+  modified: Option<SystemTime>,
+}
+
+impl CertResolver {
+  fn new(cert_file: PathBuf, key_file: PathBuf) -> Result<Self> {
+    let modified = modified(&cert_file, &key_file);
+    let key = Arc::new(load(&cert_file, &key_file)?);
+    info!("Loaded TLS certificate from {}", cert_file.display());
+
+    Ok(Self {
+      cert_file,
+      key_file,
+      current: RwLock::new(Loaded { key, modified }),
+    })
+  }
+
+  async fn watch(self: Arc<Self>) {
+    loop {
+      sleep(RELOAD_INTERVAL).await;
+
+      let modified = modified(&self.cert_file, &self.key_file);
+      if modified == self.current.read().unwrap().modified {
+        continue;
+      }
+
+      // the stamp is updated even if loading fails, so a half written pair is
+      // only retried once the other file changes as well
+      let loaded = load(&self.cert_file, &self.key_file);
+      let mut current = self.current.write().unwrap();
+      current.modified = modified;
+      match loaded {
+        Ok(key) => {
+          current.key = Arc::new(key);
+          info!("Reloaded TLS certificate");
+        }
+        Err(err) => warn!(
+          "Failed to reload TLS certificate, keeping the old one: {:?}",
+          err
+        ),
+      }
+    }
+  }
+}
+
+impl ResolvesServerCert for CertResolver {
+  fn resolve(&self, _client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+    Some(self.current.read().unwrap().key.clone())
+  }
+}
+
+fn modified(cert_file: &Path, key_file: &Path) -> Option<SystemTime> {
+  let cert = fs::metadata(cert_file).and_then(|m| m.modified()).ok()?;
+  let key = fs::metadata(key_file).and_then(|m| m.modified()).ok()?;
+  Some(cert.max(key))
+}
+
+fn load(cert_file: &Path, key_file: &Path) -> Result<CertifiedKey> {
+  let certs = CertificateDer::pem_file_iter(cert_file)
+    .context(format!("Failed to read {}", cert_file.display()))?
+    .collect::<std::result::Result<Vec<_>, _>>()
+    .context("Invalid certificate")?;
+  if certs.is_empty() {
+    bail!("{} does not contain a certificate", cert_file.display());
+  }
+  let key = PrivateKeyDer::from_pem_file(key_file)
+    .context(format!("Failed to read {}", key_file.display()))?;
+
+  let provider = CryptoProvider::get_default().expect("crypto provider is installed on startup");
+  Ok(
+    CertifiedKey::from_der(certs, key, provider)
+      .context("Certificate does not match the private key")?,
+  )
+}
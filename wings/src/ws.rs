@@ -1,18 +1,21 @@
 use axum::{
   Router,
-  extract::{
-    WebSocketUpgrade,
-    ws::{self, WebSocket},
-  },
+  extract::{WebSocketUpgrade, ws},
   response::Response,
   routing::any,
 };
 use centaurus::error::Result;
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
 use http::HeaderMap;
 use shared::{auth::SignData, msg::WingsMessage};
 use tracing::info;
 
-use crate::auth::{Auth, WingsToken};
+use crate::{
+  auth::{Auth, WingsToken},
+  metrics,
+  stats::HostStatsState,
+  sync::SyncState,
+};
 
 pub fn router() -> Router {
   Router::new().route("/", any(init_connection))
@@ -21,6 +24,8 @@ pub fn router() -> Router {
 async fn init_connection(
   auth: Auth,
   token: WingsToken,
+  stats: HostStatsState,
+  sync: SyncState,
   ws: WebSocketUpgrade,
 ) -> Result<(HeaderMap, Response)> {
   let data = SignData::from_timestamp(auth.timestamp);
@@ -28,22 +33,51 @@ async fn init_connection(
 
   info!("Established wings websocket connection");
 
-  Ok((headers, ws.on_upgrade(connection)))
+  Ok((
+    headers,
+    ws.on_upgrade(|socket| connection(socket, stats, sync)),
+  ))
 }
 
-async fn connection(mut socket: WebSocket) {
-  while let Some(Ok(next)) = socket.recv().await {
+/// Serves the panel over a websocket, regardless of which side opened it.
+pub async fn connection<S>(mut socket: S, mut stats: HostStatsState, sync: SyncState)
+where
+  S: Stream<Item = std::result::Result<ws::Message, axum::Error>>
+    + Sink<ws::Message, Error = axum::Error>
+    + Unpin,
+{
+  metrics::backend_connected();
+
+  loop {
+    let next = tokio::select! {
+      next = socket.next() => next,
+      Ok(()) = stats.changed() => {
+        let msg = WingsMessage::NodeStats(stats.latest());
+        if send(&mut socket, &msg).await.is_err() {
+          info!("Failed to send node stats, closing connection");
+          break;
+        }
+        continue;
+      }
+    };
+
+    let Some(Ok(next)) = next else {
+      break;
+    };
+
     match next {
       ws::Message::Binary(raw_msg) => match serde_json::from_slice::<WingsMessage>(&raw_msg) {
+        Ok(WingsMessage::SyncConfig(config)) => {
+          let report = WingsMessage::SyncReport(sync.apply(config));
+          if send(&mut socket, &report).await.is_err() {
+            info!("Failed to send sync report, closing connection");
+            break;
+          }
+        }
         Ok(msg) => {
           info!("Parsed wings message: {:?}", msg);
           if msg == WingsMessage::Hello {
-            socket
-              .send(ws::Message::Binary(
-                serde_json::to_vec(&WingsMessage::World).unwrap().into(),
-              ))
-              .await
-              .unwrap();
+            send(&mut socket, &WingsMessage::World).await.unwrap();
           }
         }
         Err(err) => {
@@ -57,4 +91,15 @@ async fn connection(mut socket: WebSocket) {
       _ => (),
     }
   }
+
+  metrics::backend_disconnected();
+}
+
+async fn send<S>(socket: &mut S, msg: &WingsMessage) -> std::result::Result<(), axum::Error>
+where
+  S: Sink<ws::Message, Error = axum::Error> + Unpin,
+{
+  socket
+    .send(ws::Message::Binary(serde_json::to_vec(msg).unwrap().into()))
+    .await
 }